@@ -460,16 +460,23 @@ pub fn check_multisig_auth(
     caller: &Address,
     action_hash: soroban_sdk::BytesN<32>,
     required_role: Role,
+) -> Result<bool, AuthError> {
+    let threshold = get_threshold(env, required_role.clone());
+    check_multisig_auth_with_threshold(env, caller, action_hash, required_role, threshold)
+}
+
+/// Multi-Sig check against an explicit threshold, for actions whose required
+/// signatures depend on the action itself (e.g. amount tiers) rather than the role.
+pub fn check_multisig_auth_with_threshold(
+    env: &Env,
+    caller: &Address,
+    action_hash: soroban_sdk::BytesN<32>,
+    required_role: Role,
+    threshold: u32,
 ) -> Result<bool, AuthError> {
     caller.require_auth();
     require_role(env, caller, required_role.clone())?;
 
-    let threshold: u32 = env
-        .storage()
-        .persistent()
-        .get(&RoleKey::Threshold(required_role.clone()))
-        .unwrap_or(1);
-
     if threshold <= 1 {
         return Ok(true);
     }
@@ -509,6 +516,11 @@ pub fn check_multisig_auth(
     }
 }
 
+/// Discard the signatures collected for an action, e.g. when the data they signed has changed.
+pub fn clear_approvals(env: &Env, action_hash: soroban_sdk::BytesN<32>) {
+    env.storage().persistent().remove(&RoleKey::Approvals(action_hash));
+}
+
 /// Admin Event for Threshold Changes
 pub fn set_threshold(
     env: &Env,
@@ -1,52 +1,92 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, IntoVal,
-    Symbol,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, Address,
+    Env, IntoVal, Symbol,
 };
 
-// Import the Policy contract interface to verify ownership and coverage
-// NOTE: policy contract client import omitted in this workspace build; it requires
-// a pre-built wasm artifact at build-time.
-use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, Env, Symbol, symbol_short, IntoVal, Vec};
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, BytesN, Env, String, Symbol, symbol_short, IntoVal, Vec};
 
-// Import the Policy contract interface to verify ownership and coverage
-#[cfg(not(test))]
-mod policy_contract {
-    soroban_sdk::contractimport!(file = "../../target/wasm32-unknown-unknown/release/policy_contract.wasm");
+/// Lifecycle state reported by the policy contract.
+/// Mirrors `PolicyState` in the policy contract so its responses decode here.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PolicyState {
+    ACTIVE,
+    LAPSED,
+    EXPIRED,
+    CANCELLED,
 }
 
-// Mock policy contract client for tests
-#[cfg(test)]
-mod policy_contract {
-    use soroban_sdk::{Address, Env, contractclient};
-
-    // Mock client that returns test data
-    pub struct Client<'a> {
-        _env: &'a Env,
-        _contract_id: &'a Address,
-    }
-
-    impl<'a> Client<'a> {
-        pub fn new(env: &'a Env, contract_id: &'a Address) -> Self {
-            Self { _env: env, _contract_id: contract_id }
-        }
+/// Terms in force on a given date, as reported by the policy contract.
+/// Mirrors `PolicyAmendment` in the policy contract so its responses decode here.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyAmendment {
+    pub policy_id: u64,
+    pub version: u32,
+    pub endorsement_id: Option<u64>,
+    pub coverage_amount: i128,
+    pub coverage_asset: shared::types::Asset,
+    pub premium_amount: i128,
+    pub end_time: u64,
+    pub premium_adjustment: i128,
+    pub effective_from: u64,
+}
 
-        // Mock get_policy returns (holder, coverage_amount, ...)
-        pub fn get_policy(&self, _policy_id: &u64) -> (Address, i128, i128, u64, u64) {
-            // Return mock data - this won't be used in our unit tests
-            panic!("Mock policy_contract::Client::get_policy called - use unit tests that don't call submit_claim")
-        }
-    }
+// Client interface for the policy contract, used to verify ownership and coverage
+#[contractclient(name = "PolicyClient")]
+pub trait PolicyInterface {
+    fn get_policy_holder(env: Env, policy_id: u64) -> Address;
+    fn get_policy_state(env: Env, policy_id: u64) -> PolicyState;
+    fn get_policy_dates(env: Env, policy_id: u64) -> (u64, u64);
+    fn get_policy_terms_at(env: Env, policy_id: u64, timestamp: u64) -> PolicyAmendment;
+    fn get_remaining_coverage(env: Env, policy_id: u64) -> i128;
+    fn get_certificate(env: Env, certificate_id: u64) -> MemberCertificate;
+    fn get_certificate_remaining_coverage(env: Env, certificate_id: u64) -> i128;
+    fn get_policy_product(env: Env, policy_id: u64) -> Option<u64>;
 }
 
 // Import shared types and authorization from the common library
 use insurance_contracts::authorization::{
-    get_role, initialize_admin, register_trusted_contract, require_admin, require_claim_processing,
+    check_multisig_auth_with_threshold, clear_approvals, get_role, initialize_admin,
+    register_trusted_contract, require_admin, require_claim_processing, require_role,
     require_trusted_contract, Role,
 };
 use insurance_contracts::rate_limit::{self, RateLimitConfig};
 use insurance_contracts::emergency_pause::EmergencyPause;
 use insurance_contracts::types::ClaimStatus;
+use insurance_contracts::utils::{store_claim_evidence, verify_claim_evidence};
+use soroban_sdk::xdr::ToXdr;
+
+use shared::types::{BeneficiaryDesignation, CertificateStatus, CoverageTerms, MemberCertificate};
+
+#[path = "src/config.rs"]
+mod config;
+#[path = "src/dispute.rs"]
+mod dispute;
+#[path = "src/evidence.rs"]
+mod evidence;
+#[path = "src/fraud.rs"]
+mod fraud;
+#[path = "src/revision.rs"]
+mod revision;
+#[path = "src/settlement.rs"]
+mod settlement;
+#[path = "src/sla.rs"]
+mod sla;
+#[path = "src/structured.rs"]
+mod structured;
+
+pub use dispute::{Dispute, DisputeOutcome, DisputeRoute};
+pub use evidence::{EvidenceItem, EvidenceStatus, EvidenceSubmission};
+pub use fraud::{FraudAction, FraudScore, FraudScreeningConfig};
+use fraud::{RiskMonitoringClient, SlashingClient};
+pub use revision::{ClaimRevision, RevisionKind};
+pub use settlement::ClaimSettlement;
+use settlement::{AssetRegistryClient, RiskPoolClient};
+pub use structured::{Commutation, Installment, PaymentSchedule, ScheduleStatus, ScheduledPayment};
+pub use sla::{ClaimMilestones, ClaimSla, SlaBreach, SlaEscalation, SlaEscalationConfig, SlaMetrics, SlaStage};
+use sla::{RecoveryClient, RecoveryError};
 
 // Import invariants and safety assertions
 use insurance_invariants::{InvariantError, ProtocolInvariants};
@@ -66,9 +106,38 @@ pub struct ClaimsContract;
 const PAUSED: Symbol = symbol_short!("PAUSED");
 const CONFIG: Symbol = symbol_short!("CONFIG");
 const CLAIM: Symbol = symbol_short!("CLAIM");
-const POLICY_CLAIM: Symbol = symbol_short!("P_CLAIM");
+const POLICY_CLAIMS: Symbol = symbol_short!("P_CLMS");
 const ORACLE_CONFIG: Symbol = symbol_short!("ORA_CFG");
 const CLAIM_ORACLE_ID: Symbol = symbol_short!("CLM_OID");
+const CLAIM_AMOUNTS: Symbol = symbol_short!("CLM_AMT");
+const CLAIM_PERIL: Symbol = symbol_short!("CLM_PRL");
+const CLAIM_PAYEES: Symbol = symbol_short!("CLM_PAY");
+const CLAIM_CERTIFICATE: Symbol = symbol_short!("CLM_CRT");
+const CLAIM_LOSS: Symbol = symbol_short!("CLM_LOSS");
+const CLAIM_DECISION: Symbol = symbol_short!("CLM_DEC");
+const DISPUTE: Symbol = symbol_short!("DISPUTE");
+const DISPUTE_VOTE: Symbol = symbol_short!("DSP_VOTE");
+const DISPUTE_PANEL: Symbol = symbol_short!("DSP_PNL");
+const DISPUTE_GOVERNANCE: Symbol = symbol_short!("DSP_GOV");
+const CLAIM_EVIDENCE: Symbol = symbol_short!("CLM_EVD");
+const CLAIM_PRODUCT: Symbol = symbol_short!("CLM_PRD");
+const REQUIRED_EVIDENCE: Symbol = symbol_short!("REQ_EVD");
+const APPROVAL_TIERS: Symbol = symbol_short!("APR_TIER");
+const PENDING_APPROVAL: Symbol = symbol_short!("CLM_PND");
+const FRAUD_CONFIG: Symbol = symbol_short!("FRD_CFG");
+const FRAUD_SCORE: Symbol = symbol_short!("FRD_SCR");
+const CLAIMANT_HISTORY: Symbol = symbol_short!("CLMT_HST");
+const CLAIM_REVISIONS: Symbol = symbol_short!("CLM_REV");
+const ASSET_REGISTRY: Symbol = symbol_short!("AST_REG");
+const CLAIM_SETTLEMENT: Symbol = symbol_short!("CLM_STL");
+const PAYMENT_SCHEDULE: Symbol = symbol_short!("PAY_SCHD");
+const CLAIM_SLA: Symbol = symbol_short!("CLM_SLA");
+const CLAIM_MILESTONES: Symbol = symbol_short!("CLM_MS");
+const SLA_ESCALATION: Symbol = symbol_short!("SLA_ESC");
+const SLA_BREACH: Symbol = symbol_short!("SLA_BRCH");
+const SLA_ASSIGNMENT: Symbol = symbol_short!("SLA_ASGN");
+const SLA_METRICS: Symbol = symbol_short!("SLA_MET");
+const SLA_BACKUP_CURSOR: Symbol = symbol_short!("SLA_RR");
 
 // NOTE: Keys used for storing oracle data IDs per claim.
 const ORACLE_CFG: Symbol = ORACLE_CONFIG;
@@ -85,6 +154,18 @@ const DEFAULT_CLAIM_SUBMIT_RATE_LIMIT_WINDOW_SECS: u64 = 60;
 /// Maximum number of claims to return in a single paginated request.
 const MAX_PAGINATION_LIMIT: u32 = 50;
 
+/// Filing times kept per claimant for the claim-frequency fraud signal
+const MAX_CLAIMANT_HISTORY: u32 = 50;
+
+/// Basis points denominator used for co-insurance shares
+const BASIS_POINTS: i128 = 10_000;
+
+/// Maximum number of evidence items attached to a single claim
+const MAX_EVIDENCE_ITEMS: u32 = 20;
+
+/// Maximum number of installments in a structured settlement (ten years of monthly payments)
+const MAX_INSTALLMENTS: u32 = 120;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum ContractError {
@@ -113,6 +194,27 @@ pub enum ContractError {
     Overflow = 107,
     RateLimitExceeded = 108,
     InvalidRateLimitConfig = 109,
+    // Loss-sharing errors
+    BelowDeductible = 110,
+    // Policy verification errors
+    PolicyNotFound = 111,
+    NotPolicyholder = 112,
+    PolicyNotActive = 113,
+    LossOutsideCoveragePeriod = 114,
+    CertificateNotActive = 115,
+    // Dispute errors
+    NotDisputable = 116,
+    DisputeWindowClosed = 117,
+    DisputeNotOpen = 118,
+    NotPanelMember = 119,
+    AlreadyVoted = 120,
+    // Evidence errors
+    EvidenceRequired = 121,
+    EvidenceHashMismatch = 122,
+    // Fraud screening errors
+    FraudScreeningRequired = 123,
+    // Settlement errors
+    NoPayoutAssetAvailable = 124,
 }
 
 impl From<insurance_contracts::authorization::AuthError> for ContractError {
@@ -178,6 +280,63 @@ pub struct ClaimView {
     pub submitted_at: u64,
 }
 
+/// Breakdown of a claim from the gross loss to the amount payable under the policy terms.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimAmounts {
+    /// Loss amount requested by the claimant
+    pub gross_amount: i128,
+    /// Loss amount accepted by the processor; below the gross amount for partial approvals
+    pub approved_amount: i128,
+    /// Reason code recorded when less than the requested amount was approved
+    pub adjustment_reason: Option<Symbol>,
+    /// Reduction from the peril sub-limit, if one applied
+    pub sub_limit_reduction: i128,
+    /// Reduction from the policy's per-occurrence or remaining aggregate limit
+    pub limit_reduction: i128,
+    /// Deductible retained by the holder
+    pub deductible_amount: i128,
+    /// Holder's co-insurance share of the loss above the deductible
+    pub coinsurance_amount: i128,
+    /// Amount reserved and paid from the risk pool
+    pub net_amount: i128,
+    /// Amount actually paid out at settlement
+    pub paid_amount: i128,
+    /// Peril the loss was attributed to
+    pub peril: Option<Symbol>,
+}
+
+/// Signatures required to approve claims below `max_amount`.
+/// Amounts at or above the highest tier's bound use the highest tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalTier {
+    /// Exclusive upper bound of approved amounts covered by this tier
+    pub max_amount: i128,
+    /// Distinct claim processors that must sign the approval
+    pub required_approvals: u32,
+    /// Whether an Auditor must countersign as well
+    pub auditor_required: bool,
+}
+
+/// Signatures collected so far for approving a claim at a specific amount.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingApproval {
+    /// Amount being approved; signatures only count for this amount
+    pub approved_amount: i128,
+    pub adjustment_reason: Option<Symbol>,
+    /// Multi-sig action hash bound to `(claim_id, approved_amount)`
+    pub action_hash: BytesN<32>,
+    pub required_approvals: u32,
+    pub approvals: Vec<Address>,
+    pub auditor_required: bool,
+    pub auditor: Option<Address>,
+    /// Whether the processor threshold has been met
+    pub processors_ready: bool,
+    pub opened_at: u64,
+}
+
 /// Result of a paginated claims query.
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -209,6 +368,14 @@ fn is_valid_state_transition(current: ClaimStatus, next: ClaimStatus) -> bool {
         (ClaimStatus::UnderReview, ClaimStatus::Approved) => true,
         (ClaimStatus::UnderReview, ClaimStatus::Rejected) => true,
         (ClaimStatus::Approved, ClaimStatus::Settled) => true,
+        // Disputes reopen a decision until a binding resolution is reached
+        (ClaimStatus::Rejected, ClaimStatus::Disputed) => true,
+        (ClaimStatus::Approved, ClaimStatus::Disputed) => true,
+        (ClaimStatus::Disputed, ClaimStatus::Approved) => true,
+        (ClaimStatus::Disputed, ClaimStatus::Rejected) => true,
+        // Claimants may withdraw until a decision is made
+        (ClaimStatus::Submitted, ClaimStatus::Withdrawn) => true,
+        (ClaimStatus::UnderReview, ClaimStatus::Withdrawn) => true,
         // Invalid transitions (backward, skipping, etc.)
         _ => false,
     }
@@ -233,6 +400,796 @@ fn validate_coverage_constraint(
     Ok(())
 }
 
+/// Apply the policy's sub-limit, deductible and co-insurance to a gross loss.
+fn calculate_net_payable(
+    gross_amount: i128,
+    terms: &CoverageTerms,
+    peril: Option<Symbol>,
+) -> Result<ClaimAmounts, ContractError> {
+    validate_amount(gross_amount)?;
+
+    let mut limited = gross_amount;
+    if let Some(peril) = peril.clone() {
+        for sub_limit in terms.sub_limits.iter() {
+            if sub_limit.peril == peril && sub_limit.limit < limited {
+                limited = sub_limit.limit;
+            }
+        }
+    }
+
+    if limited <= terms.deductible {
+        return Err(ContractError::BelowDeductible);
+    }
+    let above_deductible = limited.checked_sub(terms.deductible).ok_or(ContractError::Overflow)?;
+    let coinsurance_amount = above_deductible
+        .checked_mul(i128::from(terms.coinsurance_bps))
+        .ok_or(ContractError::Overflow)?
+        / BASIS_POINTS;
+    let net_amount = above_deductible
+        .checked_sub(coinsurance_amount)
+        .ok_or(ContractError::Overflow)?;
+
+    Ok(ClaimAmounts {
+        gross_amount,
+        approved_amount: gross_amount,
+        adjustment_reason: None,
+        sub_limit_reduction: gross_amount - limited,
+        limit_reduction: 0,
+        deductible_amount: terms.deductible,
+        coinsurance_amount,
+        net_amount,
+        paid_amount: 0,
+        peril,
+    })
+}
+
+/// Apply the policy's deductible, co-insurance and the claim's peril sub-limit to `amount`.
+fn assess_claim(
+    env: &Env,
+    claim_id: u64,
+    policy_id: u64,
+    amount: i128,
+) -> Result<ClaimAmounts, ContractError> {
+    let terms = coverage_terms_at_loss(env, claim_id, policy_id)?;
+    let peril: Option<Symbol> = env.storage().persistent().get(&(CLAIM_PERIL, claim_id));
+    calculate_net_payable(amount, &terms, peril)
+}
+
+/// Fetch the policy's loss-sharing terms in force at the claim's loss date, so terms changed
+/// after the loss do not apply to it. Claims filed without a loss date use the current terms.
+fn coverage_terms_at_loss(
+    env: &Env,
+    claim_id: u64,
+    policy_id: u64,
+) -> Result<CoverageTerms, ContractError> {
+    let config: (Address, Address) =
+        env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+    require_trusted_contract(env, &config.0)?;
+    let loss_timestamp: Option<u64> = env.storage().persistent().get(&(CLAIM_LOSS, claim_id));
+    Ok(match loss_timestamp {
+        Some(timestamp) => env.invoke_contract(
+            &config.0,
+            &Symbol::new(env, "get_coverage_terms_at"),
+            (policy_id, timestamp).into_val(env),
+        ),
+        None => env.invoke_contract(
+            &config.0,
+            &Symbol::new(env, "get_coverage_terms"),
+            (policy_id,).into_val(env),
+        ),
+    })
+}
+
+/// Credit `amount` to `recipient`, merging with an existing entry for the same address.
+fn add_payee(payees: &mut Vec<(Address, i128)>, recipient: Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    for i in 0..payees.len() {
+        let (existing, paid) = payees.get(i).unwrap();
+        if existing == recipient {
+            payees.set(i, (existing, paid + amount));
+            return;
+        }
+    }
+    payees.push_back((recipient, amount));
+}
+
+/// Split a net payout across the policy's beneficiaries by their basis-point shares.
+/// Shares of `unavailable` primaries go to the contingent beneficiary, or the claimant if none.
+/// The last primary absorbs rounding so the split always sums to `net_amount`.
+fn split_payout(
+    env: &Env,
+    net_amount: i128,
+    designation: &BeneficiaryDesignation,
+    unavailable: &Vec<Address>,
+    claimant: &Address,
+) -> Result<Vec<(Address, i128)>, ContractError> {
+    let mut payees: Vec<(Address, i128)> = Vec::new(env);
+    if designation.beneficiaries.is_empty() {
+        add_payee(&mut payees, claimant.clone(), net_amount);
+        return Ok(payees);
+    }
+
+    for address in unavailable.iter() {
+        if !designation.beneficiaries.iter().any(|b| b.recipient == address) {
+            return Err(ContractError::InvalidInput);
+        }
+    }
+    let fallback = designation.contingent.clone().unwrap_or(claimant.clone());
+
+    let last = designation.beneficiaries.len() - 1;
+    let mut allocated: i128 = 0;
+    for (i, beneficiary) in designation.beneficiaries.iter().enumerate() {
+        let share = if i as u32 == last {
+            net_amount - allocated
+        } else {
+            net_amount
+                .checked_mul(i128::from(beneficiary.share_bps))
+                .ok_or(ContractError::Overflow)?
+                / BASIS_POINTS
+        };
+        allocated += share;
+
+        let recipient = if unavailable.contains(&beneficiary.recipient) {
+            fallback.clone()
+        } else {
+            beneficiary.recipient
+        };
+        add_payee(&mut payees, recipient, share);
+    }
+    Ok(payees)
+}
+
+/// Pick the first asset the claimant accepts that the pool can pay `amount` (denominated
+/// in the preferred asset) in without touching other claims' reservations, converting
+/// through the asset registry. A processor's `override_asset` must itself be one the
+/// claimant accepts.
+fn select_payout_asset(
+    env: &Env,
+    risk_pool: &Address,
+    claim_id: u64,
+    preference: &shared::types::ClaimPayoutPreference,
+    override_asset: Option<shared::types::Asset>,
+    amount: i128,
+) -> Result<ClaimSettlement, ContractError> {
+    let claim_asset = preference.preferred_asset.clone();
+    let mut candidates = Vec::new(env);
+    match override_asset {
+        Some(asset) => {
+            let accepted = asset == claim_asset
+                || (preference.accept_alternative && preference.alternatives.contains(&asset));
+            if !accepted {
+                return Err(ContractError::InvalidInput);
+            }
+            candidates.push_back(asset);
+        }
+        None => {
+            candidates.push_back(claim_asset.clone());
+            if preference.accept_alternative {
+                candidates.append(&preference.alternatives);
+            }
+        }
+    }
+
+    let registry: Option<Address> = env.storage().persistent().get(&ASSET_REGISTRY);
+    let pool = RiskPoolClient::new(env, risk_pool);
+    for asset in candidates.iter() {
+        let (payout_amount, rate_bps, rate_updated_at) = if asset == claim_asset {
+            (amount, settlement::PAR_RATE_BPS, None)
+        } else {
+            // Alternatives need a registry that accepts them for claims and quotes a rate
+            let Some(registry) = &registry else {
+                continue;
+            };
+            let registry = AssetRegistryClient::new(env, registry);
+            if !registry.accepts_claims(&asset) {
+                continue;
+            }
+            let rate = match registry.try_get_conversion_rate(&claim_asset, &asset) {
+                Ok(Ok(rate)) => rate,
+                _ => continue,
+            };
+            match registry.try_convert_amount(&claim_asset, &asset, &amount) {
+                Ok(Ok(converted)) if converted > 0 => (converted, rate.rate_bps, Some(rate.updated_at)),
+                _ => continue,
+            }
+        };
+
+        let liquidity = match pool.try_get_payout_liquidity(&asset, &claim_id) {
+            Ok(Ok(liquidity)) => liquidity,
+            _ => continue,
+        };
+        if liquidity < payout_amount {
+            continue;
+        }
+
+        return Ok(ClaimSettlement {
+            claim_asset,
+            claim_amount: amount,
+            payout_asset: asset,
+            payout_amount,
+            rate_bps,
+            rate_updated_at,
+            settled_at: env.ledger().timestamp(),
+        });
+    }
+
+    Err(ContractError::NoPayoutAssetAvailable)
+}
+
+/// Pay part of a claim's reservation to `payee` through the risk pool
+fn pay_from_reservation(
+    env: &Env,
+    claim_id: u64,
+    payee: &Address,
+    asset: &shared::types::Asset,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let config: (Address, Address) =
+        env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+    require_trusted_contract(env, &config.1)?;
+    env.invoke_contract::<()>(
+        &config.1,
+        &Symbol::new(env, "payout_reserved_claim_multi_asset"),
+        (
+            env.current_contract_address(),
+            claim_id,
+            payee.clone(),
+            asset.clone(),
+            Some(amount),
+            None::<i128>,
+        )
+            .into_val(env),
+    );
+    Ok(())
+}
+
+/// Close out a structured settlement once nothing more is owed on it
+fn complete_payment_schedule(
+    env: &Env,
+    claim_id: u64,
+    mut claim: (u64, Address, i128, ClaimStatus, u64),
+    schedule: &PaymentSchedule,
+) {
+    claim.3 = ClaimStatus::Settled;
+    env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+
+    let mut payees: Vec<(Address, i128)> = Vec::new(env);
+    payees.push_back((schedule.payee.clone(), schedule.paid_amount));
+    env.storage().persistent().set(&(CLAIM_PAYEES, claim_id), &payees);
+    if let Some(mut amounts) = env
+        .storage()
+        .persistent()
+        .get::<_, ClaimAmounts>(&(CLAIM_AMOUNTS, claim_id))
+    {
+        amounts.paid_amount = schedule.paid_amount;
+        env.storage().persistent().set(&(CLAIM_AMOUNTS, claim_id), &amounts);
+    }
+    mark_milestone(env, claim_id, SlaStage::Payment);
+
+    env.events().publish(
+        (Symbol::new(env, "ClaimSettled"), claim_id),
+        (schedule.payee.clone(), claim.1, schedule.paid_amount, env.ledger().timestamp()),
+    );
+}
+
+/// Key under which an evidence item's hash is anchored in the shared evidence registry.
+fn evidence_key(env: &Env, claim_id: u64, evidence_id: u32) -> BytesN<32> {
+    env.crypto().sha256(&(claim_id, evidence_id).to_xdr(env)).into()
+}
+
+/// Append evidence to a claim, anchoring each content hash immutably.
+/// Returns the number of items now attached.
+fn add_evidence(
+    env: &Env,
+    claim_id: u64,
+    submitter: &Address,
+    items: Vec<EvidenceSubmission>,
+) -> Result<u32, ContractError> {
+    let mut evidence: Vec<EvidenceItem> = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM_EVIDENCE, claim_id))
+        .unwrap_or_else(|| Vec::new(env));
+    if evidence.len() + items.len() > MAX_EVIDENCE_ITEMS {
+        return Err(ContractError::InvalidInput);
+    }
+
+    let now = env.ledger().timestamp();
+    for item in items.iter() {
+        if item.uri.len() == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        let id = evidence.len();
+        store_claim_evidence(
+            env,
+            evidence_key(env, claim_id, id),
+            item.content_hash.clone(),
+            submitter.clone(),
+        )
+        .map_err(|_| ContractError::AlreadyExists)?;
+
+        env.events().publish(
+            (Symbol::new(env, "EvidenceAttached"), claim_id),
+            (id, item.evidence_type.clone(), item.content_hash.clone(), submitter.clone()),
+        );
+
+        evidence.push_back(EvidenceItem {
+            id,
+            content_hash: item.content_hash,
+            uri: item.uri,
+            evidence_type: item.evidence_type,
+            submitted_by: submitter.clone(),
+            submitted_at: now,
+            status: EvidenceStatus::Pending,
+            reviewed_by: None,
+            reviewed_at: None,
+        });
+    }
+
+    env.storage().persistent().set(&(CLAIM_EVIDENCE, claim_id), &evidence);
+    Ok(evidence.len())
+}
+
+/// Every evidence type the claim's product requires must have at least one verified item.
+fn require_verified_evidence(env: &Env, claim_id: u64) -> Result<(), ContractError> {
+    let product_id: Option<u64> = env.storage().persistent().get(&(CLAIM_PRODUCT, claim_id));
+    let required: Vec<Symbol> = match product_id {
+        Some(product_id) => env
+            .storage()
+            .persistent()
+            .get(&(REQUIRED_EVIDENCE, product_id))
+            .unwrap_or_else(|| Vec::new(env)),
+        None => return Ok(()),
+    };
+    let evidence: Vec<EvidenceItem> = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM_EVIDENCE, claim_id))
+        .unwrap_or_else(|| Vec::new(env));
+
+    for evidence_type in required.iter() {
+        let verified = evidence
+            .iter()
+            .any(|item| item.evidence_type == evidence_type && item.status == EvidenceStatus::Verified);
+        if !verified {
+            return Err(ContractError::EvidenceRequired);
+        }
+    }
+    Ok(())
+}
+
+/// Tier that applies to an approved amount; a single processor suffices when none are set.
+fn approval_tier(env: &Env, approved_amount: i128) -> ApprovalTier {
+    let tiers: Vec<ApprovalTier> = env
+        .storage()
+        .persistent()
+        .get(&APPROVAL_TIERS)
+        .unwrap_or_else(|| Vec::new(env));
+    for tier in tiers.iter() {
+        if approved_amount < tier.max_amount {
+            return tier;
+        }
+    }
+    tiers.last().unwrap_or(ApprovalTier {
+        max_amount: i128::MAX,
+        required_approvals: 1,
+        auditor_required: false,
+    })
+}
+
+fn approval_hash(env: &Env, claim_id: u64, approved_amount: i128) -> BytesN<32> {
+    env.crypto().sha256(&(claim_id, approved_amount).to_xdr(env)).into()
+}
+
+/// Discard signatures collected for a claim whose details have changed.
+fn expire_pending_approval(env: &Env, claim_id: u64) {
+    let pending: Option<PendingApproval> =
+        env.storage().persistent().get(&(PENDING_APPROVAL, claim_id));
+    if let Some(pending) = pending {
+        clear_approvals(env, pending.action_hash);
+        env.storage().persistent().remove(&(PENDING_APPROVAL, claim_id));
+        env.events().publish(
+            (Symbol::new(env, "ClaimApprovalsExpired"), claim_id),
+            (pending.approved_amount, pending.approvals.len()),
+        );
+    }
+}
+
+/// Record a claimant's filing time for the claim-frequency signal. Filings older than the
+/// configured frequency window are dropped, and at most `MAX_CLAIMANT_HISTORY` are kept.
+fn record_claimant_filing(env: &Env, claimant: &Address, filed_at: u64) {
+    let fraud_config: Option<FraudScreeningConfig> = env.storage().persistent().get(&FRAUD_CONFIG);
+    let window_start =
+        fraud_config.map_or(0, |config| filed_at.saturating_sub(config.frequency_window_secs));
+
+    let history: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&(CLAIMANT_HISTORY, claimant.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+    let mut kept = Vec::new(env);
+    for earlier in history.iter() {
+        if earlier >= window_start {
+            kept.push_back(earlier);
+        }
+    }
+    kept.push_back(filed_at);
+    while kept.len() > MAX_CLAIMANT_HISTORY {
+        kept.pop_front();
+    }
+    env.storage()
+        .persistent()
+        .set(&(CLAIMANT_HISTORY, claimant.clone()), &kept);
+}
+
+/// Score `claim` against the fraud screening signals and store the score.
+/// `screened_by` is the processor, or this contract when screened on submission.
+fn screen(
+    env: &Env,
+    fraud_config: &FraudScreeningConfig,
+    claim_id: u64,
+    claim: &(u64, Address, i128, ClaimStatus, u64),
+    screened_by: Address,
+) -> Result<FraudScore, ContractError> {
+    // Slashing history as a claim submitter or policyholder
+    let violation_count = match &fraud_config.slashing_contract {
+        Some(slashing_contract) => {
+            require_trusted_contract(env, slashing_contract)?;
+            let slashing = SlashingClient::new(env, slashing_contract);
+            slashing
+                .get_violation_count(&claim.1, &fraud::CLAIM_SUBMITTER_ROLE)
+                .saturating_add(slashing.get_violation_count(&claim.1, &fraud::POLICY_HOLDER_ROLE))
+        }
+        None => 0,
+    };
+
+    // Other claims the claimant filed within the frequency window
+    let history: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&(CLAIMANT_HISTORY, claim.1.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+    let window_start = claim.4.saturating_sub(fraud_config.frequency_window_secs);
+    let recent_claims = history
+        .iter()
+        .filter(|filed_at| *filed_at >= window_start && *filed_at <= claim.4)
+        .count() as u32;
+
+    // How soon after cover started the claim was filed
+    let config: (Address, Address) =
+        env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+    require_trusted_contract(env, &config.0)?;
+    let (start_time, _) = PolicyClient::new(env, &config.0).get_policy_dates(&claim.0);
+    let secs_since_issuance = claim.4.saturating_sub(start_time);
+
+    // Risk-monitoring alerts raised within the alert window
+    let now = env.ledger().timestamp();
+    let active_alerts = match &fraud_config.risk_monitoring_contract {
+        Some(risk_monitoring_contract) => {
+            require_trusted_contract(env, risk_monitoring_contract)?;
+            let alerts_from = now.saturating_sub(fraud_config.alert_window_secs);
+            RiskMonitoringClient::new(env, risk_monitoring_contract)
+                .get_alert_history()
+                .iter()
+                .filter(|alert| alert.timestamp >= alerts_from)
+                .count() as u32
+        }
+        None => 0,
+    };
+
+    let score = fraud::score(
+        fraud_config,
+        violation_count,
+        recent_claims.saturating_sub(1),
+        secs_since_issuance,
+        active_alerts,
+        screened_by.clone(),
+        now,
+    );
+    env.storage().persistent().set(&(FRAUD_SCORE, claim_id), &score);
+
+    // Signatures were given before the screening result was known
+    expire_pending_approval(env, claim_id);
+
+    env.events().publish(
+        (Symbol::new(env, "ClaimScreened"), claim_id),
+        (screened_by, score.total, score.flagged),
+    );
+    if score.flagged {
+        env.events().publish(
+            (Symbol::new(env, "ClaimFlagged"), claim_id),
+            (claim.1.clone(), score.total, score.action.clone()),
+        );
+    }
+
+    Ok(score)
+}
+
+/// Record `processor`'s signature on approving the claim at `approved_amount`.
+/// Returns true once the amount's tier is satisfied and the approval can go ahead.
+fn collect_approval(
+    env: &Env,
+    processor: &Address,
+    claim_id: u64,
+    approved_amount: i128,
+    adjustment_reason: &Option<Symbol>,
+) -> Result<bool, ContractError> {
+    let mut tier = approval_tier(env, approved_amount);
+
+    // Flagged claims may need an extra processor or an Auditor on top of the amount tier
+    let fraud_score: Option<FraudScore> = env.storage().persistent().get(&(FRAUD_SCORE, claim_id));
+    match fraud_score.and_then(|score| score.action) {
+        Some(FraudAction::ExtraApproval) => tier.required_approvals += 1,
+        Some(FraudAction::RouteToAuditor) => tier.auditor_required = true,
+        _ => {}
+    }
+
+    if tier.required_approvals <= 1 && !tier.auditor_required {
+        return Ok(true);
+    }
+
+    // Signatures for a different amount no longer apply
+    let action_hash = approval_hash(env, claim_id, approved_amount);
+    let existing: Option<PendingApproval> =
+        env.storage().persistent().get(&(PENDING_APPROVAL, claim_id));
+    let mut pending = match existing {
+        Some(pending) if pending.action_hash == action_hash => pending,
+        _ => {
+            expire_pending_approval(env, claim_id);
+            PendingApproval {
+                approved_amount,
+                adjustment_reason: adjustment_reason.clone(),
+                action_hash: action_hash.clone(),
+                required_approvals: tier.required_approvals,
+                approvals: Vec::new(env),
+                auditor_required: tier.auditor_required,
+                auditor: None,
+                processors_ready: false,
+                opened_at: env.ledger().timestamp(),
+            }
+        }
+    };
+
+    if !pending.processors_ready {
+        pending.processors_ready = check_multisig_auth_with_threshold(
+            env,
+            processor,
+            action_hash,
+            Role::ClaimProcessor,
+            tier.required_approvals,
+        )?;
+        if !pending.approvals.contains(processor) {
+            pending.approvals.push_back(processor.clone());
+        }
+    }
+
+    if pending.processors_ready && (!pending.auditor_required || pending.auditor.is_some()) {
+        env.storage().persistent().remove(&(PENDING_APPROVAL, claim_id));
+        return Ok(true);
+    }
+
+    env.storage().persistent().set(&(PENDING_APPROVAL, claim_id), &pending);
+    env.events().publish(
+        (Symbol::new(env, "ClaimApprovalPending"), claim_id),
+        (
+            processor.clone(),
+            approved_amount,
+            pending.approvals.len(),
+            pending.required_approvals,
+            pending.auditor_required && pending.auditor.is_none(),
+        ),
+    );
+    Ok(false)
+}
+
+/// Append an entry to a claim's revision history
+fn record_revision(
+    env: &Env,
+    claim_id: u64,
+    kind: RevisionKind,
+    amount: i128,
+    reason: Option<Symbol>,
+) -> u32 {
+    let mut revisions: Vec<ClaimRevision> = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM_REVISIONS, claim_id))
+        .unwrap_or_else(|| Vec::new(env));
+    let evidence_count = env
+        .storage()
+        .persistent()
+        .get::<_, Vec<EvidenceItem>>(&(CLAIM_EVIDENCE, claim_id))
+        .map_or(0, |evidence| evidence.len());
+    let revision = revisions.len();
+    revisions.push_back(ClaimRevision {
+        revision,
+        kind,
+        amount,
+        evidence_count,
+        reason,
+        revised_at: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(&(CLAIM_REVISIONS, claim_id), &revisions);
+    revision
+}
+
+/// Record that a claim reached an SLA milestone now
+fn mark_milestone(env: &Env, claim_id: u64, stage: SlaStage) {
+    let key = (CLAIM_MILESTONES, claim_id);
+    let Some(mut milestones) = env.storage().persistent().get::<_, ClaimMilestones>(&key) else {
+        return;
+    };
+    let now = Some(env.ledger().timestamp());
+    match stage {
+        SlaStage::Review => milestones.review_started_at = now,
+        SlaStage::Decision => milestones.decided_at = now,
+        SlaStage::Payment => milestones.paid_at = now,
+    }
+    env.storage().persistent().set(&key, &milestones);
+}
+
+/// SLA for a product, falling back to the default SLA
+fn claim_sla(env: &Env, product_id: Option<u64>) -> Option<ClaimSla> {
+    product_id
+        .and_then(|product_id| env.storage().persistent().get(&(CLAIM_SLA, Some(product_id))))
+        .or_else(|| env.storage().persistent().get(&(CLAIM_SLA, None::<u64>)))
+}
+
+/// Draw `amount` down from the claim's policy (or certificate) and reserve what was drawn
+/// in the risk pool. Returns the amount actually drawn and reserved.
+fn draw_and_reserve(env: &Env, claim_id: u64, policy_id: u64, amount: i128) -> Result<i128, ContractError> {
+    let config: (Address, Address) =
+        env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+    require_trusted_contract(env, &config.0)?;
+    require_trusted_contract(env, &config.1)?;
+
+    // The policy caps the draw by the per-occurrence limit and remaining coverage so
+    // concurrent claims cannot over-draw. Member claims also draw through their certificate.
+    let certificate_id: Option<u64> =
+        env.storage().persistent().get(&(CLAIM_CERTIFICATE, claim_id));
+    let drawn: i128 = match certificate_id {
+        Some(certificate_id) => env.invoke_contract(
+            &config.0,
+            &Symbol::new(env, "draw_down_certificate"),
+            (env.current_contract_address(), certificate_id, claim_id, amount).into_val(env),
+        ),
+        None => env.invoke_contract(
+            &config.0,
+            &Symbol::new(env, "draw_down_coverage"),
+            (env.current_contract_address(), policy_id, claim_id, amount).into_val(env),
+        ),
+    };
+
+    env.invoke_contract::<()>(
+        &config.1,
+        &Symbol::new(env, "reserve_liquidity"),
+        (env.current_contract_address(), claim_id, drawn).into_val(env),
+    );
+
+    Ok(drawn)
+}
+
+/// Move an approved claim's drawdown and risk pool reservation to `new_amount`.
+/// Returns the amount now drawn and reserved.
+fn resize_claim_payout(
+    env: &Env,
+    claim_id: u64,
+    policy_id: u64,
+    new_amount: i128,
+) -> Result<i128, ContractError> {
+    let config: (Address, Address) =
+        env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+    require_trusted_contract(env, &config.0)?;
+    require_trusted_contract(env, &config.1)?;
+
+    let certificate_id: Option<u64> =
+        env.storage().persistent().get(&(CLAIM_CERTIFICATE, claim_id));
+    let drawn: i128 = env.invoke_contract(
+        &config.0,
+        &Symbol::new(env, "adjust_claim_drawdown"),
+        (env.current_contract_address(), policy_id, claim_id, certificate_id, new_amount)
+            .into_val(env),
+    );
+
+    env.invoke_contract::<()>(
+        &config.1,
+        &Symbol::new(env, "adjust_reservation"),
+        (env.current_contract_address(), claim_id, drawn).into_val(env),
+    );
+
+    Ok(drawn)
+}
+
+/// Apply a dispute's binding outcome to its claim.
+/// An upheld dispute approves the claim for the loss the claimant requested, assessed under
+/// the policy terms exactly as on approval; a dismissed one restores the contested decision.
+fn resolve_dispute(
+    env: &Env,
+    claim_id: u64,
+    mut dispute: Dispute,
+    outcome: DisputeOutcome,
+) -> Result<(), ContractError> {
+    let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM, claim_id))
+        .ok_or(ContractError::NotFound)?;
+
+    let mut net_amount: i128 = 0;
+    match outcome {
+        DisputeOutcome::Upheld => {
+            let amounts = match dispute.contested_status {
+                ClaimStatus::Approved => {
+                    // A larger loss than the one approved stays above the deductible
+                    let previous: ClaimAmounts = env
+                        .storage()
+                        .persistent()
+                        .get(&(CLAIM_AMOUNTS, claim_id))
+                        .ok_or(ContractError::NotFound)?;
+                    let mut amounts =
+                        assess_claim(env, claim_id, claim.0, dispute.requested_amount)?;
+                    amounts.gross_amount = claim.2;
+                    amounts.paid_amount = previous.paid_amount;
+                    let drawn = resize_claim_payout(env, claim_id, claim.0, amounts.net_amount)?;
+                    amounts.limit_reduction = amounts.net_amount - drawn;
+                    amounts.net_amount = drawn;
+                    Some(amounts)
+                }
+                _ => {
+                    // The upheld amount is assessed under the policy terms exactly as on approval
+                    match assess_claim(env, claim_id, claim.0, dispute.requested_amount) {
+                        Err(ContractError::BelowDeductible) => None,
+                        result => {
+                            let mut amounts = result?;
+                            amounts.gross_amount = claim.2;
+                            let drawn =
+                                draw_and_reserve(env, claim_id, claim.0, amounts.net_amount)?;
+                            amounts.limit_reduction = amounts.net_amount - drawn;
+                            amounts.net_amount = drawn;
+                            Some(amounts)
+                        }
+                    }
+                }
+            };
+            match amounts {
+                Some(amounts) => {
+                    net_amount = amounts.net_amount;
+                    env.storage().persistent().set(&(CLAIM_AMOUNTS, claim_id), &amounts);
+                    claim.3 = ClaimStatus::Approved;
+                }
+                None => {
+                    // Nothing above the deductible is owed even on the claimant's own figure
+                    claim.3 = ClaimStatus::Rejected;
+                    env.events().publish(
+                        (Symbol::new(env, "ClaimRejectionReason"), claim_id),
+                        (Symbol::new(env, "below_deductible"), dispute.requested_amount),
+                    );
+                }
+            }
+        }
+        DisputeOutcome::Dismissed => {
+            claim.3 = dispute.contested_status.clone();
+        }
+    }
+
+    let now = env.ledger().timestamp();
+    dispute.outcome = Some(outcome.clone());
+    dispute.resolved = true;
+    dispute.resolved_at = Some(now);
+
+    env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+    env.storage().persistent().set(&(DISPUTE, claim_id), &dispute);
+
+    env.events().publish(
+        (Symbol::new(env, "DisputeResolved"), claim_id),
+        (outcome, claim.3, net_amount, now),
+    );
+
+    Ok(())
+}
+
 #[contractimpl]
 impl ClaimsContract {
     pub fn initialize(
@@ -389,12 +1346,17 @@ impl ClaimsContract {
     /// Submit a new claim for a policy.
     /// Uses sequential claim IDs for predictable indexing.
     /// Supports multi-asset claims with optional payout preference.
+    /// Members of a group policy pass the master `policy_id` and their `certificate_id`.
     pub fn submit_claim(
         env: Env,
         claimant: Address,
         policy_id: u64,
         amount: i128,
+        loss_timestamp: u64,
         payout_preference: Option<shared::types::ClaimPayoutPreference>,
+        peril: Option<Symbol>,
+        certificate_id: Option<u64>,
+        evidence: Vec<EvidenceSubmission>,
     ) -> Result<u64, ContractError> {
         // 1. IDENTITY CHECK
         claimant.require_auth();
@@ -419,28 +1381,71 @@ impl ClaimsContract {
         // 2. FETCH POLICY DATA
         let (policy_contract_addr, _): (Address, Address) =
             env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        require_trusted_contract(&env, &policy_contract_addr)?;
+        let policy = PolicyClient::new(&env, &policy_contract_addr);
 
-        // TODO: Replace with contractimport + client calls once the policy wasm artifact
-        // is available during tests/build.
-        let policy = (claimant.clone(), amount);
+        let holder = match policy.try_get_policy_holder(&policy_id) {
+            Ok(Ok(holder)) => holder,
+            _ => return Err(ContractError::PolicyNotFound),
+        };
 
-        // 3. OWNERSHIP CHECK (Verify policyholder identity)
-        if policy.0 != claimant {
-            return Err(ContractError::Unauthorized);
+        // 3. OWNERSHIP CHECK (Verify policyholder identity, or certificate member for group cover)
+        let certificate = match certificate_id {
+            Some(certificate_id) => {
+                let certificate = match policy.try_get_certificate(&certificate_id) {
+                    Ok(Ok(certificate)) => certificate,
+                    _ => return Err(ContractError::NotFound),
+                };
+                if certificate.master_policy_id != policy_id {
+                    return Err(ContractError::InvalidInput);
+                }
+                if certificate.member != claimant {
+                    return Err(ContractError::NotPolicyholder);
+                }
+                if certificate.status != CertificateStatus::Active {
+                    return Err(ContractError::CertificateNotActive);
+                }
+                Some(certificate)
+            }
+            None => {
+                if holder != claimant {
+                    return Err(ContractError::NotPolicyholder);
+                }
+                None
+            }
+        };
+
+        // 4. STATE CHECK
+        if policy.get_policy_state(&policy_id) != PolicyState::ACTIVE {
+            return Err(ContractError::PolicyNotActive);
         }
 
-        // 4. DUPLICATE CHECK (Check if this specific policy already has a claim)
-        if env.storage().persistent().has(&(POLICY_CLAIM, policy_id)) {
-            return Err(ContractError::AlreadyExists);
+        // 5. LOSS DATE CHECK — the policy judges the loss date against the terms in force then,
+        // covering the grace period and excluding lapse windows (and the member's enrollment)
+        if loss_timestamp > env.ledger().timestamp() {
+            return Err(ContractError::InvalidInput);
+        }
+        let terms = match policy.try_get_policy_terms_at(&policy_id, &loss_timestamp) {
+            Ok(Ok(terms)) => terms,
+            _ => return Err(ContractError::LossOutsideCoveragePeriod),
+        };
+        if certificate.as_ref().map_or(false, |c| loss_timestamp < c.enrolled_at) {
+            return Err(ContractError::LossOutsideCoveragePeriod);
         }
 
-        // 5. COVERAGE CHECK — validate amount is positive and does not exceed coverage
+        // 6. COVERAGE CHECK — validate amount is positive and fits both the coverage in force
+        // at the loss date and what is still undrawn
         if amount <= 0 {
             return Err(ContractError::InvalidAmount);
         }
-        if amount > policy.1 {
-            return Err(ContractError::CoverageExceeded);
-        }
+        let remaining_coverage = match certificate {
+            Some(certificate) => policy.get_certificate_remaining_coverage(&certificate.id),
+            None => policy.get_remaining_coverage(&policy_id),
+        };
+        validate_coverage_constraint(amount, remaining_coverage.min(terms.coverage_amount))?;
+
+        // The product decides which evidence must be verified before approval
+        let product_id = policy.get_policy_product(&policy_id);
 
         // ID Generation
         let seq: u64 = env.ledger().sequence().into();
@@ -468,10 +1473,56 @@ impl ClaimsContract {
             .persistent()
             .set(&(symbol_short!("PAYOUT"), claim_id), &payout_pref);
 
-        // Map policy to claim for duplicate prevention
+        // Peril drives which sub-limit applies at approval
+        if let Some(peril) = peril {
+            env.storage().persistent().set(&(CLAIM_PERIL, claim_id), &peril);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(CLAIM_LOSS, claim_id), &loss_timestamp);
+
+        // Certificate claims are capped by the member's cover at approval
+        if let Some(certificate_id) = certificate_id {
+            env.storage()
+                .persistent()
+                .set(&(CLAIM_CERTIFICATE, claim_id), &certificate_id);
+        }
+
+        if let Some(product_id) = product_id {
+            env.storage().persistent().set(&(CLAIM_PRODUCT, claim_id), &product_id);
+        }
+        env.storage().persistent().set(
+            &(CLAIM_MILESTONES, claim_id),
+            &ClaimMilestones {
+                submitted_at: current_time,
+                review_started_at: None,
+                decided_at: None,
+                paid_at: None,
+            },
+        );
+
+        // Claimant's filing times feed the claim-frequency fraud signal
+        record_claimant_filing(&env, &claimant, current_time);
+        add_evidence(&env, claim_id, &claimant, evidence)?;
+        record_revision(&env, claim_id, RevisionKind::Submitted, amount, None);
+
+        // Every claim is screened on submission once fraud screening is configured
+        if let Some(fraud_config) = env.storage().persistent().get::<_, FraudScreeningConfig>(&FRAUD_CONFIG) {
+            let claim = (policy_id, claimant.clone(), amount, ClaimStatus::Submitted, current_time);
+            screen(&env, &fraud_config, claim_id, &claim, env.current_contract_address())?;
+        }
+
+        // Index the claim under its policy; a policy may carry several claims up to its aggregate limit
+        let mut policy_claims: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&(POLICY_CLAIMS, policy_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        policy_claims.push_back(claim_id);
         env.storage()
             .persistent()
-            .set(&(POLICY_CLAIM, policy_id), &claim_id);
+            .set(&(POLICY_CLAIMS, policy_id), &policy_claims);
 
         // Add claim ID to the claim list for efficient querying
         let mut claim_list: Vec<u64> = env
@@ -527,6 +1578,93 @@ impl ClaimsContract {
         processor: Address,
         claim_id: u64,
         oracle_data_id: Option<u64>,
+    ) -> Result<(), ContractError> {
+        Self::approve(env, processor, claim_id, None, oracle_data_id)
+    }
+
+    /// Approve less than the requested loss, recording a reason code.
+    /// On a claim still under review this approves `approved_amount`; on an approved claim
+    /// awaiting settlement it lowers the approval and releases the difference from the pool.
+    pub fn approve_claim_partial(
+        env: Env,
+        processor: Address,
+        claim_id: u64,
+        approved_amount: i128,
+        reason_code: Symbol,
+        oracle_data_id: Option<u64>,
+    ) -> Result<(), ContractError> {
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.3 != ClaimStatus::Approved {
+            return Self::approve(
+                env,
+                processor,
+                claim_id,
+                Some((approved_amount, reason_code)),
+                oracle_data_id,
+            );
+        }
+
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "approve_claim")))?;
+
+        // A payment schedule already commits the approved amount to its installments
+        if env.storage().persistent().has(&(PAYMENT_SCHEDULE, claim_id)) {
+            return Err(ContractError::InvalidClaimState);
+        }
+
+        let mut amounts: ClaimAmounts = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_AMOUNTS, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if approved_amount <= 0 || approved_amount >= amounts.approved_amount {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let terms = coverage_terms_at_loss(&env, claim_id, claim.0)?;
+        let reassessed = calculate_net_payable(approved_amount, &terms, amounts.peril.clone())?;
+
+        // Shrink the drawdown and reservation; the released amount returns to the pool
+        let target = reassessed.net_amount.min(amounts.net_amount);
+        let net_amount = resize_claim_payout(&env, claim_id, claim.0, target)?;
+        let released = amounts.net_amount - net_amount;
+
+        amounts.approved_amount = approved_amount;
+        amounts.adjustment_reason = Some(reason_code.clone());
+        amounts.sub_limit_reduction = reassessed.sub_limit_reduction;
+        amounts.coinsurance_amount = reassessed.coinsurance_amount;
+        amounts.limit_reduction = reassessed.net_amount - net_amount;
+        amounts.net_amount = net_amount;
+        env.storage().persistent().set(&(CLAIM_AMOUNTS, claim_id), &amounts);
+
+        env.events().publish(
+            (Symbol::new(&env, "ClaimApprovalAdjusted"), claim_id),
+            (
+                processor,
+                amounts.gross_amount,
+                approved_amount,
+                net_amount,
+                released,
+                reason_code,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Approve a claim under review for `approved` (amount and reason code), or in full.
+    fn approve(
+        env: Env,
+        processor: Address,
+        claim_id: u64,
+        approved: Option<(i128, Symbol)>,
+        oracle_data_id: Option<u64>,
     ) -> Result<(), ContractError> {
         // Verify identity and require claim processing permission
         processor.require_auth();
@@ -535,7 +1673,7 @@ impl ClaimsContract {
         // Check for emergency pause
         EmergencyPause::validate_not_paused(&env, Some(&symbol_short!("approve_claim")))?;
 
-        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
             .persistent()
             .get(&(CLAIM, claim_id))
@@ -551,11 +1689,30 @@ impl ClaimsContract {
             return Err(ContractError::InvalidAmount);
         }
 
-        // Check if oracle validation is required
-        if let Some(oracle_config) = env.storage().persistent().get::<_, OracleValidationConfig>(&ORACLE_CONFIG) {
-            if oracle_config.require_oracle_validation {
-                if let Some(oracle_id) = oracle_data_id {
-                    // Verify oracle contract is trusted
+        // A partial approval may not exceed what was requested
+        let (approved_amount, adjustment_reason) = match approved {
+            Some((amount, reason_code)) => (amount, Some(reason_code)),
+            None => (claim.2, None),
+        };
+        if approved_amount <= 0 || approved_amount > claim.2 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        // The product's required evidence must be present and verified
+        require_verified_evidence(&env, claim_id)?;
+
+        // Claims must pass fraud screening first once it is configured
+        if env.storage().persistent().has(&FRAUD_CONFIG)
+            && !env.storage().persistent().has(&(FRAUD_SCORE, claim_id))
+        {
+            return Err(ContractError::FraudScreeningRequired);
+        }
+
+        // Check if oracle validation is required
+        if let Some(oracle_config) = env.storage().persistent().get::<_, OracleValidationConfig>(&ORACLE_CONFIG) {
+            if oracle_config.require_oracle_validation {
+                if let Some(oracle_id) = oracle_data_id {
+                    // Verify oracle contract is trusted
                     require_trusted_contract(&env, &oracle_config.oracle_contract)?;
 
                     // Validate using oracle data (store oracle data ID)
@@ -573,29 +1730,97 @@ impl ClaimsContract {
             }
         }
 
-        let config: (Address, Address) =
-            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
-        let risk_pool_contract = config.1.clone();
+        // Larger approvals need several processors, and possibly an Auditor, to sign the amount
+        if !collect_approval(&env, &processor, claim_id, approved_amount, &adjustment_reason)? {
+            return Ok(());
+        }
 
-        // Verify risk pool is a trusted contract before invoking
-        require_trusted_contract(&env, &risk_pool_contract)?;
+        Self::finalize_approval(env, processor, claim_id, approved_amount, adjustment_reason)
+    }
 
-        env.invoke_contract::<()>(
-            &risk_pool_contract,
-            &Symbol::new(&env, "reserve_liquidity"),
-            (claim_id, claim.2).into_val(&env),
-        );
+    /// Apply the policy terms to the approved amount, reserve the payout and mark the claim
+    /// Approved. `processor` is recorded as the decision maker.
+    fn finalize_approval(
+        env: Env,
+        processor: Address,
+        claim_id: u64,
+        approved_amount: i128,
+        adjustment_reason: Option<Symbol>,
+    ) -> Result<(), ContractError> {
+        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if !is_valid_state_transition(claim.3.clone(), ClaimStatus::Approved) {
+            return Err(ContractError::InvalidClaimState);
+        }
+
+        // Apply the policy's deductible, co-insurance and peril sub-limit to the gross loss.
+        // A loss the deductible absorbs entirely has nothing to pay and is rejected.
+        let mut amounts = match assess_claim(&env, claim_id, claim.0, approved_amount) {
+            Err(ContractError::BelowDeductible) => {
+                claim.3 = ClaimStatus::Rejected;
+                env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+                env.storage()
+                    .persistent()
+                    .set(&(CLAIM_DECISION, claim_id), &(processor.clone(), env.ledger().timestamp()));
+                mark_milestone(&env, claim_id, SlaStage::Decision);
+
+                env.events()
+                    .publish((Symbol::new(&env, "claim_rejected"), claim_id), (claim.1.clone(), claim.2));
+                env.events().publish(
+                    (Symbol::new(&env, "ClaimRejected"), claim_id),
+                    (processor, claim.1, claim.2, env.ledger().timestamp()),
+                );
+                env.events().publish(
+                    (Symbol::new(&env, "ClaimRejectionReason"), claim_id),
+                    (Symbol::new(&env, "below_deductible"), approved_amount),
+                );
+                return Ok(());
+            }
+            result => result?,
+        };
+        amounts.gross_amount = claim.2;
+        amounts.adjustment_reason = adjustment_reason.clone();
+
+        // Draw the net amount down from the policy's aggregate and reserve it in the pool
+        let drawn = draw_and_reserve(&env, claim_id, claim.0, amounts.net_amount)?;
+        amounts.limit_reduction = amounts.net_amount - drawn;
+        amounts.net_amount = drawn;
 
         // I3: Transition to Approved state
         claim.3 = ClaimStatus::Approved;
 
         env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        env.storage().persistent().set(&(CLAIM_AMOUNTS, claim_id), &amounts);
+        env.storage()
+            .persistent()
+            .set(&(CLAIM_DECISION, claim_id), &(processor.clone(), env.ledger().timestamp()));
+        mark_milestone(&env, claim_id, SlaStage::Decision);
 
-        env.events().publish((symbol_short!("clm_app"), claim_id), (claim.1, claim.2));
+        env.events().publish((symbol_short!("clm_app"), claim_id), (claim.1.clone(), claim.2));
 
 
         env.events().publish((Symbol::new(&env, "ClaimApproved"), claim_id), (processor, claim.1, claim.2, env.ledger().timestamp()));
 
+        env.events().publish(
+            (Symbol::new(&env, "ClaimNetCalculated"), claim_id),
+            (
+                amounts.gross_amount,
+                amounts.deductible_amount,
+                amounts.coinsurance_amount,
+                amounts.net_amount,
+            ),
+        );
+
+        if let Some(reason_code) = adjustment_reason {
+            env.events().publish(
+                (Symbol::new(&env, "ClaimPartiallyApproved"), claim_id),
+                (amounts.gross_amount, approved_amount, amounts.net_amount, reason_code),
+            );
+        }
+
 
     Ok(())
     }
@@ -623,6 +1848,7 @@ impl ClaimsContract {
         claim.3 = ClaimStatus::UnderReview;
 
         env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        mark_milestone(&env, claim_id, SlaStage::Review);
 
         env.events()
             .publish((Symbol::new(&env, "claim_under_review"), claim_id), (claim.1, claim.2));
@@ -655,8 +1881,13 @@ impl ClaimsContract {
 
         // I3: Transition to Rejected state
         claim.3 = ClaimStatus::Rejected;
+        expire_pending_approval(&env, claim_id);
 
         env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        env.storage()
+            .persistent()
+            .set(&(CLAIM_DECISION, claim_id), &(processor.clone(), env.ledger().timestamp()));
+        mark_milestone(&env, claim_id, SlaStage::Decision);
 
         env.events()
             .publish((Symbol::new(&env, "claim_rejected"), claim_id), (claim.1, claim.2));
@@ -668,11 +1899,15 @@ impl ClaimsContract {
     Ok(())
     }
 
+    /// Settle an approved claim, paying the net amount to the policy's beneficiaries.
+    /// `unavailable_beneficiaries` lists primaries the processor has confirmed cannot take
+    /// payment; their shares go to the contingent beneficiary.
     pub fn settle_claim(
         env: Env,
         processor: Address,
         claim_id: u64,
         payout_asset: Option<shared::types::Asset>,
+        unavailable_beneficiaries: Vec<Address>,
     ) -> Result<(), ContractError> {
         // Verify identity and require claim processing permission
         processor.require_auth();
@@ -691,6 +1926,10 @@ impl ClaimsContract {
         if !is_valid_state_transition(claim.3.clone(), ClaimStatus::Settled) {
             return Err(ContractError::InvalidClaimState);
         }
+        // Structured settlements are paid out by `release_installments`
+        if env.storage().persistent().has(&(PAYMENT_SCHEDULE, claim_id)) {
+            return Err(ContractError::InvalidClaimState);
+        }
 
         // I4: Amount must be positive
         if claim.2 <= 0 {
@@ -708,37 +1947,1388 @@ impl ClaimsContract {
                 alternatives: Vec::new(&env),
             });
 
-        // Determine payout asset (use provided or preferred)
-        let final_payout_asset = payout_asset.unwrap_or(payout_pref.preferred_asset.clone());
-
-        // Get risk pool contract address from config
+        // Get policy and risk pool contract addresses from config
         let config: (Address, Address) =
             env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        let policy_contract = config.0.clone();
         let risk_pool_contract = config.1.clone();
 
+        // The pool pays the net reservation made at approval
+        let amounts: Option<ClaimAmounts> = env.storage().persistent().get(&(CLAIM_AMOUNTS, claim_id));
+        let net_amount = amounts.as_ref().map_or(claim.2, |amounts| amounts.net_amount);
+
+        require_trusted_contract(&env, &policy_contract)?;
+        let designation: BeneficiaryDesignation = env.invoke_contract(
+            &policy_contract,
+            &Symbol::new(&env, "get_beneficiaries"),
+            (claim.0,).into_val(&env),
+        );
+        let payees = split_payout(&env, net_amount, &designation, &unavailable_beneficiaries, &claim.1)?;
+
         // Verify risk pool is a trusted contract before invoking
         require_trusted_contract(&env, &risk_pool_contract)?;
 
-        // Call risk pool to payout the claim amount with asset preference
-        env.invoke_contract::<()>(
+        // Pay in the first accepted asset the pool can cover, converted at the registry's rate
+        let settlement = select_payout_asset(
+            &env,
             &risk_pool_contract,
-            &Symbol::new(&env, "payout_reserved_claim_multi_asset"),
-            (claim_id, claim.1.clone(), final_payout_asset).into_val(&env),
-        );
+            claim_id,
+            &payout_pref,
+            payout_asset,
+            net_amount,
+        )?;
+        let final_payout_asset = settlement.payout_asset.clone();
+
+        // The total is converted once; beneficiaries split it by their share of the claim
+        let mut shares = Vec::new(&env);
+        for (_, amount) in payees.iter() {
+            shares.push_back(amount);
+        }
+        let paid_amounts = settlement::split_payout_amount(
+            &env,
+            settlement.payout_amount,
+            net_amount,
+            &shares,
+        )
+        .ok_or(ContractError::Overflow)?;
+
+        // Pay each beneficiary's share out of the claim's reservation
+        for (i, (recipient, amount)) in payees.iter().enumerate() {
+            let paid = paid_amounts.get(i as u32).ok_or(ContractError::Overflow)?;
+            env.invoke_contract::<()>(
+                &risk_pool_contract,
+                &Symbol::new(&env, "payout_reserved_claim_multi_asset"),
+                (
+                    env.current_contract_address(),
+                    claim_id,
+                    recipient.clone(),
+                    final_payout_asset.clone(),
+                    Some(amount),
+                    Some(paid),
+                )
+                    .into_val(&env),
+            );
+
+            env.events().publish(
+                (Symbol::new(&env, "BeneficiaryPaid"), claim_id),
+                (recipient, paid, final_payout_asset.clone()),
+            );
+        }
 
         // I3: Transition to Settled state
         claim.3 = ClaimStatus::Settled;
 
-        env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        env.storage().persistent().set(&(CLAIM_PAYEES, claim_id), &payees);
+        env.storage().persistent().set(&(CLAIM_SETTLEMENT, claim_id), &settlement);
+        mark_milestone(&env, claim_id, SlaStage::Payment);
+        if let Some(mut amounts) = amounts {
+            amounts.paid_amount = net_amount;
+            env.storage().persistent().set(&(CLAIM_AMOUNTS, claim_id), &amounts);
+        }
+
+        env.events()
+            .publish((Symbol::new(&env, "claim_settled"), claim_id), (claim.1.clone(), net_amount));
+
+
+        env.events().publish((Symbol::new(&env, "ClaimSettled"), claim_id), (processor, claim.1, net_amount, env.ledger().timestamp()));
+
+        env.events().publish(
+            (Symbol::new(&env, "ClaimPayoutConverted"), claim_id),
+            (
+                settlement.claim_asset,
+                settlement.payout_asset,
+                settlement.payout_amount,
+                settlement.rate_bps,
+            ),
+        );
+
+
+    Ok(())
+    }
+
+    /// Pay an approved claim as a structured settlement: installments of the approved net
+    /// amount on fixed dates, to the claimant or one of the policy's beneficiaries.
+    pub fn set_payment_schedule(
+        env: Env,
+        processor: Address,
+        claim_id: u64,
+        payee: Address,
+        payments: Vec<ScheduledPayment>,
+    ) -> Result<(), ContractError> {
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.3 != ClaimStatus::Approved {
+            return Err(ContractError::InvalidClaimState);
+        }
+        if env.storage().persistent().has(&(PAYMENT_SCHEDULE, claim_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+        if payments.is_empty() || payments.len() > MAX_INSTALLMENTS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // Installments fall on strictly increasing dates and add up to the reserved net amount
+        let mut installments = Vec::new(&env);
+        let mut total: i128 = 0;
+        let mut last_due_at: Option<u64> = None;
+        for payment in payments.iter() {
+            validate_amount(payment.amount)?;
+            if last_due_at.map_or(false, |last| payment.due_at <= last) {
+                return Err(ContractError::InvalidInput);
+            }
+            last_due_at = Some(payment.due_at);
+            total = total.checked_add(payment.amount).ok_or(ContractError::Overflow)?;
+            installments.push_back(Installment {
+                due_at: payment.due_at,
+                amount: payment.amount,
+                paid_at: None,
+            });
+        }
+        let amounts: Option<ClaimAmounts> = env.storage().persistent().get(&(CLAIM_AMOUNTS, claim_id));
+        let net_amount = amounts.map_or(claim.2, |amounts| amounts.net_amount);
+        if total != net_amount {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let config: (Address, Address) =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        if payee != claim.1 {
+            require_trusted_contract(&env, &config.0)?;
+            let designation: BeneficiaryDesignation = env.invoke_contract(
+                &config.0,
+                &Symbol::new(&env, "get_beneficiaries"),
+                (claim.0,).into_val(&env),
+            );
+            if !designation.beneficiaries.iter().any(|b| b.recipient == payee) {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+
+        let payout_pref: shared::types::ClaimPayoutPreference = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("PAYOUT"), claim_id))
+            .unwrap_or(shared::types::ClaimPayoutPreference {
+                preferred_asset: shared::types::Asset::Native,
+                accept_alternative: true,
+                alternatives: Vec::new(&env),
+            });
+
+        let schedule = PaymentSchedule {
+            payee: payee.clone(),
+            payout_asset: payout_pref.preferred_asset,
+            installments,
+            total_amount: total,
+            paid_amount: 0,
+            commutation: None,
+            created_by: processor.clone(),
+            created_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&(PAYMENT_SCHEDULE, claim_id), &schedule);
+
+        env.events().publish(
+            (Symbol::new(&env, "PaymentScheduleSet"), claim_id),
+            (processor, payee, payments.len(), total),
+        );
+
+        Ok(())
+    }
+
+    /// Release every installment that has fallen due. Anyone may call this.
+    /// Returns the number of installments paid; the claim settles with the last one.
+    pub fn release_installments(env: Env, claim_id: u64) -> Result<u32, ContractError> {
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "release_installments")))?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.3 != ClaimStatus::Approved {
+            return Err(ContractError::InvalidClaimState);
+        }
+        let mut schedule: PaymentSchedule = env
+            .storage()
+            .persistent()
+            .get(&(PAYMENT_SCHEDULE, claim_id))
+            .ok_or(ContractError::NotFound)?;
+
+        let now = env.ledger().timestamp();
+        let mut released = 0u32;
+        for i in 0..schedule.installments.len() {
+            let mut installment = schedule.installments.get(i).unwrap();
+            if installment.paid_at.is_some() {
+                continue;
+            }
+            if installment.due_at > now {
+                break;
+            }
+
+            pay_from_reservation(&env, claim_id, &schedule.payee, &schedule.payout_asset, installment.amount)?;
+            installment.paid_at = Some(now);
+            schedule.installments.set(i, installment.clone());
+            schedule.paid_amount = schedule
+                .paid_amount
+                .checked_add(installment.amount)
+                .ok_or(ContractError::Overflow)?;
+            released += 1;
+
+            env.events().publish(
+                (Symbol::new(&env, "InstallmentPaid"), claim_id),
+                (i, schedule.payee.clone(), installment.amount, schedule.payout_asset.clone()),
+            );
+        }
+
+        env.storage().persistent().set(&(PAYMENT_SCHEDULE, claim_id), &schedule);
+        if schedule.paid_amount == schedule.total_amount {
+            complete_payment_schedule(&env, claim_id, claim, &schedule);
+        }
+
+        Ok(released)
+    }
+
+    /// Offer to pay the unpaid installments as a single lump sum (processor only).
+    /// Takes effect once the claimant accepts it.
+    pub fn propose_commutation(
+        env: Env,
+        processor: Address,
+        claim_id: u64,
+        lump_sum: i128,
+    ) -> Result<(), ContractError> {
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.3 != ClaimStatus::Approved {
+            return Err(ContractError::InvalidClaimState);
+        }
+        let mut schedule: PaymentSchedule = env
+            .storage()
+            .persistent()
+            .get(&(PAYMENT_SCHEDULE, claim_id))
+            .ok_or(ContractError::NotFound)?;
+
+        // A commuted value can discount the remaining payments but never exceed them
+        validate_amount(lump_sum)?;
+        let remaining = schedule.total_amount - schedule.paid_amount;
+        if lump_sum > remaining {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        schedule.commutation = Some(Commutation {
+            lump_sum,
+            proposed_by: processor.clone(),
+            proposed_at: env.ledger().timestamp(),
+            accepted_at: None,
+        });
+        env.storage().persistent().set(&(PAYMENT_SCHEDULE, claim_id), &schedule);
+
+        env.events().publish(
+            (Symbol::new(&env, "CommutationProposed"), claim_id),
+            (processor, lump_sum, remaining),
+        );
+
+        Ok(())
+    }
+
+    /// Accept the processor's commutation offer (claimant only). `lump_sum` must match the
+    /// offer. The lump sum is paid now and whatever the schedule no longer needs is released
+    /// back to the pool and the policy's cover.
+    pub fn accept_commutation(
+        env: Env,
+        claimant: Address,
+        claim_id: u64,
+        lump_sum: i128,
+    ) -> Result<(), ContractError> {
+        claimant.require_auth();
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "accept_commutation")))?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.1 != claimant {
+            return Err(ContractError::Unauthorized);
+        }
+        if claim.3 != ClaimStatus::Approved {
+            return Err(ContractError::InvalidClaimState);
+        }
+        let mut schedule: PaymentSchedule = env
+            .storage()
+            .persistent()
+            .get(&(PAYMENT_SCHEDULE, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        let mut commutation = schedule.commutation.clone().ok_or(ContractError::NotFound)?;
+        if commutation.lump_sum != lump_sum {
+            return Err(ContractError::InvalidAmount);
+        }
+        let remaining = schedule.total_amount - schedule.paid_amount;
+        if lump_sum > remaining {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        // Shrink the claim to what has been paid plus the lump sum, then pay the lump sum
+        let config: (Address, Address) =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        require_trusted_contract(&env, &config.0)?;
+        require_trusted_contract(&env, &config.1)?;
+        let new_total = schedule.paid_amount + lump_sum;
+        let certificate_id: Option<u64> =
+            env.storage().persistent().get(&(CLAIM_CERTIFICATE, claim_id));
+        env.invoke_contract::<i128>(
+            &config.0,
+            &Symbol::new(&env, "adjust_claim_drawdown"),
+            (env.current_contract_address(), claim.0, claim_id, certificate_id, new_total)
+                .into_val(&env),
+        );
+        env.invoke_contract::<()>(
+            &config.1,
+            &Symbol::new(&env, "adjust_reservation"),
+            (env.current_contract_address(), claim_id, lump_sum).into_val(&env),
+        );
+        pay_from_reservation(&env, claim_id, &schedule.payee, &schedule.payout_asset, lump_sum)?;
+
+        commutation.accepted_at = Some(env.ledger().timestamp());
+        schedule.commutation = Some(commutation);
+        schedule.paid_amount = new_total;
+        env.storage().persistent().set(&(PAYMENT_SCHEDULE, claim_id), &schedule);
+
+        env.events().publish(
+            (Symbol::new(&env, "ScheduleCommuted"), claim_id),
+            (claimant, lump_sum, remaining),
+        );
+        complete_payment_schedule(&env, claim_id, claim, &schedule);
+
+        Ok(())
+    }
+
+    /// Get a claim's structured settlement schedule
+    pub fn get_payment_schedule(env: Env, claim_id: u64) -> Result<PaymentSchedule, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(PAYMENT_SCHEDULE, claim_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Get paid versus remaining installments on a claim's schedule
+    pub fn get_payment_schedule_status(env: Env, claim_id: u64) -> Result<ScheduleStatus, ContractError> {
+        Self::get_payment_schedule(env, claim_id).map(|schedule| schedule.status())
+    }
+
+    /// Get the payout asset and FX rate a settled claim was paid with
+    pub fn get_claim_settlement(env: Env, claim_id: u64) -> Result<ClaimSettlement, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(CLAIM_SETTLEMENT, claim_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Set the asset registry used to convert payouts into alternative assets (admin only)
+    pub fn set_asset_registry(env: Env, admin: Address, registry: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        register_trusted_contract(&env, &admin, &registry)?;
+        env.storage().persistent().set(&ASSET_REGISTRY, &registry);
+
+        env.events().publish((Symbol::new(&env, "AssetRegistrySet"), ()), (admin, registry));
+
+        Ok(())
+    }
+
+    /// Amend a claim's amount and add evidence while it is still Submitted (claimant only).
+    /// Returns the new revision number.
+    pub fn amend_claim(
+        env: Env,
+        claimant: Address,
+        claim_id: u64,
+        amount: i128,
+        evidence: Vec<EvidenceSubmission>,
+        reason: Option<Symbol>,
+    ) -> Result<u32, ContractError> {
+        claimant.require_auth();
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "amend_claim")))?;
+
+        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.1 != claimant {
+            return Err(ContractError::Unauthorized);
+        }
+        if claim.3 != ClaimStatus::Submitted {
+            return Err(ContractError::InvalidClaimState);
+        }
+        if amount == claim.2 && evidence.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // The amended amount must still fit the remaining cover and the coverage in force
+        // at the loss date, as on submission
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        let config: (Address, Address) =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        require_trusted_contract(&env, &config.0)?;
+        let policy = PolicyClient::new(&env, &config.0);
+        let certificate_id: Option<u64> = env.storage().persistent().get(&(CLAIM_CERTIFICATE, claim_id));
+        let remaining_coverage = match certificate_id {
+            Some(certificate_id) => policy.get_certificate_remaining_coverage(&certificate_id),
+            None => policy.get_remaining_coverage(&claim.0),
+        };
+        let loss_timestamp: u64 = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_LOSS, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        let terms = match policy.try_get_policy_terms_at(&claim.0, &loss_timestamp) {
+            Ok(Ok(terms)) => terms,
+            _ => return Err(ContractError::LossOutsideCoveragePeriod),
+        };
+        validate_coverage_constraint(amount, remaining_coverage.min(terms.coverage_amount))?;
+
+        let previous_amount = claim.2;
+        claim.2 = amount;
+        env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        add_evidence(&env, claim_id, &claimant, evidence)?;
+        expire_pending_approval(&env, claim_id);
+        let revision = record_revision(&env, claim_id, RevisionKind::Amended, amount, reason.clone());
+
+        env.events().publish(
+            (Symbol::new(&env, "ClaimAmended"), claim_id),
+            (claimant, revision, previous_amount, amount, reason),
+        );
+
+        Ok(revision)
+    }
+
+    /// Withdraw a claim before a decision is made (claimant only).
+    /// The claim stops counting against its policy and collected approvals lapse.
+    pub fn withdraw_claim(
+        env: Env,
+        claimant: Address,
+        claim_id: u64,
+        reason: Option<Symbol>,
+    ) -> Result<(), ContractError> {
+        claimant.require_auth();
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "withdraw_claim")))?;
+
+        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.1 != claimant {
+            return Err(ContractError::Unauthorized);
+        }
+        if !is_valid_state_transition(claim.3.clone(), ClaimStatus::Withdrawn) {
+            return Err(ContractError::InvalidClaimState);
+        }
+
+        claim.3 = ClaimStatus::Withdrawn;
+        env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        expire_pending_approval(&env, claim_id);
+
+        // Nothing is drawn or reserved before approval; only the policy index holds the claim
+        let mut policy_claims: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&(POLICY_CLAIMS, claim.0))
+            .unwrap_or_else(|| Vec::new(&env));
+        if let Some(index) = policy_claims.first_index_of(claim_id) {
+            policy_claims.remove(index);
+            env.storage()
+                .persistent()
+                .set(&(POLICY_CLAIMS, claim.0), &policy_claims);
+        }
+        record_revision(&env, claim_id, RevisionKind::Withdrawn, claim.2, reason.clone());
+
+        env.events().publish(
+            (Symbol::new(&env, "ClaimWithdrawn"), claim_id),
+            (claimant, claim.0, claim.2, reason),
+        );
+
+        Ok(())
+    }
+
+    /// Get a claim's revision history, oldest first
+    pub fn get_claim_revisions(env: Env, claim_id: u64) -> Vec<ClaimRevision> {
+        env.storage()
+            .persistent()
+            .get(&(CLAIM_REVISIONS, claim_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Set the SLA for a product's claims, or the default SLA when `product_id` is None (admin only).
+    pub fn set_claim_sla(
+        env: Env,
+        admin: Address,
+        product_id: Option<u64>,
+        sla: ClaimSla,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        // Timers run from submission, so each stage's deadline must follow the previous one
+        if sla.review_secs == 0
+            || sla.review_secs > sla.decision_secs
+            || sla.decision_secs > sla.payment_secs
+            || sla.payment_secs > sla.hard_limit_secs
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage().persistent().set(&(CLAIM_SLA, product_id), &sla);
+
+        env.events().publish(
+            (Symbol::new(&env, "ClaimSlaSet"), product_id),
+            (admin, sla.review_secs, sla.decision_secs, sla.payment_secs, sla.hard_limit_secs),
+        );
+
+        Ok(())
+    }
+
+    /// Get the SLA that applies to a product's claims
+    pub fn get_claim_sla(env: Env, product_id: Option<u64>) -> Option<ClaimSla> {
+        claim_sla(&env, product_id)
+    }
+
+    /// Configure where overdue claims are reported and the backup processors that
+    /// pick up claims past their hard limit (admin only).
+    pub fn set_sla_escalation(
+        env: Env,
+        admin: Address,
+        config: SlaEscalationConfig,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        for processor in config.backup_processors.iter() {
+            require_claim_processing(&env, &processor)?;
+        }
+        if let Some(recovery_contract) = &config.recovery_contract {
+            register_trusted_contract(&env, &admin, recovery_contract)?;
+        }
+
+        env.storage().persistent().set(&SLA_ESCALATION, &config);
+
+        env.events().publish(
+            (Symbol::new(&env, "SlaEscalationSet"), ()),
+            (admin, config.recovery_contract, config.backup_processors.len()),
+        );
+
+        Ok(())
+    }
+
+    /// Get the SLA escalation configuration
+    pub fn get_sla_escalation(env: Env) -> Result<SlaEscalationConfig, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&SLA_ESCALATION)
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Check a page of claims against their SLAs. Anyone may call this.
+    /// Each newly overdue stage is reported to the recovery contract; claims past their
+    /// hard limit are escalated there and assigned to the next backup processor.
+    /// Returns the number of claims reported or escalated.
+    pub fn check_claim_slas(env: Env, start_index: u32, limit: u32) -> Result<u32, ContractError> {
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "check_claim_slas")))?;
+
+        let escalation: SlaEscalationConfig = env
+            .storage()
+            .persistent()
+            .get(&SLA_ESCALATION)
+            .unwrap_or(SlaEscalationConfig {
+                recovery_contract: None,
+                backup_processors: Vec::new(&env),
+            });
+        let recovery = match &escalation.recovery_contract {
+            Some(recovery_contract) => {
+                require_trusted_contract(&env, recovery_contract)?;
+                Some(RecoveryClient::new(&env, recovery_contract))
+            }
+            None => None,
+        };
+
+        let claim_list: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&CLAIM_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+        let effective_limit = if limit == 0 || limit > MAX_PAGINATION_LIMIT {
+            MAX_PAGINATION_LIMIT
+        } else {
+            limit
+        };
+        let end_index = core::cmp::min(start_index.saturating_add(effective_limit), claim_list.len());
+
+        let now = env.ledger().timestamp();
+        let this_contract = env.current_contract_address();
+        let mut metrics: SlaMetrics = env.storage().persistent().get(&SLA_METRICS).unwrap_or_default();
+        let mut handled = 0u32;
+
+        for i in start_index..end_index {
+            let claim_id = claim_list.get(i).unwrap();
+            let Some(claim) = env
+                .storage()
+                .persistent()
+                .get::<_, (u64, Address, i128, ClaimStatus, u64)>(&(CLAIM, claim_id))
+            else {
+                continue;
+            };
+            let product_id: Option<u64> = env.storage().persistent().get(&(CLAIM_PRODUCT, claim_id));
+            let Some(sla) = claim_sla(&env, product_id) else {
+                continue;
+            };
+            // Structured settlements are paid on their installment dates, not the payment SLA
+            if claim.3 == ClaimStatus::Approved
+                && env.storage().persistent().has(&(PAYMENT_SCHEDULE, claim_id))
+            {
+                continue;
+            }
+            let Some((stage, deadline)) = sla::current_deadline(&sla, &claim.3, claim.4) else {
+                continue;
+            };
+            if now <= deadline {
+                continue;
+            }
+
+            // Report each overdue stage once
+            let breach_key = (SLA_BREACH, claim_id, stage);
+            let breach = match env.storage().persistent().get::<_, SlaBreach>(&breach_key) {
+                Some(breach) => breach,
+                None => {
+                    let recovery_entry_id = recovery.as_ref().map(|recovery| {
+                        recovery.report_error(
+                            &this_contract,
+                            &this_contract,
+                            &RecoveryError::ClaimSlaBreached,
+                            &Some(claim_id),
+                        )
+                    });
+                    let breach = SlaBreach {
+                        stage,
+                        deadline,
+                        detected_at: now,
+                        recovery_entry_id,
+                    };
+                    env.storage().persistent().set(&breach_key, &breach);
+                    metrics.record_breach(stage);
+                    handled += 1;
+
+                    env.events().publish(
+                        (Symbol::new(&env, "ClaimSlaBreached"), claim_id),
+                        (stage, deadline, now, recovery_entry_id),
+                    );
+                    breach
+                }
+            };
+
+            // Past the hard limit: escalate and hand the claim to a backup processor
+            let hard_limit = claim.4.saturating_add(sla.hard_limit_secs);
+            if now <= hard_limit
+                || escalation.backup_processors.is_empty()
+                || env.storage().persistent().has(&(SLA_ASSIGNMENT, claim_id))
+            {
+                continue;
+            }
+            if let (Some(recovery), Some(entry_id)) = (recovery.as_ref(), breach.recovery_entry_id) {
+                recovery.escalate_error(&this_contract, &entry_id);
+            }
+
+            let cursor: u32 = env.storage().persistent().get(&SLA_BACKUP_CURSOR).unwrap_or(0);
+            let assigned_to = escalation
+                .backup_processors
+                .get(cursor % escalation.backup_processors.len())
+                .unwrap();
+            env.storage()
+                .persistent()
+                .set(&SLA_BACKUP_CURSOR, &cursor.wrapping_add(1));
+            env.storage().persistent().set(
+                &(SLA_ASSIGNMENT, claim_id),
+                &SlaEscalation {
+                    assigned_to: assigned_to.clone(),
+                    escalated_at: now,
+                },
+            );
+            metrics.escalations = metrics.escalations.saturating_add(1);
+            // Count a claim reported and escalated in the same pass once
+            if breach.detected_at != now {
+                handled += 1;
+            }
+
+            env.events().publish(
+                (Symbol::new(&env, "ClaimSlaEscalated"), claim_id),
+                (stage, assigned_to, hard_limit, breach.recovery_entry_id),
+            );
+        }
+
+        env.storage().persistent().set(&SLA_METRICS, &metrics);
+
+        Ok(handled)
+    }
+
+    /// Get when a claim reached each SLA milestone
+    pub fn get_claim_milestones(env: Env, claim_id: u64) -> Result<ClaimMilestones, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(CLAIM_MILESTONES, claim_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Get the SLA breaches recorded for a claim, in stage order
+    pub fn get_sla_breaches(env: Env, claim_id: u64) -> Vec<SlaBreach> {
+        let mut breaches = Vec::new(&env);
+        for stage in [SlaStage::Review, SlaStage::Decision, SlaStage::Payment] {
+            if let Some(breach) = env.storage().persistent().get(&(SLA_BREACH, claim_id, stage)) {
+                breaches.push_back(breach);
+            }
+        }
+        breaches
+    }
+
+    /// Get the backup processor a claim was escalated to, if any
+    pub fn get_sla_escalation_for_claim(env: Env, claim_id: u64) -> Option<SlaEscalation> {
+        env.storage().persistent().get(&(SLA_ASSIGNMENT, claim_id))
+    }
+
+    /// Get SLA breach and escalation counts across all claims
+    pub fn get_sla_metrics(env: Env) -> SlaMetrics {
+        env.storage().persistent().get(&SLA_METRICS).unwrap_or_default()
+    }
+
+    /// Configure fraud screening signals and weights (admin only).
+    /// The slashing and risk-monitoring contracts are registered as trusted.
+    pub fn set_fraud_config(
+        env: Env,
+        admin: Address,
+        config: FraudScreeningConfig,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if config.flag_threshold == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        if let Some(slashing_contract) = &config.slashing_contract {
+            register_trusted_contract(&env, &admin, slashing_contract)?;
+        }
+        if let Some(risk_monitoring_contract) = &config.risk_monitoring_contract {
+            register_trusted_contract(&env, &admin, risk_monitoring_contract)?;
+        }
+
+        env.storage().persistent().set(&FRAUD_CONFIG, &config);
+
+        env.events().publish(
+            (Symbol::new(&env, "FraudConfigSet"), ()),
+            (admin, config.flag_threshold, config.action),
+        );
+
+        Ok(())
+    }
+
+    /// Get the fraud screening configuration
+    pub fn get_fraud_config(env: Env) -> Result<FraudScreeningConfig, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&FRAUD_CONFIG)
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Re-score a claim under review for fraud and store the score with its components.
+    /// Claims are screened on submission; this refreshes the score with current signals.
+    /// Flagged claims need an extra approval or an Auditor, as configured.
+    pub fn screen_claim(env: Env, processor: Address, claim_id: u64) -> Result<FraudScore, ContractError> {
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let fraud_config: FraudScreeningConfig = env
+            .storage()
+            .persistent()
+            .get(&FRAUD_CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.3 != ClaimStatus::UnderReview {
+            return Err(ContractError::InvalidClaimState);
+        }
+
+        screen(&env, &fraud_config, claim_id, &claim, processor)
+    }
+
+    /// Get the fraud score recorded for a claim
+    pub fn get_fraud_score(env: Env, claim_id: u64) -> Option<FraudScore> {
+        env.storage().persistent().get(&(FRAUD_SCORE, claim_id))
+    }
+
+    /// Define how many processor signatures, and whether an Auditor's, approving a claim
+    /// needs by approved amount (admin only). Tiers must ascend by `max_amount`.
+    pub fn set_approval_tiers(
+        env: Env,
+        admin: Address,
+        tiers: Vec<ApprovalTier>,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if tiers.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+        let mut previous: Option<ApprovalTier> = None;
+        for tier in tiers.iter() {
+            if tier.required_approvals == 0 || tier.max_amount <= 0 {
+                return Err(ContractError::InvalidInput);
+            }
+            if let Some(previous) = previous {
+                if tier.max_amount <= previous.max_amount
+                    || tier.required_approvals < previous.required_approvals
+                {
+                    return Err(ContractError::InvalidInput);
+                }
+            }
+            previous = Some(tier);
+        }
+
+        env.storage().persistent().set(&APPROVAL_TIERS, &tiers);
+
+        env.events()
+            .publish((Symbol::new(&env, "ApprovalTiersSet"), ()), (admin, tiers.len()));
+
+        Ok(())
+    }
+
+    /// Get the configured approval tiers
+    pub fn get_approval_tiers(env: Env) -> Vec<ApprovalTier> {
+        env.storage()
+            .persistent()
+            .get(&APPROVAL_TIERS)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the signatures collected so far for approving a claim
+    pub fn get_pending_approval(env: Env, claim_id: u64) -> Option<PendingApproval> {
+        env.storage().persistent().get(&(PENDING_APPROVAL, claim_id))
+    }
+
+    /// Auditor countersignature for approvals in tiers that require one. Signs the pending
+    /// approval at `approved_amount`, completing it if the processors have already signed.
+    pub fn countersign_approval(
+        env: Env,
+        auditor: Address,
+        claim_id: u64,
+        approved_amount: i128,
+    ) -> Result<(), ContractError> {
+        auditor.require_auth();
+        require_role(&env, &auditor, Role::Auditor)?;
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "approve_claim")))?;
+
+        let mut pending: PendingApproval = env
+            .storage()
+            .persistent()
+            .get(&(PENDING_APPROVAL, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if pending.action_hash != approval_hash(&env, claim_id, approved_amount) {
+            return Err(ContractError::InvalidAmount);
+        }
+        if !pending.auditor_required || pending.auditor.is_some() {
+            return Err(ContractError::InvalidState);
+        }
+        pending.auditor = Some(auditor.clone());
+
+        env.events().publish(
+            (Symbol::new(&env, "ClaimApprovalCountersigned"), claim_id),
+            (auditor.clone(), approved_amount),
+        );
+
+        if !pending.processors_ready {
+            env.storage().persistent().set(&(PENDING_APPROVAL, claim_id), &pending);
+            return Ok(());
+        }
+
+        env.storage().persistent().remove(&(PENDING_APPROVAL, claim_id));
+        Self::finalize_approval(
+            env,
+            auditor,
+            claim_id,
+            pending.approved_amount,
+            pending.adjustment_reason,
+        )
+    }
+
+    /// Set the evidence types a product's claims must have verified before approval (admin only)
+    pub fn set_required_evidence(
+        env: Env,
+        admin: Address,
+        product_id: u64,
+        evidence_types: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        env.storage()
+            .persistent()
+            .set(&(REQUIRED_EVIDENCE, product_id), &evidence_types);
+
+        env.events().publish(
+            (Symbol::new(&env, "RequiredEvidenceSet"), product_id),
+            (admin, evidence_types),
+        );
+
+        Ok(())
+    }
+
+    /// Get the evidence types a product requires
+    pub fn get_required_evidence(env: Env, product_id: u64) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&(REQUIRED_EVIDENCE, product_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Attach further evidence to a claim that has not been decided yet.
+    /// Callable by the claimant or a claim processor; returns the number of items attached.
+    pub fn attach_evidence(
+        env: Env,
+        submitter: Address,
+        claim_id: u64,
+        items: Vec<EvidenceSubmission>,
+    ) -> Result<u32, ContractError> {
+        submitter.require_auth();
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "attach_evidence")))?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.3 != ClaimStatus::Submitted && claim.3 != ClaimStatus::UnderReview {
+            return Err(ContractError::InvalidClaimState);
+        }
+        if submitter != claim.1 {
+            require_claim_processing(&env, &submitter)?;
+        }
+
+        // Signatures were given on the evidence as it stood
+        expire_pending_approval(&env, claim_id);
+        add_evidence(&env, claim_id, &submitter, items)
+    }
+
+    /// Record a processor's review of one evidence item. Accepting an item requires the
+    /// hash of the document the processor inspected to match the hash anchored at submission.
+    pub fn review_evidence(
+        env: Env,
+        processor: Address,
+        claim_id: u64,
+        evidence_id: u32,
+        inspected_hash: BytesN<32>,
+        sufficient: bool,
+    ) -> Result<(), ContractError> {
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.3 != ClaimStatus::Submitted && claim.3 != ClaimStatus::UnderReview {
+            return Err(ContractError::InvalidClaimState);
+        }
+
+        let mut evidence: Vec<EvidenceItem> = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_EVIDENCE, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        let mut item = evidence.get(evidence_id).ok_or(ContractError::NotFound)?;
+
+        if sufficient {
+            let matches = verify_claim_evidence(
+                &env,
+                evidence_key(&env, claim_id, evidence_id),
+                inspected_hash,
+            )
+            .map_err(|_| ContractError::NotFound)?;
+            if !matches {
+                return Err(ContractError::EvidenceHashMismatch);
+            }
+        }
+
+        item.status = if sufficient {
+            EvidenceStatus::Verified
+        } else {
+            EvidenceStatus::Insufficient
+        };
+        item.reviewed_by = Some(processor.clone());
+        item.reviewed_at = Some(env.ledger().timestamp());
+        evidence.set(evidence_id, item.clone());
+        env.storage().persistent().set(&(CLAIM_EVIDENCE, claim_id), &evidence);
+        expire_pending_approval(&env, claim_id);
+
+        env.events().publish(
+            (Symbol::new(&env, "EvidenceReviewed"), claim_id),
+            (evidence_id, item.evidence_type, item.status, processor),
+        );
+
+        Ok(())
+    }
+
+    /// Get the evidence attached to a claim with its review status
+    pub fn get_claim_evidence(env: Env, claim_id: u64) -> Vec<EvidenceItem> {
+        env.storage()
+            .persistent()
+            .get(&(CLAIM_EVIDENCE, claim_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Set how long after a decision the claimant may dispute it (admin only)
+    pub fn set_dispute_window(env: Env, admin: Address, window_secs: u64) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if window_secs == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        config::set_config(&env, &config::Config { dispute_window_secs: window_secs });
+
+        env.events().publish((Symbol::new(&env, "DisputeWindowSet"), ()), (admin, window_secs));
+
+        Ok(())
+    }
+
+    /// Get the dispute window in seconds
+    pub fn get_dispute_window(env: Env) -> u64 {
+        config::get_config(&env).dispute_window_secs
+    }
+
+    /// Appoint the review panel for escalated disputes and the number of matching votes
+    /// needed for a binding resolution (admin only)
+    pub fn set_dispute_panel(
+        env: Env,
+        admin: Address,
+        members: Vec<Address>,
+        quorum: u32,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if quorum == 0 || quorum > members.len() {
+            return Err(ContractError::InvalidInput);
+        }
+        env.storage().persistent().set(&DISPUTE_PANEL, &(members.clone(), quorum));
+
+        env.events().publish(
+            (Symbol::new(&env, "DisputePanelSet"), ()),
+            (admin, members.len(), quorum),
+        );
+
+        Ok(())
+    }
+
+    /// Get the dispute panel members and quorum
+    pub fn get_dispute_panel(env: Env) -> Result<(Vec<Address>, u32), ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DISPUTE_PANEL)
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Set the governance contract that resolves disputes escalated to a vote (admin only)
+    pub fn set_dispute_governance(
+        env: Env,
+        admin: Address,
+        governance_contract: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        validate_address(&env, &governance_contract)?;
+        env.storage().persistent().set(&DISPUTE_GOVERNANCE, &governance_contract);
+
+        Ok(())
+    }
+
+    /// Dispute a rejected or partially approved claim within the dispute window.
+    /// `requested_amount` is the loss the claimant believes should be approved; if upheld,
+    /// the policy's deductible, co-insurance and sub-limits apply to it as on approval.
+    /// Any reservation made at approval stays in the risk pool until the dispute is resolved.
+    pub fn open_dispute(
+        env: Env,
+        claimant: Address,
+        claim_id: u64,
+        reason: String,
+        evidence: Vec<BytesN<32>>,
+        requested_amount: i128,
+    ) -> Result<(), ContractError> {
+        claimant.require_auth();
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "open_dispute")))?;
+
+        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.1 != claimant {
+            return Err(ContractError::Unauthorized);
+        }
+        if env.storage().persistent().has(&(DISPUTE, claim_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+        if reason.len() == 0 || evidence.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+        if requested_amount <= 0 || requested_amount > claim.2 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        // Only rejections and approvals paying less than the claimant now asks for are disputable
+        match claim.3 {
+            ClaimStatus::Rejected => {}
+            ClaimStatus::Approved => {
+                let amounts: ClaimAmounts = env
+                    .storage()
+                    .persistent()
+                    .get(&(CLAIM_AMOUNTS, claim_id))
+                    .ok_or(ContractError::NotFound)?;
+                if requested_amount <= amounts.approved_amount {
+                    return Err(ContractError::NotDisputable);
+                }
+            }
+            _ => return Err(ContractError::NotDisputable),
+        }
+
+        let now = env.ledger().timestamp();
+        let (_, decided_at): (Address, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_DECISION, claim_id))
+            .ok_or(ContractError::NotDisputable)?;
+        let window = config::get_config(&env).dispute_window_secs;
+        if now > decided_at.saturating_add(window) {
+            return Err(ContractError::DisputeWindowClosed);
+        }
+
+        let dispute = Dispute {
+            claim_id,
+            raised_by: claimant.clone(),
+            reason,
+            evidence: evidence.clone(),
+            requested_amount,
+            contested_status: claim.3.clone(),
+            opened_at: now,
+            route: None,
+            votes_uphold: 0,
+            votes_dismiss: 0,
+            outcome: None,
+            resolved_at: None,
+            resolved: false,
+        };
+
+        // I3: Transition to Disputed state
+        claim.3 = ClaimStatus::Disputed;
+
+        env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        env.storage().persistent().set(&(DISPUTE, claim_id), &dispute);
+
+        env.events().publish(
+            (Symbol::new(&env, "DisputeOpened"), claim_id),
+            (claimant, dispute.contested_status, requested_amount, evidence.len(), now),
+        );
+
+        Ok(())
+    }
+
+    /// Send an open dispute to the review panel or to a governance vote
+    pub fn escalate_dispute(
+        env: Env,
+        processor: Address,
+        claim_id: u64,
+        route: DisputeRoute,
+    ) -> Result<(), ContractError> {
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let mut dispute = Self::get_dispute(env.clone(), claim_id)?;
+        if dispute.resolved || dispute.route.is_some() {
+            return Err(ContractError::DisputeNotOpen);
+        }
+
+        match route {
+            DisputeRoute::Panel => {
+                if !env.storage().persistent().has(&DISPUTE_PANEL) {
+                    return Err(ContractError::NotInitialized);
+                }
+            }
+            DisputeRoute::Governance => {
+                if !env.storage().persistent().has(&DISPUTE_GOVERNANCE) {
+                    return Err(ContractError::NotInitialized);
+                }
+            }
+        }
+
+        dispute.route = Some(route.clone());
+        env.storage().persistent().set(&(DISPUTE, claim_id), &dispute);
+
+        env.events().publish(
+            (Symbol::new(&env, "DisputeEscalated"), claim_id),
+            (processor, route, dispute.requested_amount, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Cast a panel vote on an escalated dispute. The processor who made the contested
+    /// decision cannot sit on its review. The dispute resolves once either side reaches quorum.
+    pub fn vote_on_dispute(
+        env: Env,
+        member: Address,
+        claim_id: u64,
+        uphold: bool,
+    ) -> Result<(), ContractError> {
+        member.require_auth();
+
+        let mut dispute = Self::get_dispute(env.clone(), claim_id)?;
+        if dispute.resolved || dispute.route != Some(DisputeRoute::Panel) {
+            return Err(ContractError::DisputeNotOpen);
+        }
+
+        let (members, quorum): (Vec<Address>, u32) = env
+            .storage()
+            .persistent()
+            .get(&DISPUTE_PANEL)
+            .ok_or(ContractError::NotInitialized)?;
+        if !members.contains(&member) {
+            return Err(ContractError::NotPanelMember);
+        }
+        let (decided_by, _): (Address, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_DECISION, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if decided_by == member {
+            return Err(ContractError::Unauthorized);
+        }
+        if env.storage().persistent().has(&(DISPUTE_VOTE, claim_id, member.clone())) {
+            return Err(ContractError::AlreadyVoted);
+        }
+
+        env.storage().persistent().set(&(DISPUTE_VOTE, claim_id, member.clone()), &uphold);
+        if uphold {
+            dispute.votes_uphold += 1;
+        } else {
+            dispute.votes_dismiss += 1;
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "DisputeVoteCast"), claim_id),
+            (member, uphold, dispute.votes_uphold, dispute.votes_dismiss),
+        );
+
+        if dispute.votes_uphold >= quorum {
+            resolve_dispute(&env, claim_id, dispute, DisputeOutcome::Upheld)
+        } else if dispute.votes_dismiss >= quorum {
+            resolve_dispute(&env, claim_id, dispute, DisputeOutcome::Dismissed)
+        } else {
+            env.storage().persistent().set(&(DISPUTE, claim_id), &dispute);
+            Ok(())
+        }
+    }
+
+    /// Record the binding result of a governance vote on an escalated dispute.
+    /// Only callable by the configured governance contract.
+    pub fn resolve_dispute_by_governance(
+        env: Env,
+        governance_contract: Address,
+        claim_id: u64,
+        uphold: bool,
+    ) -> Result<(), ContractError> {
+        governance_contract.require_auth();
+        let configured: Address = env
+            .storage()
+            .persistent()
+            .get(&DISPUTE_GOVERNANCE)
+            .ok_or(ContractError::NotInitialized)?;
+        if configured != governance_contract {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let dispute = Self::get_dispute(env.clone(), claim_id)?;
+        if dispute.resolved || dispute.route != Some(DisputeRoute::Governance) {
+            return Err(ContractError::DisputeNotOpen);
+        }
+
+        let outcome = if uphold {
+            DisputeOutcome::Upheld
+        } else {
+            DisputeOutcome::Dismissed
+        };
+        resolve_dispute(&env, claim_id, dispute, outcome)
+    }
+
+    /// Get the dispute raised on a claim
+    pub fn get_dispute(env: Env, claim_id: u64) -> Result<Dispute, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(DISPUTE, claim_id))
+            .ok_or(ContractError::NotFound)
+    }
 
-        env.events()
-            .publish((Symbol::new(&env, "claim_settled"), claim_id), (claim.1, claim.2));
+    /// Returns the IDs of all claims raised against a policy, oldest first.
+    pub fn get_policy_claims(env: Env, policy_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&(POLICY_CLAIMS, policy_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
 
+    /// Returns who was paid how much when the claim was settled
+    pub fn get_claim_payees(env: Env, claim_id: u64) -> Result<Vec<(Address, i128)>, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(CLAIM_PAYEES, claim_id))
+            .ok_or(ContractError::NotFound)
+    }
 
-        env.events().publish((Symbol::new(&env, "ClaimSettled"), claim_id), (processor, claim.1, claim.2, env.ledger().timestamp()));
+    /// Returns the loss date declared when the claim was submitted
+    pub fn get_claim_loss_timestamp(env: Env, claim_id: u64) -> Result<u64, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(CLAIM_LOSS, claim_id))
+            .ok_or(ContractError::NotFound)
+    }
 
+    /// Returns the group certificate a claim was raised under, if any
+    pub fn get_claim_certificate(env: Env, claim_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&(CLAIM_CERTIFICATE, claim_id))
+    }
 
-    Ok(())
+    /// Get the requested, approved, net and paid amounts recorded for the claim
+    pub fn get_claim_amounts(env: Env, claim_id: u64) -> Result<ClaimAmounts, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(CLAIM_AMOUNTS, claim_id))
+            .ok_or(ContractError::NotFound)
     }
 
     /// Get claim payout preference
@@ -1143,6 +3733,325 @@ mod tests {
         env.as_contract(&cid, f)
     }
 
+    #[contract]
+    pub struct MockPolicy;
+
+    #[contractimpl]
+    impl MockPolicy {
+        pub fn set_policy(
+            env: Env,
+            policy_id: u64,
+            holder: Address,
+            state: PolicyState,
+            start_time: u64,
+            end_time: u64,
+            remaining: i128,
+        ) {
+            env.storage()
+                .instance()
+                .set(&policy_id, &(holder, state, start_time, end_time, remaining));
+        }
+
+        fn policy(env: &Env, policy_id: u64) -> (Address, PolicyState, u64, u64, i128) {
+            env.storage().instance().get(&policy_id).unwrap()
+        }
+
+        pub fn get_policy_holder(env: Env, policy_id: u64) -> Address {
+            Self::policy(&env, policy_id).0
+        }
+
+        pub fn get_policy_state(env: Env, policy_id: u64) -> PolicyState {
+            Self::policy(&env, policy_id).1
+        }
+
+        pub fn get_policy_dates(env: Env, policy_id: u64) -> (u64, u64) {
+            let policy = Self::policy(&env, policy_id);
+            (policy.2, policy.3)
+        }
+
+        pub fn get_remaining_coverage(env: Env, policy_id: u64) -> i128 {
+            Self::policy(&env, policy_id).4
+        }
+
+        pub fn set_grace_period(env: Env, policy_id: u64, grace_secs: u64) {
+            env.storage()
+                .instance()
+                .set(&(symbol_short!("grace"), policy_id), &grace_secs);
+        }
+
+        pub fn set_lapse(env: Env, policy_id: u64, lapsed_at: u64, reinstated_at: Option<u64>) {
+            env.storage()
+                .instance()
+                .set(&(symbol_short!("lapse"), policy_id), &(lapsed_at, reinstated_at));
+        }
+
+        pub fn set_terms_coverage(env: Env, policy_id: u64, coverage_amount: i128) {
+            env.storage()
+                .instance()
+                .set(&(symbol_short!("cover"), policy_id), &coverage_amount);
+        }
+
+        pub fn get_policy_terms_at(env: Env, policy_id: u64, timestamp: u64) -> PolicyAmendment {
+            let policy = Self::policy(&env, policy_id);
+            let grace: u64 = env
+                .storage()
+                .instance()
+                .get(&(symbol_short!("grace"), policy_id))
+                .unwrap_or(0);
+            if timestamp < policy.2 || timestamp > policy.3 + grace {
+                panic!("outside coverage period");
+            }
+            let lapse: Option<(u64, Option<u64>)> =
+                env.storage().instance().get(&(symbol_short!("lapse"), policy_id));
+            if let Some((lapsed_at, reinstated_at)) = lapse {
+                if timestamp >= lapsed_at && reinstated_at.map_or(true, |at| timestamp < at) {
+                    panic!("policy lapsed");
+                }
+            }
+            PolicyAmendment {
+                policy_id,
+                version: 0,
+                endorsement_id: None,
+                coverage_amount: env
+                    .storage()
+                    .instance()
+                    .get(&(symbol_short!("cover"), policy_id))
+                    .unwrap_or(10_000),
+                coverage_asset: shared::types::Asset::Native,
+                premium_amount: 0,
+                end_time: policy.3,
+                premium_adjustment: 0,
+                effective_from: policy.2,
+            }
+        }
+
+        pub fn set_product(env: Env, policy_id: u64, product_id: u64) {
+            env.storage()
+                .instance()
+                .set(&(symbol_short!("product"), policy_id), &product_id);
+        }
+
+        pub fn get_policy_product(env: Env, policy_id: u64) -> Option<u64> {
+            env.storage().instance().get(&(symbol_short!("product"), policy_id))
+        }
+
+        pub fn get_beneficiaries(env: Env, _policy_id: u64) -> BeneficiaryDesignation {
+            BeneficiaryDesignation {
+                beneficiaries: Vec::new(&env),
+                contingent: None,
+            }
+        }
+
+        pub fn get_coverage_terms(env: Env, policy_id: u64) -> CoverageTerms {
+            Self::get_coverage_terms_at(env, policy_id, u64::MAX)
+        }
+
+        /// Raise the deductible for losses from `effective_from` on
+        pub fn set_deductible_from(env: Env, policy_id: u64, effective_from: u64, deductible: i128) {
+            env.storage()
+                .instance()
+                .set(&(symbol_short!("deduct"), policy_id), &(effective_from, deductible));
+        }
+
+        pub fn get_coverage_terms_at(env: Env, policy_id: u64, timestamp: u64) -> CoverageTerms {
+            let change: Option<(u64, i128)> = env
+                .storage()
+                .instance()
+                .get(&(symbol_short!("deduct"), policy_id));
+            let deductible = match change {
+                Some((effective_from, deductible)) if timestamp >= effective_from => deductible,
+                _ => 100,
+            };
+            CoverageTerms {
+                deductible,
+                coinsurance_bps: 0,
+                sub_limits: Vec::new(&env),
+            }
+        }
+
+        pub fn draw_down_coverage(
+            _env: Env,
+            _caller_contract: Address,
+            _policy_id: u64,
+            _claim_id: u64,
+            amount: i128,
+        ) -> i128 {
+            amount
+        }
+
+        pub fn adjust_claim_drawdown(
+            _env: Env,
+            _caller_contract: Address,
+            _policy_id: u64,
+            _claim_id: u64,
+            _certificate_id: Option<u64>,
+            new_amount: i128,
+        ) -> i128 {
+            new_amount
+        }
+    }
+
+    #[contract]
+    pub struct MockSlashing;
+
+    #[contractimpl]
+    impl MockSlashing {
+        pub fn get_violation_count(_env: Env, _target: Address, role: u32) -> u32 {
+            if role == fraud::CLAIM_SUBMITTER_ROLE { 1 } else { 0 }
+        }
+    }
+
+    #[contract]
+    pub struct MockRiskMonitoring;
+
+    #[contractimpl]
+    impl MockRiskMonitoring {
+        pub fn get_alert_history(env: Env) -> Vec<fraud::RiskAlert> {
+            let mut alerts = Vec::new(&env);
+            alerts.push_back(fraud::RiskAlert {
+                sensor_id: 1,
+                factor_type: fraud::RiskFactorType::Liquidity,
+                value: 90,
+                threshold_violated: 80,
+                timestamp: env.ledger().timestamp(),
+            });
+            alerts
+        }
+    }
+
+    #[contract]
+    pub struct MockRecovery;
+
+    #[contractimpl]
+    impl MockRecovery {
+        pub fn report_error(
+            env: Env,
+            _source_contract: Address,
+            _caller: Address,
+            _error: sla::RecoveryError,
+            subject_id: Option<u64>,
+        ) -> u64 {
+            let entry_id: u64 = env.storage().instance().get(&symbol_short!("next")).unwrap_or(0);
+            env.storage().instance().set(&symbol_short!("next"), &(entry_id + 1));
+            env.storage().instance().set(&entry_id, &subject_id);
+            entry_id
+        }
+
+        pub fn escalate_error(env: Env, _caller: Address, entry_id: u64) {
+            let mut escalated: Vec<u64> = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("escalated"))
+                .unwrap_or_else(|| Vec::new(&env));
+            escalated.push_back(entry_id);
+            env.storage().instance().set(&symbol_short!("escalated"), &escalated);
+        }
+
+        pub fn get_escalated(env: Env) -> Vec<u64> {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("escalated"))
+                .unwrap_or_else(|| Vec::new(&env))
+        }
+    }
+
+    #[contract]
+    pub struct MockRiskPool;
+
+    #[contractimpl]
+    impl MockRiskPool {
+        pub fn reserve_liquidity(env: Env, _caller_contract: Address, claim_id: u64, amount: i128) {
+            env.storage().instance().set(&claim_id, &amount);
+        }
+
+        pub fn adjust_reservation(
+            env: Env,
+            _caller_contract: Address,
+            claim_id: u64,
+            new_amount: i128,
+        ) {
+            env.storage().instance().set(&claim_id, &new_amount);
+        }
+
+        pub fn get_reservation(env: Env, claim_id: u64) -> i128 {
+            env.storage().instance().get(&claim_id).unwrap_or(0)
+        }
+
+        pub fn set_asset_liquidity(env: Env, asset: shared::types::Asset, amount: i128) {
+            env.storage().instance().set(&asset, &amount);
+        }
+
+        pub fn get_payout_liquidity(
+            env: Env,
+            asset: shared::types::Asset,
+            _claim_id: u64,
+        ) -> i128 {
+            env.storage().instance().get(&asset).unwrap_or(0)
+        }
+
+        pub fn payout_reserved_claim_multi_asset(
+            env: Env,
+            _caller_contract: Address,
+            claim_id: u64,
+            _recipient: Address,
+            _payout_asset: shared::types::Asset,
+            amount: Option<i128>,
+            _asset_amount: Option<i128>,
+        ) {
+            let reserved: i128 = env.storage().instance().get(&claim_id).unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&claim_id, &(reserved - amount.unwrap_or(reserved)));
+        }
+    }
+
+    #[contract]
+    pub struct MockAssetRegistry;
+
+    #[contractimpl]
+    impl MockAssetRegistry {
+        pub fn accepts_claims(_env: Env, _asset: shared::types::Asset) -> bool {
+            true
+        }
+
+        pub fn get_conversion_rate(
+            env: Env,
+            from_asset: shared::types::Asset,
+            to_asset: shared::types::Asset,
+        ) -> shared::types::AssetConversionRate {
+            shared::types::AssetConversionRate {
+                from_asset,
+                to_asset,
+                rate_bps: 20_000,
+                updated_at: env.ledger().timestamp(),
+                oracle_source: env.current_contract_address(),
+            }
+        }
+
+        pub fn convert_amount(
+            _env: Env,
+            _from_asset: shared::types::Asset,
+            _to_asset: shared::types::Asset,
+            amount: i128,
+        ) -> i128 {
+            amount * 2
+        }
+    }
+
+    /// Registers a mock policy contract holding an ACTIVE policy 1 for `holder`
+    /// covering days 1-31 with 10_000 of remaining coverage.
+    fn setup_mock_policy(env: &Env, holder: &Address) -> Address {
+        let policy_contract = env.register_contract(None, MockPolicy);
+        env.ledger().with_mut(|li| li.timestamp = 10 * 86400);
+        env.invoke_contract::<()>(
+            &policy_contract,
+            &Symbol::new(env, "set_policy"),
+            (1u64, holder.clone(), PolicyState::ACTIVE, 86400u64, 31 * 86400u64, 10_000i128)
+                .into_val(env),
+        );
+        policy_contract
+    }
+
     // Test helper functions
     fn setup_test_env() -> (Env, Address, Address, Address, Address) {
         let env = Env::default();
@@ -1283,29 +4192,1412 @@ mod tests {
     }
 
     #[test]
-    fn test_submit_claim_duplicate_for_same_policy() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+    fn test_submit_multiple_claims_for_same_policy() {
+        let (env, admin, _, risk_pool, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+
+            let policy_id = 1;
+            let loss_at = 5 * 86400;
+
+            // Submit first claim
+            let first = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                policy_id,
+                1000,
+                loss_at,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            ).unwrap();
+
+            // A second claim on the same policy is accepted; limits are enforced at approval
+            let second = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                policy_id,
+                500,
+                loss_at,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            ).unwrap();
+
+            let claims = ClaimsContract::get_policy_claims(env.clone(), policy_id);
+            assert_eq!(claims.len(), 2);
+            assert_eq!(claims.get(0).unwrap(), first);
+            assert_eq!(claims.get(1).unwrap(), second);
+        });
+    }
 
-        let policy_id = 1;
+    #[test]
+    fn test_dispute_on_rejected_claim_upheld_by_panel() {
+        let (env, admin, _, _, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let risk_pool = env.register_contract(None, MockRiskPool);
+        let processor = Address::generate(&env);
+        let panelist_a = Address::generate(&env);
+        let panelist_b = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                2000,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+            ClaimsContract::reject_claim(env.clone(), processor.clone(), claim_id).unwrap();
+
+            let reason = String::from_str(&env, "loss was covered");
+            let mut evidence = Vec::new(&env);
+            evidence.push_back(BytesN::from_array(&env, &[7u8; 32]));
+
+            // The claimed amount bounds what can be requested
+            let result = ClaimsContract::open_dispute(
+                env.clone(),
+                user.clone(),
+                claim_id,
+                reason.clone(),
+                evidence.clone(),
+                2001,
+            );
+            assert_eq!(result, Err(ContractError::InvalidAmount));
+
+            ClaimsContract::open_dispute(
+                env.clone(),
+                user.clone(),
+                claim_id,
+                reason,
+                evidence,
+                1500,
+            )
+            .unwrap();
+            let (_, _, _, status, _) = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+            assert_eq!(status, ClaimStatus::Disputed);
+
+            let mut panel = Vec::new(&env);
+            panel.push_back(processor.clone());
+            panel.push_back(panelist_a.clone());
+            panel.push_back(panelist_b.clone());
+            ClaimsContract::set_dispute_panel(env.clone(), admin.clone(), panel, 2).unwrap();
+            ClaimsContract::escalate_dispute(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                DisputeRoute::Panel,
+            )
+            .unwrap();
+
+            // The processor who rejected the claim cannot review it
+            let result =
+                ClaimsContract::vote_on_dispute(env.clone(), processor.clone(), claim_id, false);
+            assert_eq!(result, Err(ContractError::Unauthorized));
+
+            ClaimsContract::vote_on_dispute(env.clone(), panelist_a.clone(), claim_id, true)
+                .unwrap();
+            let result =
+                ClaimsContract::vote_on_dispute(env.clone(), panelist_a.clone(), claim_id, true);
+            assert_eq!(result, Err(ContractError::AlreadyVoted));
+            ClaimsContract::vote_on_dispute(env.clone(), panelist_b.clone(), claim_id, true)
+                .unwrap();
+
+            let dispute = ClaimsContract::get_dispute(env.clone(), claim_id).unwrap();
+            assert!(dispute.resolved);
+            assert_eq!(dispute.outcome, Some(DisputeOutcome::Upheld));
+            let (_, _, _, status, _) = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+            assert_eq!(status, ClaimStatus::Approved);
+            // The upheld amount still bears the policy deductible
+            let amounts = ClaimsContract::get_claim_amounts(env.clone(), claim_id).unwrap();
+            assert_eq!(amounts.approved_amount, 1500);
+            assert_eq!(amounts.deductible_amount, 100);
+            assert_eq!(amounts.net_amount, 1400);
+            let reserved: i128 = env.invoke_contract(
+                &risk_pool,
+                &Symbol::new(&env, "get_reservation"),
+                (claim_id,).into_val(&env),
+            );
+            assert_eq!(reserved, 1400);
+        });
+    }
 
-        // Submit first claim
-        ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            policy_id,
-            1000,
-        ).unwrap();
+    #[test]
+    fn test_dispute_window_and_partial_approval() {
+        let (env, admin, _, _, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let risk_pool = env.register_contract(None, MockRiskPool);
+        let processor = Address::generate(&env);
+        let governance = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+            ClaimsContract::set_dispute_governance(env.clone(), admin.clone(), governance.clone())
+                .unwrap();
+
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                2000,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+            ClaimsContract::approve_claim_partial(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                1500,
+                symbol_short!("wear"),
+                None,
+            )
+            .unwrap();
+
+            let reason = String::from_str(&env, "loss undervalued");
+            let mut evidence = Vec::new(&env);
+            evidence.push_back(BytesN::from_array(&env, &[1u8; 32]));
+
+            // 1500 of the loss is already approved; asking for no more is not a dispute
+            let result = ClaimsContract::open_dispute(
+                env.clone(),
+                user.clone(),
+                claim_id,
+                reason.clone(),
+                evidence.clone(),
+                1500,
+            );
+            assert_eq!(result, Err(ContractError::NotDisputable));
+
+            ClaimsContract::open_dispute(
+                env.clone(),
+                user.clone(),
+                claim_id,
+                reason,
+                evidence,
+                2000,
+            )
+            .unwrap();
+
+            // Settlement waits for the dispute; the reservation is untouched
+            let result = ClaimsContract::settle_claim(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                None,
+                Vec::new(&env),
+            );
+            assert_eq!(result, Err(ContractError::InvalidClaimState));
+            let reserved: i128 = env.invoke_contract(
+                &risk_pool,
+                &Symbol::new(&env, "get_reservation"),
+                (claim_id,).into_val(&env),
+            );
+            assert_eq!(reserved, 1400);
+
+            ClaimsContract::escalate_dispute(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                DisputeRoute::Governance,
+            )
+            .unwrap();
+            let result = ClaimsContract::resolve_dispute_by_governance(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                true,
+            );
+            assert_eq!(result, Err(ContractError::Unauthorized));
+            ClaimsContract::resolve_dispute_by_governance(
+                env.clone(),
+                governance.clone(),
+                claim_id,
+                false,
+            )
+            .unwrap();
+
+            // Dismissal restores the original approval
+            let (_, _, _, status, _) = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+            assert_eq!(status, ClaimStatus::Approved);
+            assert_eq!(
+                ClaimsContract::get_claim_amounts(env.clone(), claim_id).unwrap().net_amount,
+                1400
+            );
+
+            // A second claim rejected outside the window cannot be disputed
+            let late = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                500,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), late).unwrap();
+            ClaimsContract::reject_claim(env.clone(), processor.clone(), late).unwrap();
+            let window = ClaimsContract::get_dispute_window(env.clone());
+            env.ledger().with_mut(|li| li.timestamp += window + 1);
+            let mut evidence = Vec::new(&env);
+            evidence.push_back(BytesN::from_array(&env, &[2u8; 32]));
+            let result = ClaimsContract::open_dispute(
+                env.clone(),
+                user.clone(),
+                late,
+                String::from_str(&env, "late"),
+                evidence,
+                400,
+            );
+            assert_eq!(result, Err(ContractError::DisputeWindowClosed));
+        });
+    }
 
-        // Try to submit second claim for same policy
-        let result = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            policy_id,
-            500,
+    #[test]
+    fn test_upheld_dispute_reassesses_partial_approval() {
+        let (env, admin, _, _, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let risk_pool = env.register_contract(None, MockRiskPool);
+        let processor = Address::generate(&env);
+        let governance = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+            ClaimsContract::set_dispute_governance(env.clone(), admin.clone(), governance.clone())
+                .unwrap();
+
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                2000,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+            ClaimsContract::approve_claim_partial(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                1500,
+                symbol_short!("wear"),
+                None,
+            )
+            .unwrap();
+
+            let mut evidence = Vec::new(&env);
+            evidence.push_back(BytesN::from_array(&env, &[1u8; 32]));
+            ClaimsContract::open_dispute(
+                env.clone(),
+                user.clone(),
+                claim_id,
+                String::from_str(&env, "loss undervalued"),
+                evidence,
+                1800,
+            )
+            .unwrap();
+            ClaimsContract::escalate_dispute(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                DisputeRoute::Governance,
+            )
+            .unwrap();
+            ClaimsContract::resolve_dispute_by_governance(
+                env.clone(),
+                governance.clone(),
+                claim_id,
+                true,
+            )
+            .unwrap();
+
+            // The requested loss bears the deductible just as the original approval did
+            let amounts = ClaimsContract::get_claim_amounts(env.clone(), claim_id).unwrap();
+            assert_eq!(amounts.gross_amount, 2000);
+            assert_eq!(amounts.approved_amount, 1800);
+            assert_eq!(amounts.adjustment_reason, None);
+            assert_eq!(amounts.deductible_amount, 100);
+            assert_eq!(amounts.net_amount, 1700);
+            let reserved: i128 = env.invoke_contract(
+                &risk_pool,
+                &Symbol::new(&env, "get_reservation"),
+                (claim_id,).into_val(&env),
+            );
+            assert_eq!(reserved, 1700);
+        });
+    }
+
+    #[test]
+    fn test_approval_requires_verified_evidence() {
+        let (env, admin, _, _, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        env.invoke_contract::<()>(
+            &policy_contract,
+            &Symbol::new(&env, "set_product"),
+            (1u64, 7u64).into_val(&env),
+        );
+        let risk_pool = env.register_contract(None, MockRiskPool);
+        let processor = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+            let mut required = Vec::new(&env);
+            required.push_back(symbol_short!("police"));
+            required.push_back(symbol_short!("invoice"));
+            ClaimsContract::set_required_evidence(env.clone(), admin.clone(), 7, required)
+                .unwrap();
+
+            let police_hash = BytesN::from_array(&env, &[3u8; 32]);
+            let mut evidence = Vec::new(&env);
+            evidence.push_back(EvidenceSubmission {
+                content_hash: police_hash.clone(),
+                uri: String::from_str(&env, "ipfs://police-report"),
+                evidence_type: symbol_short!("police"),
+            });
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                2000,
+                5 * 86400,
+                None,
+                None,
+                None,
+                evidence,
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+
+            // Nothing verified yet
+            let result = ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None);
+            assert_eq!(result, Err(ContractError::EvidenceRequired));
+
+            // Accepting an item needs the inspected document to match the anchored hash
+            let result = ClaimsContract::review_evidence(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                0,
+                BytesN::from_array(&env, &[4u8; 32]),
+                true,
+            );
+            assert_eq!(result, Err(ContractError::EvidenceHashMismatch));
+            ClaimsContract::review_evidence(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                0,
+                police_hash,
+                true,
+            )
+            .unwrap();
+
+            // The invoice is still missing
+            let result = ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None);
+            assert_eq!(result, Err(ContractError::EvidenceRequired));
+
+            let invoice_hash = BytesN::from_array(&env, &[5u8; 32]);
+            let mut invoice = Vec::new(&env);
+            invoice.push_back(EvidenceSubmission {
+                content_hash: invoice_hash.clone(),
+                uri: String::from_str(&env, "ipfs://invoice"),
+                evidence_type: symbol_short!("invoice"),
+            });
+            let attached =
+                ClaimsContract::attach_evidence(env.clone(), user.clone(), claim_id, invoice)
+                    .unwrap();
+            assert_eq!(attached, 2);
+            ClaimsContract::review_evidence(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                1,
+                invoice_hash,
+                true,
+            )
+            .unwrap();
+
+            ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None).unwrap();
+
+            let evidence = ClaimsContract::get_claim_evidence(env.clone(), claim_id);
+            assert_eq!(evidence.len(), 2);
+            let item = evidence.get(1).unwrap();
+            assert_eq!(item.status, EvidenceStatus::Verified);
+            assert_eq!(item.submitted_by, user);
+            assert_eq!(item.reviewed_by, Some(processor));
+        });
+    }
+
+    #[test]
+    fn test_partial_approval_adjusts_reservation() {
+        let (env, admin, _, _, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let risk_pool = env.register_contract(None, MockRiskPool);
+        let processor = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                2000,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+
+            // Cannot approve more than was requested
+            let result = ClaimsContract::approve_claim_partial(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                2001,
+                symbol_short!("excess"),
+                None,
+            );
+            assert_eq!(result, Err(ContractError::InvalidAmount));
+
+            ClaimsContract::approve_claim_partial(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                1500,
+                symbol_short!("excess"),
+                None,
+            )
+            .unwrap();
+            let amounts = ClaimsContract::get_claim_amounts(env.clone(), claim_id).unwrap();
+            assert_eq!(amounts.gross_amount, 2000);
+            assert_eq!(amounts.approved_amount, 1500);
+            assert_eq!(amounts.net_amount, 1400);
+            assert_eq!(amounts.paid_amount, 0);
+            assert_eq!(amounts.adjustment_reason, Some(symbol_short!("excess")));
+
+            // Lowering the approval before settlement releases the difference
+            ClaimsContract::approve_claim_partial(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                1000,
+                symbol_short!("salvage"),
+                None,
+            )
+            .unwrap();
+            let amounts = ClaimsContract::get_claim_amounts(env.clone(), claim_id).unwrap();
+            assert_eq!(amounts.approved_amount, 1000);
+            assert_eq!(amounts.net_amount, 900);
+            let reserved: i128 = env.invoke_contract(
+                &risk_pool,
+                &Symbol::new(&env, "get_reservation"),
+                (claim_id,).into_val(&env),
+            );
+            assert_eq!(reserved, 900);
+
+            // Raising an approval is not a partial approval
+            let result = ClaimsContract::approve_claim_partial(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                1200,
+                symbol_short!("salvage"),
+                None,
+            );
+            assert_eq!(result, Err(ContractError::InvalidAmount));
+        });
+    }
+
+    #[test]
+    fn test_tiered_multisig_approval() {
+        let (env, admin, _, _, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let risk_pool = env.register_contract(None, MockRiskPool);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let auditor = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), first.clone()).unwrap();
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), second.clone()).unwrap();
+            ClaimsContract::grant_auditor_role(env.clone(), admin.clone(), auditor.clone()).unwrap();
+
+            let mut tiers = Vec::new(&env);
+            tiers.push_back(ApprovalTier { max_amount: 1_000, required_approvals: 1, auditor_required: false });
+            tiers.push_back(ApprovalTier { max_amount: 5_000, required_approvals: 2, auditor_required: false });
+            tiers.push_back(ApprovalTier { max_amount: i128::MAX, required_approvals: 2, auditor_required: true });
+            ClaimsContract::set_approval_tiers(env.clone(), admin.clone(), tiers).unwrap();
+
+            let submit = |amount: i128| {
+                let claim_id = ClaimsContract::submit_claim(
+                    env.clone(),
+                    user.clone(),
+                    1,
+                    amount,
+                    5 * 86400,
+                    None,
+                    None,
+                    None,
+                    Vec::new(&env),
+                )
+                .unwrap();
+                ClaimsContract::start_review(env.clone(), first.clone(), claim_id).unwrap();
+                claim_id
+            };
+            let status = |claim_id: u64| ClaimsContract::get_claim(env.clone(), claim_id).unwrap().3;
+
+            // Middle tier: two processors must sign the same amount
+            let claim_id = submit(2_000);
+            ClaimsContract::approve_claim(env.clone(), first.clone(), claim_id, None).unwrap();
+            assert_eq!(status(claim_id), ClaimStatus::UnderReview);
+            let pending = ClaimsContract::get_pending_approval(env.clone(), claim_id).unwrap();
+            assert_eq!(pending.approvals.len(), 1);
+            assert_eq!(pending.required_approvals, 2);
+
+            // New evidence expires the signatures collected so far
+            let mut evidence = Vec::new(&env);
+            evidence.push_back(EvidenceSubmission {
+                content_hash: BytesN::from_array(&env, &[9u8; 32]),
+                uri: String::from_str(&env, "ipfs://photos"),
+                evidence_type: symbol_short!("photo"),
+            });
+            ClaimsContract::attach_evidence(env.clone(), user.clone(), claim_id, evidence).unwrap();
+            assert_eq!(ClaimsContract::get_pending_approval(env.clone(), claim_id), None);
+
+            ClaimsContract::approve_claim(env.clone(), first.clone(), claim_id, None).unwrap();
+            ClaimsContract::approve_claim(env.clone(), second.clone(), claim_id, None).unwrap();
+            assert_eq!(status(claim_id), ClaimStatus::Approved);
+
+            // Top tier: processors sign first, the Auditor's countersignature completes it
+            let claim_id = submit(8_000);
+            ClaimsContract::approve_claim(env.clone(), first.clone(), claim_id, None).unwrap();
+            ClaimsContract::approve_claim(env.clone(), second.clone(), claim_id, None).unwrap();
+            assert_eq!(status(claim_id), ClaimStatus::UnderReview);
+            assert!(ClaimsContract::get_pending_approval(env.clone(), claim_id).unwrap().processors_ready);
+
+            let result = ClaimsContract::countersign_approval(env.clone(), auditor.clone(), claim_id, 7_000);
+            assert_eq!(result, Err(ContractError::InvalidAmount));
+            ClaimsContract::countersign_approval(env.clone(), auditor.clone(), claim_id, 8_000).unwrap();
+            assert_eq!(status(claim_id), ClaimStatus::Approved);
+            assert_eq!(ClaimsContract::get_pending_approval(env.clone(), claim_id), None);
+        });
+    }
+
+    #[test]
+    fn test_fraud_screening_routes_flagged_claim_to_auditor() {
+        let (env, admin, _, _, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let risk_pool = env.register_contract(None, MockRiskPool);
+        let slashing = env.register_contract(None, MockSlashing);
+        let risk_monitoring = env.register_contract(None, MockRiskMonitoring);
+        let processor = Address::generate(&env);
+        let auditor = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+            ClaimsContract::grant_auditor_role(env.clone(), admin.clone(), auditor.clone()).unwrap();
+            ClaimsContract::set_fraud_config(
+                env.clone(),
+                admin.clone(),
+                FraudScreeningConfig {
+                    slashing_contract: Some(slashing.clone()),
+                    risk_monitoring_contract: Some(risk_monitoring.clone()),
+                    violation_weight: 30,
+                    frequency_weight: 10,
+                    frequency_window_secs: 30 * 86400,
+                    early_claim_weight: 15,
+                    early_claim_window_secs: 30 * 86400,
+                    alert_weight: 5,
+                    alert_window_secs: 86400,
+                    flag_threshold: 50,
+                    action: FraudAction::RouteToAuditor,
+                },
+            )
+            .unwrap();
+
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                2000,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+
+            // Screened on submission: one violation, filed nine days after cover started,
+            // one live alert
+            let score = ClaimsContract::get_fraud_score(env.clone(), claim_id).unwrap();
+            assert_eq!(score.violation_points, 30);
+            assert_eq!(score.recent_claims, 0);
+            assert_eq!(score.early_claim_points, 15);
+            assert_eq!(score.alert_points, 5);
+            assert_eq!(score.total, 50);
+            assert!(score.flagged);
+            assert_eq!(score.screened_by, env.current_contract_address());
+
+            // A processor can refresh the score once the claim is under review
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+            let rescored = ClaimsContract::screen_claim(env.clone(), processor.clone(), claim_id).unwrap();
+            assert_eq!(rescored.total, 50);
+            assert_eq!(rescored.screened_by, processor);
+            assert_eq!(ClaimsContract::get_fraud_score(env.clone(), claim_id), Some(rescored));
+
+            // A second filing inside the frequency window counts against the claimant
+            let second = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                500,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            let score = ClaimsContract::get_fraud_score(env.clone(), second).unwrap();
+            assert_eq!(score.recent_claims, 1);
+            assert_eq!(score.total, 60);
+
+            // A single processor is no longer enough
+            ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None).unwrap();
+            assert_eq!(
+                ClaimsContract::get_claim(env.clone(), claim_id).unwrap().3,
+                ClaimStatus::UnderReview
+            );
+            ClaimsContract::countersign_approval(env.clone(), auditor.clone(), claim_id, 2000)
+                .unwrap();
+            assert_eq!(
+                ClaimsContract::get_claim(env.clone(), claim_id).unwrap().3,
+                ClaimStatus::Approved
+            );
+        });
+    }
+
+    #[test]
+    fn test_settlement_falls_back_to_alternative_asset_with_fx() {
+        let (env, admin, _, _, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let risk_pool = env.register_contract(None, MockRiskPool);
+        let registry = env.register_contract(None, MockAssetRegistry);
+        let processor = Address::generate(&env);
+        let usdc = shared::types::Asset::Contract(Address::generate(&env));
+
+        let set_liquidity = |asset: &shared::types::Asset, amount: i128| {
+            env.invoke_contract::<()>(
+                &risk_pool,
+                &Symbol::new(&env, "set_asset_liquidity"),
+                (asset.clone(), amount).into_val(&env),
+            );
+        };
+        set_liquidity(&shared::types::Asset::Native, 500);
+        set_liquidity(&usdc, 3000);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+            ClaimsContract::set_asset_registry(env.clone(), admin.clone(), registry.clone())
+                .unwrap();
+
+            let mut alternatives = Vec::new(&env);
+            alternatives.push_back(usdc.clone());
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                2000,
+                5 * 86400,
+                Some(shared::types::ClaimPayoutPreference {
+                    preferred_asset: shared::types::Asset::Native,
+                    accept_alternative: true,
+                    alternatives,
+                }),
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+            ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None).unwrap();
+
+            // 1900 net is 3800 in the alternative, more than the pool holds of either asset
+            let result = ClaimsContract::settle_claim(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                None,
+                Vec::new(&env),
+            );
+            assert_eq!(result, Err(ContractError::NoPayoutAssetAvailable));
+
+            // An override the claimant never accepted is refused
+            let result = ClaimsContract::settle_claim(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                Some(shared::types::Asset::Contract(Address::generate(&env))),
+                Vec::new(&env),
+            );
+            assert_eq!(result, Err(ContractError::InvalidInput));
+
+            set_liquidity(&usdc, 5000);
+            ClaimsContract::settle_claim(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            let settlement = ClaimsContract::get_claim_settlement(env.clone(), claim_id).unwrap();
+            assert_eq!(settlement.claim_asset, shared::types::Asset::Native);
+            assert_eq!(settlement.claim_amount, 1900);
+            assert_eq!(settlement.payout_asset, usdc);
+            assert_eq!(settlement.payout_amount, 3800);
+            assert_eq!(settlement.rate_bps, 20_000);
+            assert_eq!(settlement.rate_updated_at, Some(10 * 86400));
+        });
+    }
+
+    #[test]
+    fn test_structured_settlement_installments_and_commutation() {
+        let (env, admin, _, _, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let risk_pool = env.register_contract(None, MockRiskPool);
+        let processor = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                2000,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+            ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None).unwrap();
+            let reservation = || -> i128 {
+                env.invoke_contract(
+                    &risk_pool,
+                    &Symbol::new(&env, "get_reservation"),
+                    (claim_id,).into_val(&env),
+                )
+            };
+
+            let schedule = |amounts: [i128; 3]| {
+                let mut payments = Vec::new(&env);
+                for (i, amount) in amounts.iter().enumerate() {
+                    payments.push_back(ScheduledPayment {
+                        due_at: (11 + i as u64) * 86400,
+                        amount: *amount,
+                    });
+                }
+                payments
+            };
+
+            // The schedule must add up to the 1900 net approval
+            assert_eq!(
+                ClaimsContract::set_payment_schedule(
+                    env.clone(),
+                    processor.clone(),
+                    claim_id,
+                    user.clone(),
+                    schedule([600, 600, 600]),
+                ),
+                Err(ContractError::InvalidAmount)
+            );
+            ClaimsContract::set_payment_schedule(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                user.clone(),
+                schedule([600, 600, 700]),
+            )
+            .unwrap();
+            assert_eq!(ClaimsContract::release_installments(env.clone(), claim_id).unwrap(), 0);
+
+            // Two installments have fallen due by day 12
+            env.ledger().with_mut(|li| li.timestamp = 12 * 86400);
+            assert_eq!(ClaimsContract::release_installments(env.clone(), claim_id).unwrap(), 2);
+            assert_eq!(reservation(), 700);
+            let status = ClaimsContract::get_payment_schedule_status(env.clone(), claim_id).unwrap();
+            assert_eq!(status.paid_installments, 2);
+            assert_eq!(status.remaining_installments, 1);
+            assert_eq!(status.paid_amount, 1200);
+            assert_eq!(status.remaining_amount, 700);
+            assert_eq!(status.next_due_at, Some(13 * 86400));
+
+            // Lump-sum settlement is reserved for the schedule
+            let result = ClaimsContract::settle_claim(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                None,
+                Vec::new(&env),
+            );
+            assert_eq!(result, Err(ContractError::InvalidClaimState));
+
+            // Nor can the scheduled amount be cut back, or the claim chased by the payment SLA
+            let result = ClaimsContract::approve_claim_partial(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                1500,
+                symbol_short!("partial"),
+                None,
+            );
+            assert_eq!(result, Err(ContractError::InvalidClaimState));
+            ClaimsContract::set_claim_sla(
+                env.clone(),
+                admin.clone(),
+                None,
+                ClaimSla {
+                    review_secs: 86400,
+                    decision_secs: 86400,
+                    payment_secs: 86400,
+                    hard_limit_secs: 2 * 86400,
+                },
+            )
+            .unwrap();
+            assert_eq!(ClaimsContract::check_claim_slas(env.clone(), 0, 0).unwrap(), 0);
+
+            // Commutation needs both the processor's offer and the claimant's acceptance
+            assert_eq!(
+                ClaimsContract::accept_commutation(env.clone(), user.clone(), claim_id, 650),
+                Err(ContractError::NotFound)
+            );
+            ClaimsContract::propose_commutation(env.clone(), processor.clone(), claim_id, 650)
+                .unwrap();
+            assert_eq!(
+                ClaimsContract::accept_commutation(env.clone(), stranger.clone(), claim_id, 650),
+                Err(ContractError::Unauthorized)
+            );
+            ClaimsContract::accept_commutation(env.clone(), user.clone(), claim_id, 650).unwrap();
+
+            assert_eq!(reservation(), 0);
+            assert_eq!(
+                ClaimsContract::get_claim(env.clone(), claim_id).unwrap().3,
+                ClaimStatus::Settled
+            );
+            assert_eq!(
+                ClaimsContract::get_claim_amounts(env.clone(), claim_id).unwrap().paid_amount,
+                1850
+            );
+            let status = ClaimsContract::get_payment_schedule_status(env.clone(), claim_id).unwrap();
+            assert!(status.commuted);
+            assert_eq!(status.remaining_installments, 0);
+            assert_eq!(status.remaining_amount, 0);
+        });
+    }
+
+    #[test]
+    fn test_amend_and_withdraw_claim_keep_revision_history() {
+        let (env, admin, _, risk_pool, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let processor = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                1000,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+
+            let mut receipts = Vec::new(&env);
+            receipts.push_back(EvidenceSubmission {
+                content_hash: BytesN::from_array(&env, &[4u8; 32]),
+                uri: String::from_str(&env, "ipfs://receipts"),
+                evidence_type: symbol_short!("receipt"),
+            });
+            assert_eq!(
+                ClaimsContract::amend_claim(
+                    env.clone(),
+                    stranger.clone(),
+                    claim_id,
+                    1500,
+                    Vec::new(&env),
+                    None
+                ),
+                Err(ContractError::Unauthorized)
+            );
+            assert_eq!(
+                ClaimsContract::amend_claim(
+                    env.clone(),
+                    user.clone(),
+                    claim_id,
+                    10_001,
+                    Vec::new(&env),
+                    None
+                ),
+                Err(ContractError::CoverageExceeded)
+            );
+            // The coverage in force at the loss date caps the amendment too
+            env.invoke_contract::<()>(
+                &policy_contract,
+                &Symbol::new(&env, "set_terms_coverage"),
+                (1u64, 1200i128).into_val(&env),
+            );
+            assert_eq!(
+                ClaimsContract::amend_claim(
+                    env.clone(),
+                    user.clone(),
+                    claim_id,
+                    1500,
+                    Vec::new(&env),
+                    None
+                ),
+                Err(ContractError::CoverageExceeded)
+            );
+            env.invoke_contract::<()>(
+                &policy_contract,
+                &Symbol::new(&env, "set_terms_coverage"),
+                (1u64, 10_000i128).into_val(&env),
+            );
+            let revision = ClaimsContract::amend_claim(
+                env.clone(),
+                user.clone(),
+                claim_id,
+                1500,
+                receipts,
+                Some(symbol_short!("receipts")),
+            )
+            .unwrap();
+            assert_eq!(revision, 1);
+            assert_eq!(ClaimsContract::get_claim(env.clone(), claim_id).unwrap().2, 1500);
+            assert_eq!(ClaimsContract::get_claim_evidence(env.clone(), claim_id).len(), 1);
+
+            // Amendments stop once review starts, but withdrawal is still open
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+            assert_eq!(
+                ClaimsContract::amend_claim(
+                    env.clone(),
+                    user.clone(),
+                    claim_id,
+                    1200,
+                    Vec::new(&env),
+                    None
+                ),
+                Err(ContractError::InvalidClaimState)
+            );
+            ClaimsContract::withdraw_claim(env.clone(), user.clone(), claim_id, None).unwrap();
+            assert_eq!(
+                ClaimsContract::get_claim(env.clone(), claim_id).unwrap().3,
+                ClaimStatus::Withdrawn
+            );
+            assert!(ClaimsContract::get_policy_claims(env.clone(), 1).is_empty());
+            assert_eq!(
+                ClaimsContract::withdraw_claim(env.clone(), user.clone(), claim_id, None),
+                Err(ContractError::InvalidClaimState)
+            );
+
+            let revisions = ClaimsContract::get_claim_revisions(env.clone(), claim_id);
+            assert_eq!(revisions.len(), 3);
+            assert_eq!(revisions.get(0).unwrap().kind, RevisionKind::Submitted);
+            assert_eq!(revisions.get(0).unwrap().amount, 1000);
+            assert_eq!(revisions.get(1).unwrap().kind, RevisionKind::Amended);
+            assert_eq!(revisions.get(1).unwrap().evidence_count, 1);
+            assert_eq!(revisions.get(1).unwrap().reason, Some(symbol_short!("receipts")));
+            assert_eq!(revisions.get(2).unwrap().kind, RevisionKind::Withdrawn);
+            assert_eq!(revisions.get(2).unwrap().amount, 1500);
+        });
+    }
+
+    #[test]
+    fn test_sla_breaches_reported_and_escalated_to_backup() {
+        let (env, admin, _, risk_pool, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let recovery = env.register_contract(None, MockRecovery);
+        let processor = Address::generate(&env);
+        let backup_a = Address::generate(&env);
+        let backup_b = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            for p in [&processor, &backup_a, &backup_b] {
+                ClaimsContract::grant_processor_role(env.clone(), admin.clone(), p.clone()).unwrap();
+            }
+
+            // Stage deadlines must not run backwards
+            let bad_sla = ClaimSla {
+                review_secs: 3 * 86400,
+                decision_secs: 86400,
+                payment_secs: 5 * 86400,
+                hard_limit_secs: 7 * 86400,
+            };
+            assert_eq!(
+                ClaimsContract::set_claim_sla(env.clone(), admin.clone(), None, bad_sla),
+                Err(ContractError::InvalidInput)
+            );
+            ClaimsContract::set_claim_sla(
+                env.clone(),
+                admin.clone(),
+                None,
+                ClaimSla {
+                    review_secs: 86400,
+                    decision_secs: 3 * 86400,
+                    payment_secs: 5 * 86400,
+                    hard_limit_secs: 7 * 86400,
+                },
+            )
+            .unwrap();
+            let mut backups = Vec::new(&env);
+            backups.push_back(backup_a.clone());
+            backups.push_back(backup_b.clone());
+            ClaimsContract::set_sla_escalation(
+                env.clone(),
+                admin.clone(),
+                SlaEscalationConfig {
+                    recovery_contract: Some(recovery.clone()),
+                    backup_processors: backups,
+                },
+            )
+            .unwrap();
+
+            let submit = || {
+                ClaimsContract::submit_claim(
+                    env.clone(),
+                    user.clone(),
+                    1,
+                    1000,
+                    5 * 86400,
+                    None,
+                    None,
+                    None,
+                    Vec::new(&env),
+                )
+                .unwrap()
+            };
+            let idle_claim = submit();
+            let reviewed_claim = submit();
+            ClaimsContract::start_review(env.clone(), processor.clone(), reviewed_claim).unwrap();
+            let milestones =
+                ClaimsContract::get_claim_milestones(env.clone(), reviewed_claim).unwrap();
+            assert_eq!(milestones.review_started_at, Some(10 * 86400));
+            assert_eq!(milestones.decided_at, None);
+
+            // Only the claim nobody picked up is late for review, and it is reported once
+            env.ledger().with_mut(|li| li.timestamp = 11 * 86400 + 1);
+            assert_eq!(ClaimsContract::check_claim_slas(env.clone(), 0, 0).unwrap(), 1);
+            assert_eq!(ClaimsContract::check_claim_slas(env.clone(), 0, 0).unwrap(), 0);
+            let breaches = ClaimsContract::get_sla_breaches(env.clone(), idle_claim);
+            assert_eq!(breaches.len(), 1);
+            assert_eq!(breaches.get(0).unwrap().stage, SlaStage::Review);
+            assert_eq!(breaches.get(0).unwrap().recovery_entry_id, Some(0));
+            assert!(ClaimsContract::get_sla_breaches(env.clone(), reviewed_claim).is_empty());
+
+            // Past the hard limit both claims are escalated and shared across the backup pool
+            env.ledger().with_mut(|li| li.timestamp = 17 * 86400 + 1);
+            assert_eq!(ClaimsContract::check_claim_slas(env.clone(), 0, 0).unwrap(), 2);
+            assert_eq!(
+                ClaimsContract::get_sla_escalation_for_claim(env.clone(), idle_claim)
+                    .unwrap()
+                    .assigned_to,
+                backup_a
+            );
+            assert_eq!(
+                ClaimsContract::get_sla_escalation_for_claim(env.clone(), reviewed_claim)
+                    .unwrap()
+                    .assigned_to,
+                backup_b
+            );
+            let escalated: Vec<u64> =
+                env.invoke_contract(&recovery, &Symbol::new(&env, "get_escalated"), ().into_val(&env));
+            assert_eq!(escalated.len(), 2);
+
+            let metrics = ClaimsContract::get_sla_metrics(env.clone());
+            assert_eq!(metrics.review_breaches, 1);
+            assert_eq!(metrics.decision_breaches, 1);
+            assert_eq!(metrics.payment_breaches, 0);
+            assert_eq!(metrics.escalations, 2);
+
+            // Nothing further to do once escalated
+            assert_eq!(ClaimsContract::check_claim_slas(env.clone(), 0, 0).unwrap(), 0);
+        });
+    }
+
+    #[test]
+    fn test_submit_claim_verifies_policy() {
+        let (env, admin, _, risk_pool, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let stranger = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::set_submit_claim_rate_limit(env.clone(), admin.clone(), 20, 60)
+                .unwrap();
+
+            let submit = |claimant: &Address, policy_id: u64, amount: i128, loss_at: u64| {
+                ClaimsContract::submit_claim(
+                    env.clone(),
+                    claimant.clone(),
+                    policy_id,
+                    amount,
+                    loss_at,
+                    None,
+                    None,
+                    None,
+                    Vec::new(&env),
+                )
+            };
+
+            assert_eq!(submit(&user, 2, 1000, 5 * 86400), Err(ContractError::PolicyNotFound));
+            assert_eq!(submit(&stranger, 1, 1000, 5 * 86400), Err(ContractError::NotPolicyholder));
+            assert_eq!(
+                submit(&user, 1, 1000, 12 * 3600),
+                Err(ContractError::LossOutsideCoveragePeriod)
+            );
+            assert_eq!(submit(&user, 1, 10_001, 5 * 86400), Err(ContractError::CoverageExceeded));
+
+            let claim_id = submit(&user, 1, 10_000, 5 * 86400).unwrap();
+            assert_eq!(
+                ClaimsContract::get_claim_loss_timestamp(env.clone(), claim_id).unwrap(),
+                5 * 86400
+            );
+
+            // Lapsed policies accept no new claims
+            env.invoke_contract::<()>(
+                &policy_contract,
+                &Symbol::new(&env, "set_policy"),
+                (1u64, user.clone(), PolicyState::LAPSED, 86400u64, 31 * 86400u64, 10_000i128)
+                    .into_val(&env),
+            );
+            assert_eq!(submit(&user, 1, 1000, 5 * 86400), Err(ContractError::PolicyNotActive));
+        });
+    }
+
+    #[test]
+    fn test_submit_claim_uses_terms_in_force_at_loss_date() {
+        let (env, admin, _, risk_pool, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        env.invoke_contract::<()>(
+            &policy_contract,
+            &Symbol::new(&env, "set_grace_period"),
+            (1u64, 5 * 86400u64).into_val(&env),
+        );
+        env.ledger().with_mut(|li| li.timestamp = 40 * 86400);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::set_submit_claim_rate_limit(env.clone(), admin.clone(), 20, 60)
+                .unwrap();
+
+            let submit = |amount: i128, loss_at: u64| {
+                ClaimsContract::submit_claim(
+                    env.clone(),
+                    user.clone(),
+                    1,
+                    amount,
+                    loss_at,
+                    None,
+                    None,
+                    None,
+                    Vec::new(&env),
+                )
+            };
+
+            // A loss during the grace period after the term end is still covered
+            assert!(submit(1000, 33 * 86400).is_ok());
+            assert_eq!(submit(1000, 37 * 86400), Err(ContractError::LossOutsideCoveragePeriod));
+
+            // The coverage in force at the loss date caps the claim
+            env.invoke_contract::<()>(
+                &policy_contract,
+                &Symbol::new(&env, "set_terms_coverage"),
+                (1u64, 4000i128).into_val(&env),
+            );
+            assert_eq!(submit(5000, 5 * 86400), Err(ContractError::CoverageExceeded));
+            assert!(submit(4000, 5 * 86400).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_submit_claim_rejects_loss_in_lapse_window() {
+        let (env, admin, _, risk_pool, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        // Lapsed on day 6, reinstated on day 8; the policy is ACTIVE again today
+        env.invoke_contract::<()>(
+            &policy_contract,
+            &Symbol::new(&env, "set_lapse"),
+            (1u64, 6 * 86400u64, Some(8 * 86400u64)).into_val(&env),
+        );
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::set_submit_claim_rate_limit(env.clone(), admin.clone(), 20, 60)
+                .unwrap();
+
+            let submit = |loss_at: u64| {
+                ClaimsContract::submit_claim(
+                    env.clone(),
+                    user.clone(),
+                    1,
+                    1000,
+                    loss_at,
+                    None,
+                    None,
+                    None,
+                    Vec::new(&env),
+                )
+            };
+
+            assert_eq!(submit(7 * 86400), Err(ContractError::LossOutsideCoveragePeriod));
+            // Losses before the lapse and after reinstatement stay covered
+            assert!(submit(5 * 86400).is_ok());
+            assert!(submit(9 * 86400).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_approval_below_deductible_rejects_claim() {
+        let (env, admin, _, risk_pool, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let processor = Address::generate(&env);
+
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+
+            // The 100 deductible absorbs the whole loss
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                100,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+            ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None).unwrap();
+
+            assert_eq!(
+                ClaimsContract::get_claim(env.clone(), claim_id).unwrap().3,
+                ClaimStatus::Rejected
+            );
+            assert!(ClaimsContract::get_claim_amounts(env.clone(), claim_id).is_err());
+        });
+    }
+
+    #[test]
+    fn test_assessment_uses_coverage_terms_at_loss_date() {
+        let (env, admin, _, risk_pool, user) = setup_test_env();
+        let policy_contract = setup_mock_policy(&env, &user);
+        let processor = Address::generate(&env);
+        // The deductible was raised on day 8, after the day-5 loss
+        env.invoke_contract::<()>(
+            &policy_contract,
+            &Symbol::new(&env, "set_deductible_from"),
+            (1u64, 8 * 86400u64, 500i128).into_val(&env),
         );
 
-        assert_eq!(result, Err(ContractError::AlreadyExists));
+        with_contract_env(&env, || {
+            initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+            ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone())
+                .unwrap();
+
+            let claim_id = ClaimsContract::submit_claim(
+                env.clone(),
+                user.clone(),
+                1,
+                1000,
+                5 * 86400,
+                None,
+                None,
+                None,
+                Vec::new(&env),
+            )
+            .unwrap();
+            ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+            ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None).unwrap();
+
+            let amounts = ClaimsContract::get_claim_amounts(env.clone(), claim_id).unwrap();
+            assert_eq!(amounts.deductible_amount, 100);
+            assert_eq!(amounts.net_amount, 900);
+
+            // Lowering the approval reassesses under the same loss-date terms
+            ClaimsContract::approve_claim_partial(
+                env.clone(),
+                processor.clone(),
+                claim_id,
+                800,
+                symbol_short!("wear"),
+                None,
+            )
+            .unwrap();
+            let amounts = ClaimsContract::get_claim_amounts(env.clone(), claim_id).unwrap();
+            assert_eq!(amounts.net_amount, 700);
+        });
     }
 
     #[test]
@@ -1938,6 +6230,15 @@ mod tests {
         assert_eq!(is_valid_state_transition(ClaimStatus::UnderReview, ClaimStatus::Approved), true);
         assert_eq!(is_valid_state_transition(ClaimStatus::UnderReview, ClaimStatus::Rejected), true);
         assert_eq!(is_valid_state_transition(ClaimStatus::Approved, ClaimStatus::Settled), true);
+        assert_eq!(is_valid_state_transition(ClaimStatus::Rejected, ClaimStatus::Disputed), true);
+        assert_eq!(is_valid_state_transition(ClaimStatus::Approved, ClaimStatus::Disputed), true);
+        assert_eq!(is_valid_state_transition(ClaimStatus::Disputed, ClaimStatus::Approved), true);
+        assert_eq!(is_valid_state_transition(ClaimStatus::Disputed, ClaimStatus::Rejected), true);
+        assert_eq!(is_valid_state_transition(ClaimStatus::Disputed, ClaimStatus::Settled), false);
+        assert_eq!(is_valid_state_transition(ClaimStatus::Submitted, ClaimStatus::Withdrawn), true);
+        assert_eq!(is_valid_state_transition(ClaimStatus::UnderReview, ClaimStatus::Withdrawn), true);
+        assert_eq!(is_valid_state_transition(ClaimStatus::Approved, ClaimStatus::Withdrawn), false);
+        assert_eq!(is_valid_state_transition(ClaimStatus::Withdrawn, ClaimStatus::Submitted), false);
     }
 
     #[test]
@@ -1966,4 +6267,106 @@ mod tests {
             Err(ContractError::CoverageExceeded)
         );
     }
+
+    #[test]
+    fn test_split_payout_across_beneficiaries() {
+        let env = Env::default();
+        let claimant = Address::generate(&env);
+        let spouse = Address::generate(&env);
+        let child = Address::generate(&env);
+        let estate = Address::generate(&env);
+
+        // No designation pays the claimant
+        let mut designation = BeneficiaryDesignation {
+            beneficiaries: Vec::new(&env),
+            contingent: None,
+        };
+        let payees = split_payout(&env, 1_000, &designation, &Vec::new(&env), &claimant).unwrap();
+        assert_eq!(payees.len(), 1);
+        assert_eq!(payees.get(0).unwrap(), (claimant.clone(), 1_000));
+
+        designation.beneficiaries.push_back(shared::types::Beneficiary {
+            recipient: spouse.clone(),
+            share_bps: 3_333,
+        });
+        designation.beneficiaries.push_back(shared::types::Beneficiary {
+            recipient: child.clone(),
+            share_bps: 6_667,
+        });
+        designation.contingent = Some(estate.clone());
+
+        // The last beneficiary absorbs rounding
+        let payees = split_payout(&env, 1_000, &designation, &Vec::new(&env), &claimant).unwrap();
+        assert_eq!(payees.get(0).unwrap(), (spouse.clone(), 333));
+        assert_eq!(payees.get(1).unwrap(), (child.clone(), 667));
+
+        // An unavailable primary's share goes to the contingent
+        let mut unavailable = Vec::new(&env);
+        unavailable.push_back(spouse);
+        let payees = split_payout(&env, 1_000, &designation, &unavailable, &claimant).unwrap();
+        assert_eq!(payees.get(0).unwrap(), (estate, 333));
+        assert_eq!(payees.get(1).unwrap(), (child, 667));
+
+        // Only designated primaries can be marked unavailable
+        let mut unknown = Vec::new(&env);
+        unknown.push_back(claimant.clone());
+        assert_eq!(
+            split_payout(&env, 1_000, &designation, &unknown, &claimant),
+            Err(ContractError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_split_payout_amount_sums_to_converted_total() {
+        let env = Env::default();
+        let mut shares = Vec::new(&env);
+        shares.push_back(333);
+        shares.push_back(333);
+        shares.push_back(334);
+
+        // 1000 converted at 1.0015 is 1001; per-share conversion would pay 333 + 333 + 334
+        let parts = settlement::split_payout_amount(&env, 1_001, 1_000, &shares).unwrap();
+        assert_eq!(parts.get(0).unwrap(), 333);
+        assert_eq!(parts.get(1).unwrap(), 333);
+        assert_eq!(parts.get(2).unwrap(), 335);
+        assert_eq!(parts.iter().sum::<i128>(), 1_001);
+
+        assert_eq!(settlement::split_payout_amount(&env, 1_001, 0, &shares), None);
+    }
+
+    #[test]
+    fn test_calculate_net_payable_applies_terms() {
+        let env = Env::default();
+        let flood = Symbol::new(&env, "flood");
+        let mut sub_limits = Vec::new(&env);
+        sub_limits.push_back(shared::types::PerilSubLimit {
+            peril: flood.clone(),
+            limit: 6_000,
+        });
+        let terms = CoverageTerms {
+            deductible: 1_000,
+            coinsurance_bps: 2_000,
+            sub_limits,
+        };
+
+        // No peril: 10k gross, 1k deductible, 20% of the remaining 9k retained
+        let amounts = calculate_net_payable(10_000, &terms, None).unwrap();
+        assert_eq!(amounts.sub_limit_reduction, 0);
+        assert_eq!(amounts.deductible_amount, 1_000);
+        assert_eq!(amounts.coinsurance_amount, 1_800);
+        assert_eq!(amounts.net_amount, 7_200);
+
+        // Flood loss is capped at 6k before the deductible and co-insurance apply
+        let amounts = calculate_net_payable(10_000, &terms, Some(flood)).unwrap();
+        assert_eq!(amounts.gross_amount, 10_000);
+        assert_eq!(amounts.sub_limit_reduction, 4_000);
+        assert_eq!(amounts.coinsurance_amount, 1_000);
+        assert_eq!(amounts.net_amount, 4_000);
+
+        // Losses within the deductible pay nothing
+        assert_eq!(
+            calculate_net_payable(1_000, &terms, None),
+            Err(ContractError::BelowDeductible)
+        );
+    }
 }
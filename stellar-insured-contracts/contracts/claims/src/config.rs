@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Env};
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol};
+
+const DISPUTE_CONFIG: Symbol = symbol_short!("DSP_CFG");
 
 #[contracttype]
 pub struct Config {
@@ -8,8 +10,12 @@ pub struct Config {
 pub fn get_config(env: &Env) -> Config {
     env.storage()
         .instance()
-        .get(&"CONFIG")
+        .get(&DISPUTE_CONFIG)
         .unwrap_or(Config {
             dispute_window_secs: 86_400, // 24h default
         })
 }
+
+pub fn set_config(env: &Env, config: &Config) {
+    env.storage().instance().set(&DISPUTE_CONFIG, config);
+}
@@ -1,10 +1,43 @@
-use soroban_sdk::{contracttype, Address};
+use insurance_contracts::types::ClaimStatus;
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
 
+/// Where an escalated dispute is reviewed.
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeRoute {
+    /// Independent review panel, none of whom decided the claim
+    Panel,
+    /// Token-holder vote in the governance contract
+    Governance,
+}
+
+/// Binding outcome of a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeOutcome {
+    /// Claimant's position accepted; the claim pays the requested amount
+    Upheld,
+    /// Original decision stands
+    Dismissed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
 pub struct Dispute {
     pub claim_id: u64,
     pub raised_by: Address,
     pub reason: soroban_sdk::String,
+    /// Hashes of the supporting documents
+    pub evidence: Vec<BytesN<32>>,
+    /// Net amount the claimant says the claim should pay
+    pub requested_amount: i128,
+    /// Claim status that was contested (Rejected or Approved)
+    pub contested_status: ClaimStatus,
+    pub opened_at: u64,
+    pub route: Option<DisputeRoute>,
+    pub votes_uphold: u32,
+    pub votes_dismiss: u32,
+    pub outcome: Option<DisputeOutcome>,
+    pub resolved_at: Option<u64>,
     pub resolved: bool,
 }
@@ -0,0 +1,40 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol};
+
+/// Review state of a single evidence item.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EvidenceStatus {
+    /// Awaiting processor review
+    Pending,
+    /// Processor matched the document to its hash and accepted it
+    Verified,
+    /// Processor found the document does not support the claim
+    Insufficient,
+}
+
+/// Evidence supplied by a claimant or processor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceSubmission {
+    /// SHA-256 of the document
+    pub content_hash: BytesN<32>,
+    /// Off-chain location of the document (e.g. IPFS URI)
+    pub uri: String,
+    /// Kind of document, e.g. `police_rpt` or `invoice`
+    pub evidence_type: Symbol,
+}
+
+/// Evidence item attached to a claim.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceItem {
+    pub id: u32,
+    pub content_hash: BytesN<32>,
+    pub uri: String,
+    pub evidence_type: Symbol,
+    pub submitted_by: Address,
+    pub submitted_at: u64,
+    pub status: EvidenceStatus,
+    pub reviewed_by: Option<Address>,
+    pub reviewed_at: Option<u64>,
+}
@@ -0,0 +1,131 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol, Vec};
+
+/// Slashing roles counted against a claimant (mirrors `SlashingRole` in the slashing contract)
+pub const CLAIM_SUBMITTER_ROLE: u32 = 1;
+pub const POLICY_HOLDER_ROLE: u32 = 4;
+
+/// What happens to claims scoring at or above the flag threshold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FraudAction {
+    /// Flag the claim for reporting only
+    Flag,
+    /// Require one more processor approval than the amount tier does
+    ExtraApproval,
+    /// Require an Auditor countersignature
+    RouteToAuditor,
+}
+
+/// Signal sources and weights for fraud screening.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FraudScreeningConfig {
+    pub slashing_contract: Option<Address>,
+    pub risk_monitoring_contract: Option<Address>,
+    /// Points per slashing violation on record for the claimant
+    pub violation_weight: u32,
+    /// Points per other claim the claimant filed within `frequency_window_secs`
+    pub frequency_weight: u32,
+    pub frequency_window_secs: u64,
+    /// Points when the claim was filed within `early_claim_window_secs` of cover starting
+    pub early_claim_weight: u32,
+    pub early_claim_window_secs: u64,
+    /// Points per risk-monitoring alert raised within `alert_window_secs`
+    pub alert_weight: u32,
+    pub alert_window_secs: u64,
+    /// Scores at or above this are flagged
+    pub flag_threshold: u32,
+    pub action: FraudAction,
+}
+
+/// Fraud score recorded on a claim, with the signals behind it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FraudScore {
+    pub violation_count: u32,
+    pub recent_claims: u32,
+    pub secs_since_issuance: u64,
+    pub active_alerts: u32,
+    pub violation_points: u32,
+    pub frequency_points: u32,
+    pub early_claim_points: u32,
+    pub alert_points: u32,
+    pub total: u32,
+    pub flagged: bool,
+    pub action: Option<FraudAction>,
+    pub screened_by: Address,
+    pub screened_at: u64,
+}
+
+/// Mirrors `RiskFactorType` in the risk-monitoring contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RiskFactorType {
+    Price,
+    Liquidity,
+    Volatility,
+    Invariant,
+    Custom(Symbol),
+}
+
+/// Mirrors `RiskAlert` in the risk-monitoring contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskAlert {
+    pub sensor_id: u64,
+    pub factor_type: RiskFactorType,
+    pub value: i128,
+    pub threshold_violated: i128,
+    pub timestamp: u64,
+}
+
+#[contractclient(name = "SlashingClient")]
+pub trait SlashingInterface {
+    fn get_violation_count(env: Env, target: Address, role: u32) -> u32;
+}
+
+#[contractclient(name = "RiskMonitoringClient")]
+pub trait RiskMonitoringInterface {
+    fn get_alert_history(env: Env) -> Vec<RiskAlert>;
+}
+
+/// Combine the screening signals into a weighted score.
+pub fn score(
+    config: &FraudScreeningConfig,
+    violation_count: u32,
+    recent_claims: u32,
+    secs_since_issuance: u64,
+    active_alerts: u32,
+    screened_by: Address,
+    screened_at: u64,
+) -> FraudScore {
+    let violation_points = violation_count.saturating_mul(config.violation_weight);
+    let frequency_points = recent_claims.saturating_mul(config.frequency_weight);
+    let early_claim_points = if secs_since_issuance < config.early_claim_window_secs {
+        config.early_claim_weight
+    } else {
+        0
+    };
+    let alert_points = active_alerts.saturating_mul(config.alert_weight);
+    let total = violation_points
+        .saturating_add(frequency_points)
+        .saturating_add(early_claim_points)
+        .saturating_add(alert_points);
+    let flagged = total >= config.flag_threshold;
+
+    FraudScore {
+        violation_count,
+        recent_claims,
+        secs_since_issuance,
+        active_alerts,
+        violation_points,
+        frequency_points,
+        early_claim_points,
+        alert_points,
+        total,
+        flagged,
+        action: if flagged { Some(config.action.clone()) } else { None },
+        screened_by,
+        screened_at,
+    }
+}
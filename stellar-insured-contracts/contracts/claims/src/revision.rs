@@ -0,0 +1,24 @@
+use soroban_sdk::{contracttype, Symbol};
+
+/// What a claim revision recorded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RevisionKind {
+    Submitted,
+    Amended,
+    Withdrawn,
+}
+
+/// One entry in a claim's revision history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimRevision {
+    pub revision: u32,
+    pub kind: RevisionKind,
+    /// Claimed amount after this revision
+    pub amount: i128,
+    /// Evidence items on the claim after this revision
+    pub evidence_count: u32,
+    pub reason: Option<Symbol>,
+    pub revised_at: u64,
+}
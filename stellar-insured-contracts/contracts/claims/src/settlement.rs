@@ -0,0 +1,62 @@
+use soroban_sdk::{contractclient, contracttype, Env, Vec};
+
+use shared::types::{Asset, AssetConversionRate};
+
+/// Rate applied when the claim is paid in its own asset.
+pub const PAR_RATE_BPS: u32 = 10_000;
+
+/// The asset a claim was paid in and the FX rate applied.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimSettlement {
+    /// Asset the claim is denominated in (the claimant's preferred asset)
+    pub claim_asset: Asset,
+    /// Net amount owed, in `claim_asset`
+    pub claim_amount: i128,
+    pub payout_asset: Asset,
+    /// Net amount paid, in `payout_asset`
+    pub payout_amount: i128,
+    /// Conversion rate from `claim_asset` to `payout_asset`, in basis points
+    pub rate_bps: u32,
+    /// When the asset registry last updated the rate; None when no conversion was needed
+    pub rate_updated_at: Option<u64>,
+    pub settled_at: u64,
+}
+
+#[contractclient(name = "AssetRegistryClient")]
+pub trait AssetRegistryInterface {
+    fn accepts_claims(env: Env, asset: Asset) -> bool;
+    fn get_conversion_rate(env: Env, from_asset: Asset, to_asset: Asset) -> AssetConversionRate;
+    fn convert_amount(env: Env, from_asset: Asset, to_asset: Asset, amount: i128) -> i128;
+}
+
+#[contractclient(name = "RiskPoolClient")]
+pub trait RiskPoolInterface {
+    fn get_payout_liquidity(env: Env, asset: Asset, claim_id: u64) -> i128;
+}
+
+/// Split the converted `payout_amount` across payees in proportion to their `shares`
+/// of `claim_amount`. The last payee takes the rounding remainder so the parts add up
+/// to exactly `payout_amount`.
+pub fn split_payout_amount(
+    env: &Env,
+    payout_amount: i128,
+    claim_amount: i128,
+    shares: &Vec<i128>,
+) -> Option<Vec<i128>> {
+    if claim_amount <= 0 {
+        return None;
+    }
+    let mut parts = Vec::new(env);
+    let mut allocated: i128 = 0;
+    for (i, share) in shares.iter().enumerate() {
+        let part = if i as u32 + 1 == shares.len() {
+            payout_amount.checked_sub(allocated)?
+        } else {
+            payout_amount.checked_mul(share)?.checked_div(claim_amount)?
+        };
+        allocated = allocated.checked_add(part)?;
+        parts.push_back(part);
+    }
+    Some(parts)
+}
@@ -0,0 +1,113 @@
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, Vec};
+
+use insurance_contracts::types::ClaimStatus;
+
+/// Service-level targets for a product's claims, in seconds from submission.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimSla {
+    /// A processor must start review within this long
+    pub review_secs: u64,
+    /// The claim must be approved or rejected within this long
+    pub decision_secs: u64,
+    /// An approved claim must be settled within this long
+    pub payment_secs: u64,
+    /// Claims still open after this long are escalated and reassigned
+    pub hard_limit_secs: u64,
+}
+
+/// Lifecycle stage an SLA timer covers.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SlaStage {
+    Review,
+    Decision,
+    Payment,
+}
+
+/// When a claim reached each SLA milestone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimMilestones {
+    pub submitted_at: u64,
+    pub review_started_at: Option<u64>,
+    pub decided_at: Option<u64>,
+    pub paid_at: Option<u64>,
+}
+
+/// A missed SLA deadline, as reported to the recovery contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlaBreach {
+    pub stage: SlaStage,
+    pub deadline: u64,
+    pub detected_at: u64,
+    /// Recovery contract entry, when one is configured
+    pub recovery_entry_id: Option<u64>,
+}
+
+/// Backup assignment for a claim past its hard limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlaEscalation {
+    pub assigned_to: Address,
+    pub escalated_at: u64,
+}
+
+/// Where overdue claims are reported and who picks them up.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlaEscalationConfig {
+    pub recovery_contract: Option<Address>,
+    pub backup_processors: Vec<Address>,
+}
+
+/// Breach counts per stage, for regulatory reporting.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SlaMetrics {
+    pub review_breaches: u32,
+    pub decision_breaches: u32,
+    pub payment_breaches: u32,
+    pub escalations: u32,
+}
+
+/// Mirrors `InsuranceError::ClaimSlaBreached` in the error_handling contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum RecoveryError {
+    ClaimSlaBreached = 206,
+}
+
+#[contractclient(name = "RecoveryClient")]
+pub trait RecoveryInterface {
+    fn report_error(
+        env: Env,
+        source_contract: Address,
+        caller: Address,
+        error: RecoveryError,
+        subject_id: Option<u64>,
+    ) -> u64;
+    fn escalate_error(env: Env, caller: Address, entry_id: u64);
+}
+
+/// The stage a claim in `status` is timed against, and its deadline.
+pub fn current_deadline(sla: &ClaimSla, status: &ClaimStatus, submitted_at: u64) -> Option<(SlaStage, u64)> {
+    match status {
+        ClaimStatus::Submitted => Some((SlaStage::Review, submitted_at.saturating_add(sla.review_secs))),
+        ClaimStatus::UnderReview => Some((SlaStage::Decision, submitted_at.saturating_add(sla.decision_secs))),
+        ClaimStatus::Approved => Some((SlaStage::Payment, submitted_at.saturating_add(sla.payment_secs))),
+        _ => None,
+    }
+}
+
+impl SlaMetrics {
+    pub fn record_breach(&mut self, stage: SlaStage) {
+        match stage {
+            SlaStage::Review => self.review_breaches = self.review_breaches.saturating_add(1),
+            SlaStage::Decision => self.decision_breaches = self.decision_breaches.saturating_add(1),
+            SlaStage::Payment => self.payment_breaches = self.payment_breaches.saturating_add(1),
+        }
+    }
+}
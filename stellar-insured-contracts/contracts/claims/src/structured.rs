@@ -0,0 +1,90 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+use shared::types::Asset;
+
+/// One payment in a requested schedule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledPayment {
+    pub due_at: u64,
+    pub amount: i128,
+}
+
+/// A scheduled payment and whether it has been released.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Installment {
+    pub due_at: u64,
+    pub amount: i128,
+    pub paid_at: Option<u64>,
+}
+
+/// Offer to replace the unpaid installments with a single payment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Commutation {
+    pub lump_sum: i128,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub accepted_at: Option<u64>,
+}
+
+/// Structured settlement paying an approved claim in installments.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentSchedule {
+    pub payee: Address,
+    pub payout_asset: Asset,
+    pub installments: Vec<Installment>,
+    pub total_amount: i128,
+    pub paid_amount: i128,
+    pub commutation: Option<Commutation>,
+    pub created_by: Address,
+    pub created_at: u64,
+}
+
+/// Paid versus remaining installments on a schedule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleStatus {
+    pub paid_installments: u32,
+    pub remaining_installments: u32,
+    pub paid_amount: i128,
+    pub remaining_amount: i128,
+    pub next_due_at: Option<u64>,
+    /// Remaining installments were replaced by a lump sum
+    pub commuted: bool,
+}
+
+impl PaymentSchedule {
+    pub fn is_commuted(&self) -> bool {
+        self.commutation
+            .as_ref()
+            .map_or(false, |commutation| commutation.accepted_at.is_some())
+    }
+
+    pub fn status(&self) -> ScheduleStatus {
+        let commuted = self.is_commuted();
+        let mut paid_installments = 0u32;
+        let mut remaining_installments = 0u32;
+        let mut next_due_at = None;
+        for installment in self.installments.iter() {
+            if installment.paid_at.is_some() {
+                paid_installments += 1;
+            } else if !commuted {
+                remaining_installments += 1;
+                if next_due_at.is_none() {
+                    next_due_at = Some(installment.due_at);
+                }
+            }
+        }
+        ScheduleStatus {
+            paid_installments,
+            remaining_installments,
+            paid_amount: self.paid_amount,
+            remaining_amount: if commuted { 0 } else { self.total_amount - self.paid_amount },
+            next_due_at,
+            commuted,
+        }
+    }
+}
@@ -52,6 +52,8 @@ pub enum InsuranceError {
     ClaimInvalidState = 204,
     /// Required claim documentation is missing
     ClaimMissingDocuments = 205,
+    /// Claim has been pending past its service-level deadline
+    ClaimSlaBreached = 206,
 
     // ── Payments & Premiums (300–399) ────────────────────────────────────────
     /// Provided amount is zero or negative
@@ -126,6 +128,7 @@ impl InsuranceError {
             Self::ClaimDeadlineExceeded   => "Claim was submitted past the filing deadline",
             Self::ClaimInvalidState       => "Claim is in a state that does not permit this action",
             Self::ClaimMissingDocuments   => "Required documentation for the claim is missing",
+            Self::ClaimSlaBreached        => "Claim has exceeded its service-level deadline",
 
             Self::PaymentInvalidAmount    => "Payment amount must be greater than zero",
             Self::PaymentUnsupportedAsset => "This asset type is not accepted for payment",
@@ -172,6 +175,7 @@ impl InsuranceError {
             Self::ClaimDeadlineExceeded   => "Contact support for late-filing assistance",
             Self::ClaimInvalidState       => "Check the claim status before retrying",
             Self::ClaimMissingDocuments   => "Upload the required documents and resubmit",
+            Self::ClaimSlaBreached        => "Assign a processor to review or decide the claim",
 
             Self::PaymentInvalidAmount    => "Provide a positive payment amount",
             Self::PaymentUnsupportedAsset => "Use an accepted asset (e.g., USDC)",
@@ -219,6 +223,7 @@ impl InsuranceError {
                 | Self::ComplianceViolation
                 | Self::ClaimMissingDocuments
                 | Self::ClaimDeadlineExceeded
+                | Self::ClaimSlaBreached
         )
     }
 }
\ No newline at end of file
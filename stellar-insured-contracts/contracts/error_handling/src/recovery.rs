@@ -107,13 +107,21 @@ impl RecoveryContract {
         Ok(())
     }
 
-    /// Escalate a pending error to the operator for manual intervention.
-    pub fn escalate_error(env: Env, entry_id: u64) -> Result<(), InsuranceError> {
-        registry::get_admin(&env).require_auth();
+    /// Escalate a pending error to the operator for manual intervention
+    /// (admin, or the contract that reported it).
+    pub fn escalate_error(env: Env, caller: Address, entry_id: u64) -> Result<(), InsuranceError> {
+        caller.require_auth();
 
         let mut entry = registry::get_error(&env, entry_id)
             .ok_or(InsuranceError::RecordNotFound)?;
 
+        let admin = registry::get_admin(&env);
+        if caller != admin
+            && !(caller == entry.source_contract && registry::is_authorized_reporter(&env, &caller))
+        {
+            return Err(InsuranceError::Unauthorized);
+        }
+
         entry.recovery_action = RecoveryAction::ManualInterventionRequired;
         entry.recovery_status = RecoveryStatus::EscalatedToOperator;
         registry::save_error(&env, &entry);
@@ -207,6 +215,7 @@ impl RecoveryContract {
 
             InsuranceError::OracleDataStale
             | InsuranceError::KycExpired
+            | InsuranceError::ClaimSlaBreached
             | InsuranceError::StorageExpired => ErrorSeverity::Warning,
 
             _ => ErrorSeverity::Info,
@@ -220,13 +220,34 @@ fn test_escalate_error() {
     c.authorize_reporter(&reporter);
 
     let entry_id = c.report_error(&reporter, &caller, &InsuranceError::ComplianceViolation, &None);
-    c.escalate_error(&entry_id);
+    c.escalate_error(&admin, &entry_id);
 
     let entry = c.get_error(&entry_id);
     assert!(matches!(entry.recovery_status, RecoveryStatus::EscalatedToOperator));
     assert!(matches!(entry.recovery_action, RecoveryAction::ManualInterventionRequired));
 }
 
+#[test]
+fn test_reporter_escalates_own_error() {
+    let (env, id, admin) = setup();
+    let c = client(&env, &id);
+    let reporter = Address::generate(&env);
+    let other = Address::generate(&env);
+    let caller = Address::generate(&env);
+
+    c.initialize(&admin);
+    c.authorize_reporter(&reporter);
+    c.authorize_reporter(&other);
+
+    let entry_id = c.report_error(&reporter, &caller, &InsuranceError::ClaimSlaBreached, &Some(7));
+    assert!(c.try_escalate_error(&other, &entry_id).is_err());
+
+    c.escalate_error(&reporter, &entry_id);
+    let entry = c.get_error(&entry_id);
+    assert!(matches!(entry.recovery_status, RecoveryStatus::EscalatedToOperator));
+    assert!(matches!(entry.severity, ErrorSeverity::Warning));
+}
+
 // ── Emergency Controls ────────────────────────────────────────────────────────
 
 #[test]
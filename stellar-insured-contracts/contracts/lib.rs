@@ -18,6 +18,8 @@ pub mod authorization {
         register_trusted_contract, unregister_trusted_contract,
         is_trusted_contract, require_trusted_contract,
         verify_and_require_role, verify_and_check_permission,
+        get_threshold, get_approvals, check_multisig_auth,
+        check_multisig_auth_with_threshold, clear_approvals,
     };
 }
 
@@ -44,6 +46,10 @@ pub mod types {
         Approved,
         Rejected,
         Settled,
+        /// Decision is contested by the claimant and awaiting a binding resolution
+        Disputed,
+        /// Withdrawn by the claimant before a decision
+        Withdrawn,
     }
 
     /// Governance proposal status
@@ -214,6 +220,94 @@ pub mod rate_limit {
     }
 }
 
+/// Token custody helpers mapping protocol assets onto Soroban token contracts.
+pub mod token {
+    use super::*;
+    use shared::types::Asset;
+    use soroban_sdk::token::Client as TokenClient;
+
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    enum TokenKey {
+        AssetToken(Asset),
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum TokenError {
+        /// No token contract is registered for the asset
+        UnsupportedAsset,
+        /// The token contract rejected the transfer
+        TransferFailed,
+    }
+
+    /// Register the token contract (e.g. the Stellar Asset Contract) backing an asset.
+    pub fn set_asset_token(env: &Env, asset: &Asset, token: &Address) {
+        env.storage()
+            .persistent()
+            .set(&TokenKey::AssetToken(asset.clone()), token);
+    }
+
+    /// Resolve the token contract for an asset.
+    /// Contract assets carry their own address; Native and Stellar assets must be registered.
+    pub fn get_asset_token(env: &Env, asset: &Asset) -> Option<Address> {
+        match asset {
+            Asset::Contract(address) => Some(address.clone()),
+            _ => env
+                .storage()
+                .persistent()
+                .get(&TokenKey::AssetToken(asset.clone())),
+        }
+    }
+
+    /// Transfer `amount` of `asset` from `from` to `to` through the token interface.
+    pub fn transfer(
+        env: &Env,
+        asset: &Asset,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
+        let token = get_asset_token(env, asset).ok_or(TokenError::UnsupportedAsset)?;
+        match TokenClient::new(env, &token).try_transfer(from, to, &amount) {
+            Ok(Ok(())) => Ok(()),
+            _ => Err(TokenError::TransferFailed),
+        }
+    }
+
+    /// Transfer `amount` of `asset` from `from` to `to` using an allowance granted to `spender`.
+    pub fn transfer_from(
+        env: &Env,
+        asset: &Asset,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
+        let token = get_asset_token(env, asset).ok_or(TokenError::UnsupportedAsset)?;
+        match TokenClient::new(env, &token).try_transfer_from(spender, from, to, &amount) {
+            Ok(Ok(())) => Ok(()),
+            _ => Err(TokenError::TransferFailed),
+        }
+    }
+
+    /// Read the allowance `from` has granted `spender` for `asset`.
+    pub fn allowance(
+        env: &Env,
+        asset: &Asset,
+        from: &Address,
+        spender: &Address,
+    ) -> Result<i128, TokenError> {
+        let token = get_asset_token(env, asset).ok_or(TokenError::UnsupportedAsset)?;
+        Ok(TokenClient::new(env, &token).allowance(from, spender))
+    }
+
+    /// Read the on-chain balance of `asset` held by `owner`.
+    pub fn balance(env: &Env, asset: &Asset, owner: &Address) -> Result<i128, TokenError> {
+        let token = get_asset_token(env, asset).ok_or(TokenError::UnsupportedAsset)?;
+        Ok(TokenClient::new(env, &token).balance(owner))
+    }
+}
+
 /// Utility functions for contract operations
 pub mod utils {
     use super::*;
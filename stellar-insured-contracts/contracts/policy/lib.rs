@@ -1,14 +1,21 @@
 #![no_std]
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Vec};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, xdr::ToXdr, Address, BytesN, Env,
+    IntoVal, Symbol, Vec,
+};
 
 // Import authorization from the common library
 use insurance_contracts::authorization::{
     get_role, has_role, initialize_admin, register_trusted_contract, require_admin,
-    require_policy_management, Role,
+    require_policy_management, require_trusted_contract, Role,
 };
 use insurance_contracts::rate_limit::{self, RateLimitConfig};
+use insurance_contracts::token::{self, TokenError};
 use insurance_contracts::gas_optimization::{GasOptimizer, PerformanceMonitor};
 use insurance_contracts::emergency_pause::EmergencyPause;
+use shared::types::{
+    Beneficiary, BeneficiaryDesignation, CertificateStatus, CoverageTerms, MemberCertificate,
+};
 
 // Import invariant checks and error types
 use insurance_invariants::{InvariantError, ProtocolInvariants};
@@ -33,6 +40,21 @@ const POLICY_ISSUE_SCOPE: &str = "policy_issue";
 const DEFAULT_POLICY_ISSUE_RATE_LIMIT_MAX_CALLS: u32 = 5;
 const DEFAULT_POLICY_ISSUE_RATE_LIMIT_WINDOW_SECS: u64 = 60;
 
+/// Default time after `end_time` during which an unpaid renewal keeps coverage alive
+const DEFAULT_GRACE_PERIOD_SECS: u64 = 7 * 86400;
+
+/// Maximum number of policies a single keeper sweep may process
+const MAX_SWEEP_BATCH: u32 = 25;
+
+/// How long a holder quote locks in price and capacity
+const QUOTE_VALIDITY_SECS: u64 = 900;
+
+/// Maximum number of primary beneficiaries on a policy
+const MAX_BENEFICIARIES: u32 = 10;
+
+/// Basis points denominator used for fee splits
+const BASIS_POINTS: i128 = 10_000;
+
 #[contract]
 pub struct PolicyContract;
 
@@ -45,12 +67,45 @@ pub enum DataKey {
     PolicyCounter,
     PolicyStatusHistory(u64), // history_id
     PolicyStatusHistoryCounter,
+    ShortRatePenalty(u64), // product_id -> penalty in basis points
+    Endorsement(u64),      // endorsement_id
+    EndorsementCounter,
+    PendingEndorsement(u64),       // policy_id -> endorsement_id
+    PolicyAmendment(u64, u32),     // (policy_id, version)
+    PolicyAmendmentCount(u64),     // policy_id -> number of recorded versions
+    Quote(u64),                    // quote_id
+    QuoteCounter,
+    ProductCapacity(u64),          // product_id -> remaining coverage capacity
+    GracePeriod,                   // seconds after end_time before an unpaid policy lapses
+    ReinstatementFee,              // basis points of the reinstatement premium
+    LapseHistory(u64),             // policy_id -> Vec<LapseWindow>
+    RenewalTerm(u64),              // policy_id -> duration_days used for auto-renewal
+    KeeperBounty,                  // bounty per policy acted on by a keeper sweep
+    CoverageTerms(u64),            // policy_id -> deductible, co-insurance and peril sub-limits
+    CoverageTermsHistory(u64),     // policy_id -> Vec<(effective_from, CoverageTerms)>
+    ClaimLimits(u64),              // policy_id -> (aggregate_limit, per_occurrence_limit)
+    CoverageDrawn(u64),            // policy_id -> total paid or reserved for claims
+    ClaimDrawdown(u64, u64),       // (policy_id, claim_id) -> amount drawn for the claim
+    Beneficiaries(u64),            // policy_id -> BeneficiaryDesignation
+    BeneficiaryChange(u64, u32),   // (policy_id, version) -> BeneficiaryChange
+    BeneficiaryChangeCount(u64),   // policy_id -> number of recorded designations
+    GroupPolicy(u64),              // master policy_id -> GroupPolicySummary
+    Certificate(u64),              // certificate_id -> MemberCertificate
+    CertificateCounter,
+    GroupMember(u64, u32),         // (master policy_id, enrollment index) -> certificate_id
+    MemberCertificate(u64, Address), // (master policy_id, member) -> active certificate_id
+    CertificateDrawn(u64),         // certificate_id -> total drawn for the member's claims
+    PremiumFeeRate(u64),           // policy_id -> treasury fee (basis points) taken from its premium
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Config {
     pub risk_pool: Address,
+    /// Treasury receiving the protocol fee share of each premium
+    pub treasury: Option<Address>,
+    /// Product template contract pricing holder quotes
+    pub product_template: Option<Address>,
 }
 
 #[contracttype]
@@ -61,6 +116,148 @@ pub struct PolicyStatusHistory {
     pub new_state: PolicyState,
     pub actor: Address,
     pub timestamp: u64,
+    /// Premium refunded to the holder as part of this transition
+    pub refund_amount: i128,
+}
+
+/// Lifecycle of a mid-term endorsement proposal.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EndorsementStatus {
+    Proposed,
+    Accepted,
+    Declined,
+}
+
+/// A change to policy terms proposed by a PolicyManager and countersigned by the holder.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Endorsement {
+    pub id: u64,
+    pub policy_id: u64,
+    pub proposed_by: Address,
+    pub coverage_amount: i128,
+    pub coverage_asset: shared::types::Asset,
+    pub end_time: u64,
+    /// Additional (positive) or returned (negative) premium; quoted at proposal, final on acceptance
+    pub premium_adjustment: i128,
+    pub status: EndorsementStatus,
+    pub proposed_at: u64,
+    pub decided_at: Option<u64>,
+}
+
+/// Versioned snapshot of the terms in force from `effective_from`.
+/// Version 0 holds the terms at issuance; each accepted endorsement appends a version.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyAmendment {
+    pub policy_id: u64,
+    pub version: u32,
+    pub endorsement_id: Option<u64>,
+    pub coverage_amount: i128,
+    pub coverage_asset: shared::types::Asset,
+    pub premium_amount: i128,
+    pub end_time: u64,
+    pub premium_adjustment: i128,
+    pub effective_from: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QuoteStatus {
+    Open,
+    Purchased,
+    Expired,
+}
+
+/// A time-limited, product-priced offer a holder can redeem with `purchase_policy`.
+/// `digest` binds the quoted terms; the holder signs it when purchasing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyQuote {
+    pub id: u64,
+    pub holder: Address,
+    pub product_id: u64,
+    pub coverage_amount: i128,
+    pub premium_amount: i128,
+    pub duration_days: u32,
+    pub deductible: i128,
+    pub auto_renew: bool,
+    pub coverage_asset: shared::types::Asset,
+    pub premium_asset: shared::types::Asset,
+    pub issued_at: u64,
+    pub expires_at: u64,
+    pub digest: BytesN<32>,
+    pub status: QuoteStatus,
+    /// Whether the quote holds product capacity that must be released on expiry
+    pub capacity_locked: bool,
+}
+
+/// A period during which a policy was lapsed and provided no cover.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LapseWindow {
+    pub lapsed_at: u64,
+    pub reinstated_at: Option<u64>,
+}
+
+/// Claim limits of a policy and how much of the aggregate has been drawn down.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimLimits {
+    /// Total payable across all claims over the life of the policy
+    pub aggregate_limit: i128,
+    /// Maximum payable for a single claim
+    pub per_occurrence_limit: i128,
+    /// Amount already reserved or paid for approved claims
+    pub drawn_down: i128,
+    pub remaining: i128,
+}
+
+/// Audit record of a beneficiary designation made by the holder.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryChange {
+    pub policy_id: u64,
+    pub version: u32,
+    pub changed_by: Address,
+    pub designation: BeneficiaryDesignation,
+    pub changed_at: u64,
+}
+
+/// Enrollment totals for a group master policy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupPolicySummary {
+    pub master_policy_id: u64,
+    /// Certificates ever issued, including removed members
+    pub member_count: u32,
+    pub active_members: u32,
+    /// Sum of coverage across active certificates
+    pub certificate_coverage: i128,
+}
+
+/// Result of a paginated group member query.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PaginatedMembersResult {
+    pub certificates: Vec<MemberCertificate>,
+    pub total_count: u32,
+}
+
+/// Outcome of a keeper sweep over the active policy list.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SweepResult {
+    /// Number of policies inspected
+    pub processed: u32,
+    pub expired: u32,
+    pub renewed: u32,
+    pub lapsed: u32,
+    /// Bounty paid to the keeper by the treasury
+    pub bounty_paid: i128,
+    /// Cursor for the next sweep, or None once the end of the list is reached
+    pub next_cursor: Option<u32>,
 }
 
 /// Structured view of a policy for frontend/indexer consumption.
@@ -80,7 +277,7 @@ pub struct PolicyView {
     pub start_time: u64,
     /// Policy end timestamp
     pub end_time: u64,
-    /// Current state (ACTIVE, EXPIRED, CANCELLED)
+    /// Current state (ACTIVE, LAPSED, EXPIRED, CANCELLED)
     pub state: PolicyState,
     /// Timestamp when policy was created
     pub created_at: u64,
@@ -111,6 +308,8 @@ pub struct PaginatedPoliciesResult {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PolicyState {
     ACTIVE,
+    /// Renewal went unpaid past the grace period; cover is suspended until reinstated
+    LAPSED,
     EXPIRED,
     CANCELLED,
 }
@@ -122,6 +321,10 @@ impl PolicyState {
     /// Valid transitions:
     /// - ACTIVE → EXPIRED
     /// - ACTIVE → CANCELLED
+    /// - ACTIVE → LAPSED
+    /// - LAPSED → ACTIVE (reinstatement)
+    /// - LAPSED → EXPIRED
+    /// - LAPSED → CANCELLED
     /// - EXPIRED → (no transitions)
     /// - CANCELLED → (no transitions)
     pub fn can_transition_to(self, next: PolicyState) -> bool {
@@ -129,6 +332,11 @@ impl PolicyState {
             // ACTIVE can transition to EXPIRED or CANCELLED
             (PolicyState::ACTIVE, PolicyState::EXPIRED) => true,
             (PolicyState::ACTIVE, PolicyState::CANCELLED) => true,
+            (PolicyState::ACTIVE, PolicyState::LAPSED) => true,
+            // LAPSED can be reinstated, or closed out
+            (PolicyState::LAPSED, PolicyState::ACTIVE) => true,
+            (PolicyState::LAPSED, PolicyState::EXPIRED) => true,
+            (PolicyState::LAPSED, PolicyState::CANCELLED) => true,
             // EXPIRED and CANCELLED are terminal states - no transitions allowed
             (PolicyState::EXPIRED, _) => false,
             (PolicyState::CANCELLED, _) => false,
@@ -156,6 +364,8 @@ pub struct Policy {
     pub premium_asset: shared::types::Asset,
     /// Whether multi-asset claims are allowed for this policy
     pub allow_multi_asset_claims: bool,
+    /// Product the policy was sold under, if any
+    pub product_id: Option<u64>,
 }
 
 // Step 4: Implement Policy Methods
@@ -172,6 +382,7 @@ impl Policy {
         coverage_asset: shared::types::Asset,
         premium_asset: shared::types::Asset,
         allow_multi_asset_claims: bool,
+        product_id: Option<u64>,
     ) -> Self {
         Policy {
             holder,
@@ -185,6 +396,7 @@ impl Policy {
             coverage_asset,
             premium_asset,
             allow_multi_asset_claims,
+            product_id,
         }
     }
 
@@ -217,6 +429,11 @@ impl Policy {
         matches!(self.state, PolicyState::ACTIVE)
     }
 
+    /// Checks if the policy is lapsed
+    pub fn is_lapsed(&self) -> bool {
+        matches!(self.state, PolicyState::LAPSED)
+    }
+
     /// Checks if the policy is expired
     pub fn is_expired(&self) -> bool {
         matches!(self.state, PolicyState::EXPIRED)
@@ -238,6 +455,17 @@ impl PolicyStateMachine {
         policy_id: u64,
         target_state: PolicyState,
         actor: Address,
+    ) -> Result<(), ContractError> {
+        Self::transition_with_refund(env, policy_id, target_state, actor, 0)
+    }
+
+    /// Same as `transition`, additionally recording a premium refund in the history entry
+    pub fn transition_with_refund(
+        env: &Env,
+        policy_id: u64,
+        target_state: PolicyState,
+        actor: Address,
+        refund_amount: i128,
     ) -> Result<(), ContractError> {
         // Get current policy
         let mut policy: Policy = env
@@ -259,8 +487,11 @@ impl PolicyStateMachine {
         // Save updated policy
         env.storage().persistent().set(&DataKey::Policy(policy_id), &policy);
 
-        // Remove from active policy list if transitioning to a terminal state
-        if matches!(target_state, PolicyState::CANCELLED | PolicyState::EXPIRED) {
+        // Remove from active policy list once the policy stops providing cover
+        if matches!(
+            target_state,
+            PolicyState::CANCELLED | PolicyState::EXPIRED | PolicyState::LAPSED
+        ) {
             let mut active_list: Vec<u64> = env
                 .storage()
                 .persistent()
@@ -278,6 +509,17 @@ impl PolicyStateMachine {
             env.storage()
                 .persistent()
                 .set(&ACTIVE_POLICY_LIST, &new_list);
+        } else if previous_state == PolicyState::LAPSED {
+            // Reinstated policies rejoin the active list
+            let mut active_list: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&ACTIVE_POLICY_LIST)
+                .unwrap_or_else(|| Vec::new(env));
+            active_list.push_back(policy_id);
+            env.storage()
+                .persistent()
+                .set(&ACTIVE_POLICY_LIST, &active_list);
         }
 
         // Record history
@@ -288,6 +530,7 @@ impl PolicyStateMachine {
             new_state: target_state,
             actor: actor.clone(),
             timestamp: env.ledger().timestamp(),
+            refund_amount,
         };
         env.storage()
             .persistent()
@@ -296,12 +539,13 @@ impl PolicyStateMachine {
         // Emit event
         let event_name = match target_state {
             PolicyState::ACTIVE => Symbol::new(env, "PolicyActivated"),
+            PolicyState::LAPSED => Symbol::new(env, "PolicyLapsed"),
             PolicyState::EXPIRED => Symbol::new(env, "PolicyExpired"),
             PolicyState::CANCELLED => Symbol::new(env, "PolicyCancelled"),
         };
         env.events().publish(
             (event_name, policy_id),
-            (actor, previous_state, target_state, env.ledger().timestamp()),
+            (actor, previous_state, target_state, env.ledger().timestamp(), refund_amount),
         );
 
         Ok(())
@@ -372,6 +616,31 @@ pub enum ContractError {
     Overflow2 = 107,
     RateLimitExceeded = 108,
     InvalidRateLimitConfig = 109,
+    // Premium collection errors
+    UnsupportedAsset = 110,
+    PremiumTransferFailed = 111,
+    // Endorsement errors
+    EndorsementPending = 112,
+    EndorsementNotPending = 113,
+    OutsideCoveragePeriod = 114,
+    // Quote errors
+    QuoteExpired = 115,
+    QuoteNotOpen = 116,
+    QuoteMismatch = 117,
+    InsufficientCapacity = 118,
+    ProductTemplateNotConfigured = 119,
+    // Renewal and lapse errors
+    GracePeriodElapsed = 120,
+    GracePeriodNotElapsed = 121,
+    PolicyLapsed = 122,
+    // Loss-sharing errors
+    InvalidCoverageTerms = 123,
+    AggregateLimitExhausted = 124,
+    InvalidBeneficiaries = 125,
+    // Group policy errors
+    NotMasterPolicy = 126,
+    MemberAlreadyEnrolled = 127,
+    CertificateNotActive = 128,
 }
 
 impl From<insurance_contracts::authorization::AuthError> for ContractError {
@@ -418,6 +687,15 @@ impl From<insurance_contracts::rate_limit::RateLimitError> for ContractError {
     }
 }
 
+impl From<TokenError> for ContractError {
+    fn from(err: TokenError) -> Self {
+        match err {
+            TokenError::UnsupportedAsset => ContractError::UnsupportedAsset,
+            TokenError::TransferFailed => ContractError::PremiumTransferFailed,
+        }
+    }
+}
+
 fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError> {
     Ok(())
 }
@@ -461,6 +739,678 @@ fn validate_duration(duration_days: u32) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Computes the unearned premium for the unused term of a policy cancelled at `cancelled_at`,
+/// less the product's short-rate penalty (basis points of the unearned amount).
+fn calculate_unearned_premium(
+    policy: &Policy,
+    cancelled_at: u64,
+    short_rate_penalty_bps: u32,
+) -> Result<i128, ContractError> {
+    if cancelled_at >= policy.end_time || policy.end_time <= policy.start_time {
+        return Ok(0);
+    }
+
+    let term = i128::from(policy.end_time - policy.start_time);
+    let remaining = i128::from(policy.end_time - core::cmp::max(cancelled_at, policy.start_time));
+
+    let unearned = policy
+        .premium_amount
+        .checked_mul(remaining)
+        .ok_or(ContractError::Overflow2)?
+        / term;
+    let penalty = unearned
+        .checked_mul(i128::from(short_rate_penalty_bps))
+        .ok_or(ContractError::Overflow2)?
+        / BASIS_POINTS;
+
+    unearned.checked_sub(penalty).ok_or(ContractError::Overflow2)
+}
+
+fn get_short_rate_penalty(env: &Env, policy: &Policy) -> u32 {
+    match policy.product_id {
+        Some(product_id) => env
+            .storage()
+            .persistent()
+            .get(&DataKey::ShortRatePenalty(product_id))
+            .unwrap_or(0u32),
+        None => 0,
+    }
+}
+
+/// Pro-rata premium adjustment for moving a policy onto new coverage and end time at
+/// `effective_at`: unearned premium under the new terms minus unearned premium under the old.
+fn calculate_endorsement_premium(
+    policy: &Policy,
+    new_coverage_amount: i128,
+    new_end_time: u64,
+    effective_at: u64,
+) -> Result<i128, ContractError> {
+    if policy.end_time <= policy.start_time || policy.coverage_amount <= 0 {
+        return Ok(0);
+    }
+
+    let term = i128::from(policy.end_time - policy.start_time);
+    let from = core::cmp::max(effective_at, policy.start_time);
+    let old_remaining = i128::from(policy.end_time.saturating_sub(from));
+    let new_remaining = i128::from(new_end_time.saturating_sub(from));
+
+    let old_unearned = policy
+        .premium_amount
+        .checked_mul(old_remaining)
+        .ok_or(ContractError::Overflow2)?
+        / term;
+    let new_unearned = policy
+        .premium_amount
+        .checked_mul(new_remaining)
+        .ok_or(ContractError::Overflow2)?
+        / term;
+    let new_unearned = new_unearned
+        .checked_mul(new_coverage_amount)
+        .ok_or(ContractError::Overflow2)?
+        / policy.coverage_amount;
+
+    new_unearned.checked_sub(old_unearned).ok_or(ContractError::Overflow2)
+}
+
+/// Premium for adding `coverage_amount` to a master policy at `enrolled_at`:
+/// the master's premium rate applied to the remaining term.
+fn calculate_certificate_premium(
+    master: &Policy,
+    coverage_amount: i128,
+    enrolled_at: u64,
+) -> Result<i128, ContractError> {
+    if master.end_time <= master.start_time || master.coverage_amount <= 0 {
+        return Ok(0);
+    }
+
+    let term = i128::from(master.end_time - master.start_time);
+    let remaining = i128::from(
+        master
+            .end_time
+            .saturating_sub(core::cmp::max(enrolled_at, master.start_time)),
+    );
+
+    let premium = master
+        .premium_amount
+        .checked_mul(coverage_amount)
+        .ok_or(ContractError::Overflow2)?
+        / master.coverage_amount;
+    Ok(premium.checked_mul(remaining).ok_or(ContractError::Overflow2)? / term)
+}
+
+/// Unearned share of a certificate's premium when the member is removed at `removed_at`.
+fn calculate_certificate_refund(
+    certificate: &MemberCertificate,
+    end_time: u64,
+    removed_at: u64,
+) -> Result<i128, ContractError> {
+    if removed_at >= end_time || end_time <= certificate.enrolled_at {
+        return Ok(0);
+    }
+
+    let term = i128::from(end_time - certificate.enrolled_at);
+    let remaining = i128::from(end_time - core::cmp::max(removed_at, certificate.enrolled_at));
+    Ok(certificate
+        .premium_amount
+        .checked_mul(remaining)
+        .ok_or(ContractError::Overflow2)?
+        / term)
+}
+
+fn get_group_policy(env: &Env, policy_id: u64) -> Result<GroupPolicySummary, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GroupPolicy(policy_id))
+        .ok_or(ContractError::NotMasterPolicy)
+}
+
+fn get_grace_period(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GracePeriod)
+        .unwrap_or(DEFAULT_GRACE_PERIOD_SECS)
+}
+
+/// Premium for extending cover by `duration_days`, at the rate of the current term.
+fn calculate_renewal_premium(policy: &Policy, duration_days: u32) -> Result<i128, ContractError> {
+    let term = policy.end_time.saturating_sub(policy.start_time);
+    if term == 0 {
+        return Ok(policy.premium_amount);
+    }
+    let extension = u64::from(duration_days).checked_mul(86400).ok_or(ContractError::Overflow2)?;
+    Ok(policy
+        .premium_amount
+        .checked_mul(i128::from(extension))
+        .ok_or(ContractError::Overflow2)?
+        / i128::from(term))
+}
+
+fn get_lapse_history(env: &Env, policy_id: u64) -> Vec<LapseWindow> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LapseHistory(policy_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_amendment_count(env: &Env, policy_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PolicyAmendmentCount(policy_id))
+        .unwrap_or(0u32)
+}
+
+/// Appends the policy's current terms as a new amendment version.
+fn record_amendment(
+    env: &Env,
+    policy_id: u64,
+    policy: &Policy,
+    endorsement_id: Option<u64>,
+    premium_adjustment: i128,
+    effective_from: u64,
+) -> u32 {
+    let version = get_amendment_count(env, policy_id);
+    let amendment = PolicyAmendment {
+        policy_id,
+        version,
+        endorsement_id,
+        coverage_amount: policy.coverage_amount,
+        coverage_asset: policy.coverage_asset.clone(),
+        premium_amount: policy.premium_amount,
+        end_time: policy.end_time,
+        premium_adjustment,
+        effective_from,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::PolicyAmendment(policy_id, version), &amendment);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PolicyAmendmentCount(policy_id), &(version + 1));
+    version
+}
+
+/// Loss-sharing terms for a policy; policies without explicit terms pay the gross loss.
+fn get_coverage_terms(env: &Env, policy_id: u64) -> CoverageTerms {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CoverageTerms(policy_id))
+        .unwrap_or(CoverageTerms {
+            deductible: 0,
+            coinsurance_bps: 0,
+            sub_limits: Vec::new(env),
+        })
+}
+
+/// Stores `terms` as the policy's current loss-sharing terms and records them as in force
+/// from `effective_from`, so claims are assessed against the terms at their loss date.
+fn store_coverage_terms(env: &Env, policy_id: u64, terms: &CoverageTerms, effective_from: u64) {
+    let mut history: Vec<(u64, CoverageTerms)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoverageTermsHistory(policy_id))
+        .unwrap_or(Vec::new(env));
+    history.push_back((effective_from, terms.clone()));
+    env.storage()
+        .persistent()
+        .set(&DataKey::CoverageTermsHistory(policy_id), &history);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CoverageTerms(policy_id), terms);
+}
+
+/// Aggregate and per-occurrence limits default to the current coverage amount.
+fn get_claim_limits(env: &Env, policy_id: u64, policy: &Policy) -> ClaimLimits {
+    let (aggregate_limit, per_occurrence_limit): (i128, i128) = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ClaimLimits(policy_id))
+        .unwrap_or((policy.coverage_amount, policy.coverage_amount));
+    let drawn_down: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoverageDrawn(policy_id))
+        .unwrap_or(0);
+    ClaimLimits {
+        aggregate_limit,
+        per_occurrence_limit,
+        drawn_down,
+        remaining: aggregate_limit.saturating_sub(drawn_down).max(0),
+    }
+}
+
+/// Draw `amount` down from a policy's aggregate, capped by the per-occurrence limit and what
+/// remains. Each claim may draw once. Returns the amount actually drawn.
+fn draw_down(env: &Env, policy_id: u64, claim_id: u64, amount: i128) -> Result<i128, ContractError> {
+    let policy: Policy = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Policy(policy_id))
+        .ok_or(ContractError::NotFound)?;
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::ClaimDrawdown(policy_id, claim_id))
+    {
+        return Err(ContractError::AlreadyExists);
+    }
+
+    let limits = get_claim_limits(env, policy_id, &policy);
+    let drawn = amount.min(limits.per_occurrence_limit).min(limits.remaining);
+    if drawn <= 0 {
+        return Err(ContractError::AggregateLimitExhausted);
+    }
+
+    let drawn_down = limits.drawn_down.checked_add(drawn).ok_or(ContractError::Overflow2)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CoverageDrawn(policy_id), &drawn_down);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ClaimDrawdown(policy_id, claim_id), &drawn);
+
+    env.events().publish(
+        (Symbol::new(env, "CoverageDrawnDown"), policy_id),
+        (
+            claim_id,
+            amount,
+            drawn,
+            limits.aggregate_limit - drawn_down,
+            env.ledger().timestamp(),
+        ),
+    );
+
+    Ok(drawn)
+}
+
+/// Move a claim's existing drawdown to `new_amount`. Increases are capped by the
+/// per-occurrence limit and what remains; decreases return coverage to the aggregate.
+/// Returns the amount now drawn for the claim.
+fn adjust_draw_down(
+    env: &Env,
+    policy_id: u64,
+    claim_id: u64,
+    new_amount: i128,
+) -> Result<i128, ContractError> {
+    let policy: Policy = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Policy(policy_id))
+        .ok_or(ContractError::NotFound)?;
+    let previous: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ClaimDrawdown(policy_id, claim_id))
+        .ok_or(ContractError::NotFound)?;
+
+    let limits = get_claim_limits(env, policy_id, &policy);
+    let drawn = if new_amount > previous {
+        let headroom = previous.checked_add(limits.remaining).ok_or(ContractError::Overflow2)?;
+        new_amount.min(limits.per_occurrence_limit).min(headroom).max(previous)
+    } else {
+        new_amount
+    };
+
+    let drawn_down = limits
+        .drawn_down
+        .checked_add(drawn - previous)
+        .ok_or(ContractError::Overflow2)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CoverageDrawn(policy_id), &drawn_down);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ClaimDrawdown(policy_id, claim_id), &drawn);
+
+    env.events().publish(
+        (Symbol::new(env, "CoverageDrawdownAdjusted"), policy_id),
+        (
+            claim_id,
+            previous,
+            drawn,
+            limits.aggregate_limit - drawn_down,
+            env.ledger().timestamp(),
+        ),
+    );
+
+    Ok(drawn)
+}
+
+/// Primary shares must be positive, unique and total 100%; the contingent must not also be primary.
+/// An empty primary list with no contingent clears the designation.
+fn validate_beneficiaries(
+    beneficiaries: &Vec<Beneficiary>,
+    contingent: &Option<Address>,
+) -> Result<(), ContractError> {
+    if beneficiaries.is_empty() {
+        return match contingent {
+            Some(_) => Err(ContractError::InvalidBeneficiaries),
+            None => Ok(()),
+        };
+    }
+    if beneficiaries.len() > MAX_BENEFICIARIES {
+        return Err(ContractError::InvalidBeneficiaries);
+    }
+
+    let mut total: i128 = 0;
+    for (i, beneficiary) in beneficiaries.iter().enumerate() {
+        if beneficiary.share_bps == 0 {
+            return Err(ContractError::InvalidBeneficiaries);
+        }
+        if contingent.as_ref() == Some(&beneficiary.recipient) {
+            return Err(ContractError::InvalidBeneficiaries);
+        }
+        for other in beneficiaries.iter().skip(i + 1) {
+            if other.recipient == beneficiary.recipient {
+                return Err(ContractError::InvalidBeneficiaries);
+            }
+        }
+        total += i128::from(beneficiary.share_bps);
+    }
+    if total != BASIS_POINTS {
+        return Err(ContractError::InvalidBeneficiaries);
+    }
+    Ok(())
+}
+
+/// Deductible and sub-limits must sit within the coverage amount; co-insurance within 100%.
+fn validate_coverage_terms(policy: &Policy, terms: &CoverageTerms) -> Result<(), ContractError> {
+    if terms.deductible < 0 || terms.deductible >= policy.coverage_amount {
+        return Err(ContractError::InvalidCoverageTerms);
+    }
+    if i128::from(terms.coinsurance_bps) >= BASIS_POINTS {
+        return Err(ContractError::InvalidCoverageTerms);
+    }
+    for (i, sub_limit) in terms.sub_limits.iter().enumerate() {
+        if sub_limit.limit <= 0 || sub_limit.limit > policy.coverage_amount {
+            return Err(ContractError::InvalidCoverageTerms);
+        }
+        for other in terms.sub_limits.iter().skip(i + 1) {
+            if other.peril == sub_limit.peril {
+                return Err(ContractError::InvalidCoverageTerms);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn next_quote_id(env: &Env) -> u64 {
+    let current_id: u64 = env.storage().persistent().get(&DataKey::QuoteCounter).unwrap_or(0u64);
+    let next_id = current_id + 1;
+    env.storage().persistent().set(&DataKey::QuoteCounter, &next_id);
+    next_id
+}
+
+fn quote_digest(env: &Env, quote: &PolicyQuote) -> BytesN<32> {
+    let terms = (
+        env.current_contract_address(),
+        quote.id,
+        quote.holder.clone(),
+        quote.product_id,
+        quote.coverage_amount,
+        quote.premium_amount,
+        quote.duration_days,
+        quote.deductible,
+        quote.auto_renew,
+        quote.coverage_asset.clone(),
+        quote.premium_asset.clone(),
+        quote.expires_at,
+    );
+    env.crypto().sha256(&terms.to_xdr(env)).into()
+}
+
+fn policy_end_time(start_time: u64, duration_days: u32) -> Result<u64, ContractError> {
+    start_time
+        .checked_add(u64::from(duration_days).checked_mul(86400).ok_or(ContractError::Overflow2)?)
+        .ok_or(ContractError::Overflow2)
+}
+
+fn next_endorsement_id(env: &Env) -> u64 {
+    let current_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::EndorsementCounter)
+        .unwrap_or(0u64);
+    let next_id = current_id + 1;
+    env.storage().persistent().set(&DataKey::EndorsementCounter, &next_id);
+    next_id
+}
+
+/// Pulls the premium from the holder and splits it between the risk pool and the treasury.
+/// The treasury share is `TreasuryConfig::fee_percentage` (basis points) of the premium;
+/// the remainder is transferred to the pool and credited through `credit_premium`.
+/// Any failed transfer or cross-contract call aborts the whole issuance.
+/// With `via_allowance` the premium is drawn from an allowance the payer granted this
+/// contract, so automated renewals can charge the holder without their signature.
+/// The fee rate is recorded against `policy_id` so refunds come only out of the pool's share.
+fn collect_premium(
+    env: &Env,
+    policy_id: u64,
+    payer: &Address,
+    asset: &shared::types::Asset,
+    premium_amount: i128,
+    via_allowance: bool,
+) -> Result<(), ContractError> {
+    let config: Config = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Config)
+        .ok_or(ContractError::NotInitialized)?;
+
+    let fee_percentage: u32 = match &config.treasury {
+        Some(treasury) => env.invoke_contract(
+            treasury,
+            &Symbol::new(env, "get_fee_percentage"),
+            ().into_val(env),
+        ),
+        None => 0,
+    };
+    let fee_amount = premium_amount
+        .checked_mul(i128::from(fee_percentage))
+        .ok_or(ContractError::Overflow2)?
+        / BASIS_POINTS;
+    env.storage()
+        .persistent()
+        .set(&DataKey::PremiumFeeRate(policy_id), &fee_percentage);
+    let pool_amount = premium_amount.checked_sub(fee_amount).ok_or(ContractError::Overflow2)?;
+    let this = env.current_contract_address();
+    let pull = |to: &Address, amount: i128| {
+        if via_allowance {
+            token::transfer_from(env, asset, &this, payer, to, amount)
+        } else {
+            token::transfer(env, asset, payer, to, amount)
+        }
+    };
+
+    if pool_amount > 0 {
+        pull(&config.risk_pool, pool_amount)?;
+        env.invoke_contract::<()>(
+            &config.risk_pool,
+            &Symbol::new(env, "credit_premium"),
+            (this.clone(), asset.clone(), pool_amount).into_val(env),
+        );
+    }
+
+    if let Some(treasury) = &config.treasury {
+        if fee_amount > 0 {
+            pull(treasury, fee_amount)?;
+            env.invoke_contract::<()>(
+                treasury,
+                &Symbol::new(env, "deposit_premium_fee"),
+                (this, fee_amount).into_val(env),
+            );
+        }
+    }
+
+    env.events().publish(
+        (Symbol::new(env, "PremiumCollected"), payer.clone()),
+        (asset.clone(), premium_amount, pool_amount, fee_amount),
+    );
+
+    Ok(())
+}
+
+/// The part of a premium refund the risk pool holds: the treasury fee taken when the
+/// premium was collected stays with the treasury.
+fn pool_refund_share(env: &Env, policy_id: u64, refund: i128) -> Result<i128, ContractError> {
+    let fee_percentage: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PremiumFeeRate(policy_id))
+        .unwrap_or(0u32);
+    let fee = refund
+        .checked_mul(i128::from(fee_percentage))
+        .ok_or(ContractError::Overflow2)?
+        / BASIS_POINTS;
+    refund.checked_sub(fee).ok_or(ContractError::Overflow2)
+}
+
+/// Charges the renewal premium and extends `end_time` by `duration_days`.
+/// Callers are responsible for authorization, state and grace-period checks.
+fn extend_policy(
+    env: &Env,
+    policy_id: u64,
+    policy: &mut Policy,
+    duration_days: u32,
+    actor: &Address,
+    via_allowance: bool,
+) -> Result<i128, ContractError> {
+    let renewal_premium = calculate_renewal_premium(policy, duration_days)?;
+    collect_premium(
+        env,
+        policy_id,
+        &policy.holder,
+        &policy.premium_asset,
+        renewal_premium,
+        via_allowance,
+    )?;
+
+    // Extend from the current end_time to avoid gaps
+    let new_end_time = policy_end_time(policy.end_time, duration_days)?;
+
+    policy.end_time = new_end_time;
+    policy.premium_amount = policy
+        .premium_amount
+        .checked_add(renewal_premium)
+        .ok_or(ContractError::Overflow2)?;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Policy(policy_id), &*policy);
+    record_amendment(
+        env,
+        policy_id,
+        policy,
+        None,
+        renewal_premium,
+        env.ledger().timestamp(),
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "PolicyRenewed"), policy_id),
+        (actor.clone(), new_end_time, duration_days),
+    );
+    env.events().publish(
+        (Symbol::new(env, "PolicyRenewedFull"), policy_id),
+        (
+            actor.clone(),
+            new_end_time,
+            duration_days,
+            env.ledger().timestamp(),
+            renewal_premium,
+        ),
+    );
+
+    Ok(renewal_premium)
+}
+
+/// Whether the holder's allowance and balance cover an automated renewal charge.
+/// Checked up front so a keeper sweep never leaves a renewal half-collected.
+fn can_charge_renewal(env: &Env, policy: &Policy, amount: i128) -> bool {
+    let this = env.current_contract_address();
+    let allowance = token::allowance(env, &policy.premium_asset, &policy.holder, &this);
+    let balance = token::balance(env, &policy.premium_asset, &policy.holder);
+    matches!((allowance, balance), (Ok(a), Ok(b)) if a >= amount && b >= amount)
+}
+
+/// Moves an ACTIVE policy to LAPSED and opens a lapse window at the end of its grace period.
+fn lapse(env: &Env, policy_id: u64, policy: &Policy, actor: Address) -> Result<(), ContractError> {
+    PolicyStateMachine::transition(env, policy_id, PolicyState::LAPSED, actor)?;
+
+    let mut history = get_lapse_history(env, policy_id);
+    history.push_back(LapseWindow {
+        lapsed_at: policy.end_time.saturating_add(get_grace_period(env)),
+        reinstated_at: None,
+    });
+    env.storage()
+        .persistent()
+        .set(&DataKey::LapseHistory(policy_id), &history);
+
+    Ok(())
+}
+
+/// Collects the premium and persists a newly issued policy.
+/// The premium is pulled before the policy exists, so a failed payment aborts issuance.
+fn activate_policy(
+    env: &Env,
+    policy: Policy,
+    duration_days: u32,
+    issuer: &Address,
+) -> Result<u64, ContractError> {
+    let policy_id = next_policy_id(env);
+    collect_premium(
+        env,
+        policy_id,
+        &policy.holder,
+        &policy.premium_asset,
+        policy.premium_amount,
+        false,
+    )?;
+
+    env.storage().persistent().set(&DataKey::Policy(policy_id), &policy);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RenewalTerm(policy_id), &duration_days);
+    record_amendment(env, policy_id, &policy, None, 0, policy.start_time);
+
+    // Add policy ID to the active policy list for efficient querying
+    let mut active_list: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&ACTIVE_POLICY_LIST)
+        .unwrap_or_else(|| Vec::new(env));
+    active_list.push_back(policy_id);
+    env.storage()
+        .persistent()
+        .set(&ACTIVE_POLICY_LIST, &active_list);
+
+    env.events().publish(
+        (Symbol::new(env, "PolicyIssued"), policy_id),
+        (
+            policy.holder.clone(),
+            policy.coverage_amount,
+            policy.premium_amount,
+            duration_days,
+            issuer.clone(),
+            policy.start_time,
+        ),
+    );
+    env.events().publish(
+        (Symbol::new(env, "PolicyIssuedFull"), policy_id),
+        (
+            policy.holder,
+            policy.coverage_amount,
+            policy.premium_amount,
+            duration_days,
+            issuer.clone(),
+            policy.start_time,
+            policy.end_time,
+            policy.auto_renew,
+        ),
+    );
+
+    Ok(policy_id)
+}
+
 #[contractimpl]
 impl PolicyContract {
     pub fn initialize(env: Env, admin: Address, risk_pool: Address) -> Result<(), ContractError> {
@@ -482,7 +1432,11 @@ impl PolicyContract {
         // Initialize emergency pause system
         EmergencyPause::initialize(&env, &admin)?;
 
-        let config = Config { risk_pool };
+        let config = Config {
+            risk_pool,
+            treasury: None,
+            product_template: None,
+        };
         env.storage().persistent().set(&DataKey::Config, &config);
 
         env.storage().persistent().set(&DataKey::PolicyCounter, &0u64);
@@ -515,6 +1469,45 @@ impl PolicyContract {
         Ok(())
     }
 
+    /// Set the treasury that receives the protocol fee share of premiums (admin only)
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        validate_address(&env, &treasury)?;
+
+        let mut config = Self::get_config(env.clone())?;
+        config.treasury = Some(treasury.clone());
+        env.storage().persistent().set(&DataKey::Config, &config);
+
+        env.events().publish(
+            (Symbol::new(&env, "TreasuryUpdated"), ()),
+            (admin, treasury, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Register the token contract used to move funds for an asset (admin only)
+    pub fn set_asset_token(
+        env: Env,
+        admin: Address,
+        asset: shared::types::Asset,
+        token_address: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        validate_address(&env, &token_address)?;
+
+        token::set_asset_token(&env, &asset, &token_address);
+
+        env.events().publish(
+            (Symbol::new(&env, "AssetTokenSet"), ()),
+            (admin, asset, token_address),
+        );
+
+        Ok(())
+    }
+
     pub fn issue_policy(
         env: Env,
         manager: Address,
@@ -526,6 +1519,7 @@ impl PolicyContract {
         coverage_asset: Option<shared::types::Asset>,
         premium_asset: Option<shared::types::Asset>,
         allow_multi_asset_claims: Option<bool>,
+        product_id: Option<u64>,
     ) -> Result<u64, ContractError> {
         // Use performance monitoring for optimization tracking
         PerformanceMonitor::track_operation(&env, "issue_policy", || {
@@ -537,6 +1531,10 @@ impl PolicyContract {
                 premium_amount,
                 duration_days,
                 auto_renew,
+                coverage_asset.clone(),
+                premium_asset.clone(),
+                allow_multi_asset_claims,
+                product_id,
             )
         })
     }
@@ -550,11 +1548,18 @@ impl PolicyContract {
         premium_amount: i128,
         duration_days: u32,
         auto_renew: bool,
+        coverage_asset: Option<shared::types::Asset>,
+        premium_asset: Option<shared::types::Asset>,
+        allow_multi_asset_claims: Option<bool>,
+        product_id: Option<u64>,
     ) -> Result<u64, ContractError> {
         // Verify identity and require policy management permission
         manager.require_auth();
         require_policy_management(&env, &manager)?;
 
+        // The holder pays the premium, so they must authorize the issuance too
+        holder.require_auth();
+
         // Check emergency pause status
         EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "issue_policy")))?;
 
@@ -583,62 +1588,300 @@ impl PolicyContract {
         // Validate duration within bounds
         validate_duration(duration_days)?;
 
-        // Use optimized policy issuance for gas efficiency
-        let policy_id = OptimizedPolicyContract::issue_policy_optimized(
-            &env,
-            manager.clone(),
         // Use default assets if not specified (Native XLM)
         let cov_asset = coverage_asset.unwrap_or(shared::types::Asset::Native);
         let prem_asset = premium_asset.unwrap_or(shared::types::Asset::Native);
         let multi_asset = allow_multi_asset_claims.unwrap_or(false);
 
-        let policy_id = next_policy_id(&env);
         let current_time = env.ledger().timestamp();
-        let end_time = current_time
-            .checked_add(
-                u64::from(duration_days).checked_mul(86400).ok_or(ContractError::Overflow2)?,
-            )
-            .ok_or(ContractError::Overflow2)?;
+        let end_time = policy_end_time(current_time, duration_days)?;
 
         // Use the new Policy constructor which initializes state to Active
         let policy = Policy::new(
             holder.clone(),
             coverage_amount,
             premium_amount,
-            duration_days,
+            current_time,
+            end_time,
+            current_time,
             auto_renew,
-        )?;
             cov_asset,
             prem_asset,
             multi_asset,
+            product_id,
         );
 
-        env.storage().persistent().set(&DataKey::Policy(policy_id), &policy);
-
-        // Add policy ID to the active policy list for efficient querying
-        let mut active_list: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&ACTIVE_POLICY_LIST)
-            .unwrap_or_else(|| Vec::new(&env));
-        active_list.push_back(policy_id);
-        env.storage()
-            .persistent()
-            .set(&ACTIVE_POLICY_LIST, &active_list);
+        activate_policy(&env, policy, duration_days, &manager)
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "PolicyIssued"), policy_id),
-            (holder, coverage_amount, premium_amount, duration_days, manager, current_time),
+    /// Set the product template contract used to price holder quotes (admin only)
+    pub fn set_product_template(
+        env: Env,
+        admin: Address,
+        product_template: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        validate_address(&env, &product_template)?;
+
+        let mut config = Self::get_config(env.clone())?;
+        config.product_template = Some(product_template.clone());
+        env.storage().persistent().set(&DataKey::Config, &config);
+
+        env.events().publish(
+            (Symbol::new(&env, "ProductTemplateUpdated"), ()),
+            (admin, product_template, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Set the remaining coverage capacity a product may sell through quotes (admin only).
+    /// Products without a configured capacity are not capacity-limited.
+    pub fn set_product_capacity(
+        env: Env,
+        admin: Address,
+        product_id: u64,
+        capacity: i128,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if capacity < 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProductCapacity(product_id), &capacity);
+
+        env.events().publish(
+            (Symbol::new(&env, "ProductCapacitySet"), product_id),
+            (admin, capacity),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_product_capacity(env: Env, product_id: u64) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProductCapacity(product_id))
+    }
+
+    /// Quote a policy priced by the product template's pricing model.
+    /// The quote locks the price and the product capacity for `QUOTE_VALIDITY_SECS`.
+    /// Coverage and premium default to the native asset, as with `issue_policy`.
+    pub fn quote_policy(
+        env: Env,
+        holder: Address,
+        product_id: u64,
+        coverage_amount: i128,
+        duration_days: u32,
+        deductible: i128,
+        custom_values: Vec<shared::types::CustomParamValue>,
+        auto_renew: bool,
+        coverage_asset: Option<shared::types::Asset>,
+        premium_asset: Option<shared::types::Asset>,
+    ) -> Result<PolicyQuote, ContractError> {
+        holder.require_auth();
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "issue_policy")))?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        rate_limit::enforce(
+            &env,
+            Symbol::new(&env, POLICY_ISSUE_SCOPE),
+            &holder,
+            RateLimitConfig {
+                max_calls: DEFAULT_POLICY_ISSUE_RATE_LIMIT_MAX_CALLS,
+                window_secs: DEFAULT_POLICY_ISSUE_RATE_LIMIT_WINDOW_SECS,
+            },
+        )?;
+
+        validate_coverage_amount(coverage_amount)?;
+        validate_duration(duration_days)?;
+
+        let config = Self::get_config(env.clone())?;
+        let product_template = config
+            .product_template
+            .ok_or(ContractError::ProductTemplateNotConfigured)?;
+
+        let premium_amount: i128 = env.invoke_contract(
+            &product_template,
+            &Symbol::new(&env, "quote_premium"),
+            (product_id, coverage_amount, duration_days, deductible, custom_values).into_val(&env),
         );
+        validate_premium_amount(premium_amount)?;
+
+        // Lock capacity for the lifetime of the quote
+        let capacity = Self::get_product_capacity(env.clone(), product_id);
+        if let Some(capacity) = capacity {
+            if capacity < coverage_amount {
+                return Err(ContractError::InsufficientCapacity);
+            }
+            env.storage().persistent().set(
+                &DataKey::ProductCapacity(product_id),
+                &(capacity - coverage_amount),
+            );
+        }
+
+        let now = env.ledger().timestamp();
+        let mut quote = PolicyQuote {
+            id: next_quote_id(&env),
+            holder: holder.clone(),
+            product_id,
+            coverage_amount,
+            premium_amount,
+            duration_days,
+            deductible,
+            auto_renew,
+            coverage_asset: coverage_asset.unwrap_or(shared::types::Asset::Native),
+            premium_asset: premium_asset.unwrap_or(shared::types::Asset::Native),
+            issued_at: now,
+            expires_at: now + QUOTE_VALIDITY_SECS,
+            digest: BytesN::from_array(&env, &[0u8; 32]),
+            status: QuoteStatus::Open,
+            capacity_locked: capacity.is_some(),
+        };
+        quote.digest = quote_digest(&env, &quote);
+
+        env.storage().persistent().set(&DataKey::Quote(quote.id), &quote);
+
         env.events().publish(
-            (Symbol::new(&env, "PolicyIssuedFull"), policy_id),
-            (holder.clone(), coverage_amount, premium_amount, duration_days, manager.clone(), current_time, end_time, auto_renew),
+            (Symbol::new(&env, "PolicyQuoted"), quote.id),
+            (holder, product_id, coverage_amount, premium_amount, quote.expires_at),
+        );
+
+        Ok(quote)
+    }
+
+    /// Redeem an open quote: the holder it was issued to authorizes the quoted terms via
+    /// `quote_digest` and pays the locked premium, in the quoted asset, in the same call.
+    pub fn purchase_policy(
+        env: Env,
+        holder: Address,
+        quote_id: u64,
+        quote_digest: BytesN<32>,
+    ) -> Result<u64, ContractError> {
+        holder.require_auth();
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "issue_policy")))?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        rate_limit::enforce(
+            &env,
+            Symbol::new(&env, POLICY_ISSUE_SCOPE),
+            &holder,
+            RateLimitConfig {
+                max_calls: DEFAULT_POLICY_ISSUE_RATE_LIMIT_MAX_CALLS,
+                window_secs: DEFAULT_POLICY_ISSUE_RATE_LIMIT_WINDOW_SECS,
+            },
+        )?;
+
+        let mut quote = Self::get_quote(env.clone(), quote_id)?;
+        if quote.holder != holder {
+            return Err(ContractError::Unauthorized);
+        }
+        if quote.status != QuoteStatus::Open {
+            return Err(ContractError::QuoteNotOpen);
+        }
+        if quote.digest != quote_digest {
+            return Err(ContractError::QuoteMismatch);
+        }
+
+        let now = env.ledger().timestamp();
+        if now > quote.expires_at {
+            return Err(ContractError::QuoteExpired);
+        }
+
+        let policy = Policy::new(
+            holder.clone(),
+            quote.coverage_amount,
+            quote.premium_amount,
+            now,
+            policy_end_time(now, quote.duration_days)?,
+            now,
+            quote.auto_renew,
+            quote.coverage_asset.clone(),
+            quote.premium_asset.clone(),
+            false,
+            Some(quote.product_id),
         );
+        let policy_id = activate_policy(&env, policy, quote.duration_days, &holder)?;
+
+        if quote.deductible > 0 {
+            store_coverage_terms(
+                &env,
+                policy_id,
+                &CoverageTerms {
+                    deductible: quote.deductible,
+                    coinsurance_bps: 0,
+                    sub_limits: Vec::new(&env),
+                },
+                now,
+            );
+        }
 
+        quote.status = QuoteStatus::Purchased;
+        env.storage().persistent().set(&DataKey::Quote(quote_id), &quote);
+
+        env.events().publish(
+            (Symbol::new(&env, "QuotePurchased"), quote_id),
+            (holder, policy_id, quote.premium_amount, now),
+        );
 
         Ok(policy_id)
     }
 
+    /// Close an unredeemed quote after expiry and release its locked capacity.
+    /// Permissionless so capacity cannot be held hostage by stale quotes.
+    pub fn expire_quote(env: Env, quote_id: u64) -> Result<(), ContractError> {
+        let mut quote = Self::get_quote(env.clone(), quote_id)?;
+        if quote.status != QuoteStatus::Open {
+            return Err(ContractError::QuoteNotOpen);
+        }
+        if env.ledger().timestamp() <= quote.expires_at {
+            return Err(ContractError::InvalidState);
+        }
+
+        if quote.capacity_locked {
+            let capacity = Self::get_product_capacity(env.clone(), quote.product_id).unwrap_or(0);
+            let released = capacity
+                .checked_add(quote.coverage_amount)
+                .ok_or(ContractError::Overflow2)?;
+            env.storage()
+                .persistent()
+                .set(&DataKey::ProductCapacity(quote.product_id), &released);
+        }
+
+        quote.status = QuoteStatus::Expired;
+        env.storage().persistent().set(&DataKey::Quote(quote_id), &quote);
+
+        env.events().publish(
+            (Symbol::new(&env, "QuoteExpired"), quote_id),
+            (quote.holder, quote.product_id, quote.coverage_amount),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_quote(env: Env, quote_id: u64) -> Result<PolicyQuote, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Quote(quote_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Extends an ACTIVE policy and charges the renewal premium at the current term's rate.
+    /// The holder may renew until the grace period after `end_time` runs out; automation
+    /// renewing an auto-renew policy draws the premium from the holder's allowance.
     pub fn renew_policy(
         env: Env,
         actor: Address,
@@ -674,911 +1917,3357 @@ impl PolicyContract {
         // Validate duration
         validate_duration(duration_days)?;
 
-        // Calculate new end time
-        // Extend from the current end_time to avoid gaps
-        let new_end_time = policy
-            .end_time
-            .checked_add(
-                u64::from(duration_days)
-                    .checked_mul(86400)
-                    .ok_or(ContractError::Overflow2)?,
-            )
-            .ok_or(ContractError::Overflow2)?;
+        // Past the grace period the policy must lapse and be reinstated instead
+        let grace_end = policy.end_time.saturating_add(get_grace_period(&env));
+        if env.ledger().timestamp() > grace_end {
+            return Err(ContractError::GracePeriodElapsed);
+        }
 
-        policy.end_time = new_end_time;
+        extend_policy(&env, policy_id, &mut policy, duration_days, &actor, !is_holder)?;
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Policy(policy_id), &policy);
+        Ok(())
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "PolicyRenewed"), policy_id),
-            (actor, new_end_time, duration_days),
+    /// Moves an ACTIVE policy whose grace period passed without a paid renewal to LAPSED.
+    /// Permissionless so keepers can enforce lapses.
+    pub fn lapse_policy(env: Env, policy_id: u64) -> Result<(), ContractError> {
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        if !policy.is_active() {
+            return Err(ContractError::InvalidState);
+        }
 
-        );
-        env.events().publish((Symbol::new(&env, "PolicyRenewedFull"), policy_id), (actor, new_end_time, duration_days, env.ledger().timestamp()));
+        if env.ledger().timestamp() <= policy.end_time.saturating_add(get_grace_period(&env)) {
+            return Err(ContractError::GracePeriodNotElapsed);
+        }
 
-        Ok(())
+        lapse(&env, policy_id, &policy, env.current_contract_address())
     }
 
-    pub fn set_auto_renew(
+    /// Reinstates a LAPSED policy for a new term. The holder pays the premium for the new
+    /// term plus the reinstatement fee; the lapse window stays uncovered.
+    pub fn reinstate_policy(
         env: Env,
         holder: Address,
         policy_id: u64,
-        auto_renew: bool,
+        duration_days: u32,
     ) -> Result<(), ContractError> {
         holder.require_auth();
 
-        let mut policy = Self::get_policy(env.clone(), policy_id)?;
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "renew_policy")))?;
 
+        let policy = Self::get_policy(env.clone(), policy_id)?;
         if policy.holder != holder {
             return Err(ContractError::Unauthorized);
         }
+        if !policy.is_lapsed() {
+            return Err(ContractError::InvalidState);
+        }
 
-        policy.auto_renew = auto_renew;
+        validate_duration(duration_days)?;
+
+        let premium = calculate_renewal_premium(&policy, duration_days)?;
+        let fee_bps: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReinstatementFee)
+            .unwrap_or(0u32);
+        let fee = premium
+            .checked_mul(i128::from(fee_bps))
+            .ok_or(ContractError::Overflow2)?
+            / BASIS_POINTS;
+        let total = premium.checked_add(fee).ok_or(ContractError::Overflow2)?;
+        collect_premium(&env, policy_id, &holder, &policy.premium_asset, total, false)?;
+
+        PolicyStateMachine::transition(&env, policy_id, PolicyState::ACTIVE, holder.clone())?;
+
+        // Reinstatement starts a fresh term
+        let now = env.ledger().timestamp();
+        let mut policy = Self::get_policy(env.clone(), policy_id)?;
+        policy.start_time = now;
+        policy.end_time = policy_end_time(now, duration_days)?;
+        policy.premium_amount = premium;
+        env.storage().persistent().set(&DataKey::Policy(policy_id), &policy);
         env.storage()
             .persistent()
-            .set(&DataKey::Policy(policy_id), &policy);
+            .set(&DataKey::RenewalTerm(policy_id), &duration_days);
+        record_amendment(&env, policy_id, &policy, None, premium, now);
 
-        env.events().publish(
-            (Symbol::new(&env, "AutoRenewUpdated"), policy_id),
-            (holder, auto_renew),
+        let mut history = get_lapse_history(&env, policy_id);
+        if let Some(mut window) = history.pop_back() {
+            window.reinstated_at = Some(now);
+            history.push_back(window);
+            env.storage()
+                .persistent()
+                .set(&DataKey::LapseHistory(policy_id), &history);
+        }
 
+        env.events().publish(
+            (Symbol::new(&env, "PolicyReinstated"), policy_id),
+            (holder, premium, fee, policy.end_time, now),
         );
 
-        env.events().publish((Symbol::new(&env, "AutoRenewUpdatedFull"), policy_id), (holder.clone(), auto_renew, env.ledger().timestamp()));
-
-
         Ok(())
     }
 
-    pub fn get_policy(env: Env, policy_id: u64) -> Result<Policy, ContractError> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Policy(policy_id))
-            .ok_or(ContractError::NotFound)
-    }
-
-    pub fn get_policy_holder(env: Env, policy_id: u64) -> Result<Address, ContractError> {
-        let policy: Policy = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Policy(policy_id))
-            .ok_or(ContractError::NotFound)?;
-        Ok(policy.holder)
+    /// Returns the lapse windows recorded for a policy, oldest first.
+    pub fn get_lapse_history(env: Env, policy_id: u64) -> Vec<LapseWindow> {
+        get_lapse_history(&env, policy_id)
     }
 
-    pub fn get_coverage_amount(env: Env, policy_id: u64) -> Result<i128, ContractError> {
-        let policy: Policy = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Policy(policy_id))
-            .ok_or(ContractError::NotFound)?;
-        Ok(policy.coverage_amount)
-    }
+    /// Permissionless keeper sweep over `ACTIVE_POLICY_LIST` starting at `cursor`.
+    /// Overdue auto-renew policies are renewed for their renewal term, charging the
+    /// holder's allowance; ones that cannot be charged lapse once their grace period ends.
+    /// Other overdue policies expire once their grace period ends. The keeper earns the
+    /// configured bounty from the treasury for every policy acted on.
+    pub fn sweep_policies(
+        env: Env,
+        keeper: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<SweepResult, ContractError> {
+        keeper.require_auth();
 
-    pub fn get_premium_amount(env: Env, policy_id: u64) -> Result<i128, ContractError> {
-        let policy: Policy = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Policy(policy_id))
-            .ok_or(ContractError::NotFound)?;
-        Ok(policy.premium_amount)
-    }
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "sweep_policies")))?;
 
-    pub fn get_policy_state(env: Env, policy_id: u64) -> Result<PolicyState, ContractError> {
-        let policy: Policy = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Policy(policy_id))
-            .ok_or(ContractError::NotFound)?;
-        Ok(policy.state())
-    }
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        if limit == 0 || limit > MAX_SWEEP_BATCH {
+            return Err(ContractError::InvalidInput);
+        }
 
-    pub fn get_policy_dates(env: Env, policy_id: u64) -> Result<(u64, u64), ContractError> {
-        let policy: Policy = env
+        let active_list: Vec<u64> = env
             .storage()
             .persistent()
-            .get(&DataKey::Policy(policy_id))
-            .ok_or(ContractError::NotFound)?;
-        Ok((policy.start_time, policy.end_time))
-    }
+            .get(&ACTIVE_POLICY_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+        let total = active_list.len();
+        let end = core::cmp::min(cursor.saturating_add(limit), total);
+
+        let now = env.ledger().timestamp();
+        let grace_period = get_grace_period(&env);
+        let mut result = SweepResult {
+            processed: 0,
+            expired: 0,
+            renewed: 0,
+            lapsed: 0,
+            bounty_paid: 0,
+            next_cursor: None,
+        };
 
-    /// Cancels a policy. Only allowed when the policy is ACTIVE.
-    pub fn cancel_policy(env: Env, actor: Address, policy_id: u64) -> Result<(), ContractError> {
-        require_admin(&env, &actor)?;
+        for i in cursor..end {
+            let policy_id = active_list.get(i).unwrap();
+            result.processed += 1;
 
-        // Check emergency pause status for policy cancellation
-        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "cancel_policy")))?;
+            let mut policy = match Self::get_policy(env.clone(), policy_id) {
+                Ok(policy) if policy.is_active() => policy,
+                _ => continue,
+            };
+            if now <= policy.end_time {
+                continue;
+            }
+            let grace_elapsed = now > policy.end_time.saturating_add(grace_period);
+
+            if policy.auto_renew {
+                let duration_days: u32 = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::RenewalTerm(policy_id))
+                    .unwrap_or(MAX_POLICY_DURATION_DAYS);
+                let premium = calculate_renewal_premium(&policy, duration_days)?;
+
+                if !grace_elapsed && can_charge_renewal(&env, &policy, premium) {
+                    extend_policy(&env, policy_id, &mut policy, duration_days, &keeper, true)?;
+                    result.renewed += 1;
+                } else if grace_elapsed {
+                    lapse(&env, policy_id, &policy, keeper.clone())?;
+                    result.lapsed += 1;
+                }
+            } else if grace_elapsed {
+                PolicyStateMachine::transition(
+                    &env,
+                    policy_id,
+                    PolicyState::EXPIRED,
+                    keeper.clone(),
+                )?;
+                result.expired += 1;
+            }
+        }
 
-        // Use the state machine to transition to CANCELLED
-        PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, actor)?;
+        // Expired and lapsed policies were removed from the list, shifting later entries back
+        let removed = result.expired + result.lapsed;
+        if end < total {
+            result.next_cursor = Some(end - removed);
+        }
 
-    Ok(())
+        let actions = result.expired + result.renewed + result.lapsed;
+        let bounty: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::KeeperBounty)
+            .unwrap_or(0i128);
+        let config = Self::get_config(env.clone())?;
+        if let (Some(treasury), true) = (config.treasury, actions > 0 && bounty > 0) {
+            let amount = bounty
+                .checked_mul(i128::from(actions))
+                .ok_or(ContractError::Overflow2)?;
+            // A drained treasury must not block state upkeep, so bounty failures are tolerated
+            let paid = env.try_invoke_contract::<(), soroban_sdk::Error>(
+                &treasury,
+                &Symbol::new(&env, "pay_keeper_bounty"),
+                (env.current_contract_address(), keeper.clone(), amount).into_val(&env),
+            );
+            if matches!(paid, Ok(Ok(()))) {
+                result.bounty_paid = amount;
+            } else {
+                env.events().publish(
+                    (Symbol::new(&env, "KeeperBountyUnpaid"), keeper.clone()),
+                    (amount, now),
+                );
+            }
+        }
 
+        env.events().publish(
+            (Symbol::new(&env, "PoliciesSwept"), keeper),
+            (
+                cursor,
+                result.processed,
+                result.expired,
+                result.renewed,
+                result.lapsed,
+                result.bounty_paid,
+            ),
+        );
 
+        Ok(result)
     }
 
-    /// Expires a policy. Only allowed when the policy is ACTIVE.
-    pub fn expire_policy(env: Env, actor: Address, policy_id: u64) -> Result<(), ContractError> {
-        require_admin(&env, &actor)?;
+    /// Set the bounty paid to keepers per policy expired, renewed or lapsed (admin only)
+    pub fn set_keeper_bounty(env: Env, admin: Address, bounty: i128) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
-        // Check emergency pause status for policy expiration
-        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "expire_policy")))?;
+        if bounty < 0 {
+            return Err(ContractError::InvalidInput);
+        }
 
-        // Use the state machine to transition to EXPIRED
-        PolicyStateMachine::transition(&env, policy_id, PolicyState::EXPIRED, actor)?;
+        env.storage().persistent().set(&DataKey::KeeperBounty, &bounty);
+
+        env.events().publish(
+            (Symbol::new(&env, "KeeperBountyUpdated"), ()),
+            (admin, bounty, env.ledger().timestamp()),
+        );
 
         Ok(())
     }
 
-    pub fn get_admin(env: Env) -> Result<Address, ContractError> {
-        insurance_contracts::authorization::get_admin(&env).ok_or(ContractError::NotInitialized)
-    }
+    /// Set the grace period after `end_time` during which renewals are accepted (admin only)
+    pub fn set_grace_period(
+        env: Env,
+        admin: Address,
+        grace_period_secs: u64,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
-    pub fn get_config(env: Env) -> Result<Config, ContractError> {
         env.storage()
             .persistent()
-            .get(&DataKey::Config)
-            .ok_or(ContractError::NotInitialized)
-    }
+            .set(&DataKey::GracePeriod, &grace_period_secs);
 
-    pub fn get_risk_pool(env: Env) -> Result<Address, ContractError> {
-        let config: Config = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Config)
-            .ok_or(ContractError::NotInitialized)?;
-        Ok(config.risk_pool)
-    }
+        env.events().publish(
+            (Symbol::new(&env, "GracePeriodUpdated"), ()),
+            (admin, grace_period_secs, env.ledger().timestamp()),
+        );
 
-    pub fn get_policy_count(env: Env) -> u64 {
-        env.storage().persistent().get(&DataKey::PolicyCounter).unwrap_or(0u64)
+        Ok(())
     }
 
-    /// Returns a paginated list of active policies with structured view data.
-    /// This is a read-only function optimized for frontend/indexer consumption.
-    ///
-    /// # Arguments
-    /// * `start_index` - Zero-based index to start from in the active policy list
-    /// * `limit` - Maximum number of policies to return (capped at 50)
-    ///
-    /// # Returns
-    /// * `PaginatedPoliciesResult` containing the policies and total count
-    ///
-    /// # Example
-    /// To get the first page: `get_active_policies(0, 50)`
-    /// To get the second page: `get_active_policies(50, 50)`
-    pub fn get_active_policies(
+    /// Set the reinstatement fee charged on top of the premium, in basis points (admin only)
+    pub fn set_reinstatement_fee(
         env: Env,
-        start_index: u32,
-        limit: u32,
-    ) -> PaginatedPoliciesResult {
-        // Use performance monitoring
-        PerformanceMonitor::track_operation(&env, "get_active_policies", || {
-            Self::get_active_policies_impl(env.clone(), start_index, limit)
-        }).unwrap_or_else(|_| PaginatedPoliciesResult {
-            policies: Vec::new(&env),
-            total_count: 0,
-        })
-    }
+        admin: Address,
+        fee_bps: u32,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
-    /// Optimized implementation of active policies query
-    fn get_active_policies_impl(
-        env: Env,
-        start_index: u32,
-        limit: u32,
-    ) -> Result<PaginatedPoliciesResult, ContractError> {
-        // Use optimized batched query
-        let (policies_vec, total_count) = OptimizedPolicyContract::get_policies_batched(
-            &env,
-            start_index,
-            limit,
-            None, // No state filter
+        if i128::from(fee_bps) > BASIS_POINTS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReinstatementFee, &fee_bps);
+
+        env.events().publish(
+            (Symbol::new(&env, "ReinstatementFeeUpdated"), ()),
+            (admin, fee_bps, env.ledger().timestamp()),
         );
 
-        Ok(PaginatedPoliciesResult {
-            policies: policies_vec,
-            total_count,
-        })
+        Ok(())
     }
 
-    /// Get policies by specific state with optimization
-    pub fn get_policies_by_state(
+    pub fn set_auto_renew(
         env: Env,
-        state: PolicyState,
-        start_index: u32,
-        limit: u32,
-    ) -> PaginatedPoliciesResult {
-        PerformanceMonitor::track_operation(&env, "get_policies_by_state", || {
-            Self::get_policies_by_state_impl(env.clone(), state, start_index, limit)
-        }).unwrap_or_else(|_| PaginatedPoliciesResult {
-            policies: Vec::new(&env),
-            total_count: 0,
-        })
-    }
+        holder: Address,
+        policy_id: u64,
+        auto_renew: bool,
+    ) -> Result<(), ContractError> {
+        holder.require_auth();
+
+        let mut policy = Self::get_policy(env.clone(), policy_id)?;
+
+        if policy.holder != holder {
+            return Err(ContractError::Unauthorized);
+        }
+
+        policy.auto_renew = auto_renew;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Policy(policy_id), &policy);
+
+        env.events().publish(
+            (Symbol::new(&env, "AutoRenewUpdated"), policy_id),
+            (holder, auto_renew),
 
-    fn get_policies_by_state_impl(
-        env: Env,
-        state: PolicyState,
-        start_index: u32,
-        limit: u32,
-    ) -> Result<PaginatedPoliciesResult, ContractError> {
-        // Use optimized batched query with state filter
-        let (policies_vec, total_count) = OptimizedPolicyContract::get_policies_batched(
-            &env,
-            start_index,
-            limit,
-            Some(state),
         );
 
-        Ok(PaginatedPoliciesResult {
-            policies: policies_vec,
-            total_count,
-        })
+        env.events().publish((Symbol::new(&env, "AutoRenewUpdatedFull"), policy_id), (holder.clone(), auto_renew, env.ledger().timestamp()));
+
+
+        Ok(())
     }
 
-    /// Returns the count of currently active policies.
-    pub fn get_active_policy_count(env: Env) -> u32 {
-        let active_list: Vec<u64> = env
+    pub fn get_policy(env: Env, policy_id: u64) -> Result<Policy, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    pub fn get_policy_holder(env: Env, policy_id: u64) -> Result<Address, ContractError> {
+        let policy: Policy = env
             .storage()
             .persistent()
-            .get(&ACTIVE_POLICY_LIST)
-            .unwrap_or_else(|| Vec::new(&env));
-        active_list.len()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        Ok(policy.holder)
     }
 
-    pub fn is_paused(env: Env) -> bool {
-        is_paused(&env)
+    pub fn get_coverage_amount(env: Env, policy_id: u64) -> Result<i128, ContractError> {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        Ok(policy.coverage_amount)
     }
 
-    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
-        // Verify identity and require admin permission
-        admin.require_auth();
-        require_admin(&env, &admin)?;
+    /// Returns the product the policy was sold under, if any
+    pub fn get_policy_product(env: Env, policy_id: u64) -> Result<Option<u64>, ContractError> {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        Ok(policy.product_id)
+    }
 
-        set_paused(&env, true);
+    pub fn get_premium_amount(env: Env, policy_id: u64) -> Result<i128, ContractError> {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        Ok(policy.premium_amount)
+    }
 
-        env.events().publish((Symbol::new(&env, "paused"), ()), admin);
+    pub fn get_policy_state(env: Env, policy_id: u64) -> Result<PolicyState, ContractError> {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        Ok(policy.state())
+    }
 
-        Ok(())
+    pub fn get_policy_dates(env: Env, policy_id: u64) -> Result<(u64, u64), ContractError> {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        Ok((policy.start_time, policy.end_time))
     }
 
-    pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
-        // Verify identity and require admin permission
-        admin.require_auth();
-        require_admin(&env, &admin)?;
+    /// Cancels a policy (admin only). Only allowed when the policy is ACTIVE.
+    /// The pool's share of the unearned premium (less any short-rate penalty) is refunded
+    /// from the risk pool in the policy's premium asset; the treasury keeps its fee.
+    pub fn cancel_policy(env: Env, actor: Address, policy_id: u64) -> Result<(), ContractError> {
+        actor.require_auth();
+        require_admin(&env, &actor)?;
 
-        set_paused(&env, false);
+        // Check emergency pause status for policy cancellation
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "cancel_policy")))?;
 
-        env.events().publish((Symbol::new(&env, "unpaused"), ()), admin);
-        env.events().publish(
-            (Symbol::new(&env, "ContractUnpaused"), ()),
-            (admin, env.ledger().timestamp()),
-        );
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        let unearned = calculate_unearned_premium(
+            &policy,
+            env.ledger().timestamp(),
+            get_short_rate_penalty(&env, &policy),
+        )?;
+        let refund_amount = pool_refund_share(&env, policy_id, unearned)?;
+
+        // Use the state machine to transition to CANCELLED
+        PolicyStateMachine::transition_with_refund(
+            &env,
+            policy_id,
+            PolicyState::CANCELLED,
+            actor,
+            refund_amount,
+        )?;
+
+        if refund_amount > 0 {
+            let config = Self::get_config(env.clone())?;
+            env.invoke_contract::<()>(
+                &config.risk_pool,
+                &Symbol::new(&env, "refund_premium"),
+                (
+                    env.current_contract_address(),
+                    policy.holder.clone(),
+                    refund_amount,
+                    policy.premium_asset.clone(),
+                )
+                    .into_val(&env),
+            );
 
+            env.events().publish(
+                (Symbol::new(&env, "PremiumRefunded"), policy_id),
+                (policy.holder, refund_amount, policy.premium_asset, env.ledger().timestamp()),
+            );
+        }
 
         Ok(())
     }
 
-    /// Emergency pause the entire contract
-    pub fn emergency_pause(
-        env: Env,
-        admin: Address,
-        reason: Symbol,
-        max_duration_seconds: u64,
-    ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
-        
-        EmergencyPause::activate_emergency_pause(&env, &admin, reason, max_duration_seconds)
+    /// Returns the refund the holder would receive if the policy were cancelled now.
+    pub fn get_cancellation_refund(env: Env, policy_id: u64) -> Result<i128, ContractError> {
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        if !policy.is_active() {
+            return Err(ContractError::InvalidState);
+        }
+        let unearned = calculate_unearned_premium(
+            &policy,
+            env.ledger().timestamp(),
+            get_short_rate_penalty(&env, &policy),
+        )?;
+        pool_refund_share(&env, policy_id, unearned)
     }
 
-    /// Deactivate emergency pause
-    pub fn emergency_unpause(
+    /// Set the short-rate cancellation penalty for a product, in basis points (admin only)
+    pub fn set_short_rate_penalty(
         env: Env,
         admin: Address,
-        reason: Symbol,
+        product_id: u64,
+        penalty_bps: u32,
     ) -> Result<(), ContractError> {
         admin.require_auth();
         require_admin(&env, &admin)?;
-        
-        EmergencyPause::deactivate_emergency_pause(&env, &admin, reason)
-    }
 
-    /// Pause specific functions
-    pub fn pause_functions(
-        env: Env,
-        admin: Address,
-        functions: Vec<Symbol>,
-        reason: Symbol,
-    ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
-        
-        EmergencyPause::pause_functions(&env, &admin, &functions, reason)
-    }
+        if i128::from(penalty_bps) > BASIS_POINTS {
+            return Err(ContractError::InvalidInput);
+        }
 
-    /// Unpause specific functions
-    pub fn unpause_functions(
-        env: Env,
-        admin: Address,
-        functions: Vec<Symbol>,
-        reason: Symbol,
-    ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
-        
-        EmergencyPause::unpause_functions(&env, &admin, &functions, reason)
-    }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ShortRatePenalty(product_id), &penalty_bps);
 
-    /// Get emergency pause configuration
-    pub fn get_emergency_pause_config(env: Env) -> Result<insurance_contracts::emergency_pause::EmergencyPauseConfig, ContractError> {
-        EmergencyPause::get_pause_config(&env)
-    }
+        env.events().publish(
+            (Symbol::new(&env, "ShortRatePenaltySet"), product_id),
+            (admin, penalty_bps),
+        );
 
-    /// Get emergency pause history
-    pub fn get_emergency_pause_history(env: Env, limit: u32) -> Vec<insurance_contracts::emergency_pause::EmergencyPauseEvent> {
-        EmergencyPause::get_pause_history(&env, limit)
+        Ok(())
     }
 
-    /// Grant policy manager role to an address (admin only)
-    pub fn grant_manager_role(
+    /// Propose a mid-term endorsement to an ACTIVE policy (PolicyManager only).
+    /// `duration_days` is measured from the policy start, as at issuance.
+    /// The endorsement takes effect only once the holder accepts it.
+    pub fn propose_endorsement(
         env: Env,
-        admin: Address,
         manager: Address,
-    ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
+        policy_id: u64,
+        coverage_amount: Option<i128>,
+        coverage_asset: Option<shared::types::Asset>,
+        duration_days: Option<u32>,
+    ) -> Result<u64, ContractError> {
+        manager.require_auth();
+        require_policy_management(&env, &manager)?;
 
-        insurance_contracts::authorization::grant_role(
-            &env,
-            &admin,
-            &manager,
-            Role::PolicyManager,
-        )?;
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "endorse_policy")))?;
+
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        if !policy.is_active() {
+            return Err(ContractError::InvalidState);
+        }
+        if env.storage().persistent().has(&DataKey::PendingEndorsement(policy_id)) {
+            return Err(ContractError::EndorsementPending);
+        }
+        if coverage_amount.is_none() && coverage_asset.is_none() && duration_days.is_none() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let now = env.ledger().timestamp();
+        let new_coverage = coverage_amount.unwrap_or(policy.coverage_amount);
+        validate_coverage_amount(new_coverage)?;
+
+        let new_end_time = match duration_days {
+            Some(days) => {
+                validate_duration(days)?;
+                policy_end_time(policy.start_time, days)?
+            }
+            None => policy.end_time,
+        };
+        if new_end_time <= now {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let premium_adjustment =
+            calculate_endorsement_premium(&policy, new_coverage, new_end_time, now)?;
+
+        let endorsement_id = next_endorsement_id(&env);
+        let endorsement = Endorsement {
+            id: endorsement_id,
+            policy_id,
+            proposed_by: manager.clone(),
+            coverage_amount: new_coverage,
+            coverage_asset: coverage_asset.unwrap_or(policy.coverage_asset),
+            end_time: new_end_time,
+            premium_adjustment,
+            status: EndorsementStatus::Proposed,
+            proposed_at: now,
+            decided_at: None,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Endorsement(endorsement_id), &endorsement);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingEndorsement(policy_id), &endorsement_id);
 
-        env.events()
-            .publish((Symbol::new(&env, "role_granted"), manager.clone()), admin);
         env.events().publish(
-            (Symbol::new(&env, "PolicyManagerGranted"), ()),
-            (admin, manager.clone(), env.ledger().timestamp()),
+            (Symbol::new(&env, "EndorsementProposed"), policy_id),
+            (endorsement_id, manager, new_coverage, new_end_time, premium_adjustment, now),
+        );
+
+        Ok(endorsement_id)
+    }
+
+    /// Holder countersigns a pending endorsement. The pro-rata premium difference is
+    /// recalculated at acceptance: additional premium is collected from the holder and
+    /// returned premium is refunded from the risk pool.
+    pub fn accept_endorsement(
+        env: Env,
+        holder: Address,
+        endorsement_id: u64,
+    ) -> Result<u32, ContractError> {
+        holder.require_auth();
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "endorse_policy")))?;
+
+        let mut endorsement = Self::get_endorsement(env.clone(), endorsement_id)?;
+        if endorsement.status != EndorsementStatus::Proposed {
+            return Err(ContractError::EndorsementNotPending);
+        }
+
+        let policy_id = endorsement.policy_id;
+        let mut policy = Self::get_policy(env.clone(), policy_id)?;
+        if policy.holder != holder {
+            return Err(ContractError::Unauthorized);
+        }
+        if !policy.is_active() {
+            return Err(ContractError::InvalidState);
+        }
+
+        let now = env.ledger().timestamp();
+        if endorsement.end_time <= now {
+            return Err(ContractError::InvalidInput);
+        }
 
+        let premium_adjustment = calculate_endorsement_premium(
+            &policy,
+            endorsement.coverage_amount,
+            endorsement.end_time,
+            now,
+        )?;
+
+        if premium_adjustment > 0 {
+            collect_premium(
+                &env,
+                policy_id,
+                &holder,
+                &policy.premium_asset,
+                premium_adjustment,
+                false,
+            )?;
+        } else if premium_adjustment < 0 {
+            let config = Self::get_config(env.clone())?;
+            let refund = pool_refund_share(&env, policy_id, -premium_adjustment)?;
+            env.invoke_contract::<()>(
+                &config.risk_pool,
+                &Symbol::new(&env, "refund_premium"),
+                (
+                    env.current_contract_address(),
+                    holder.clone(),
+                    refund,
+                    policy.premium_asset.clone(),
+                )
+                    .into_val(&env),
             );
-        env.events().publish((Symbol::new(&env, "PolicyManagerGrantedFull"), ()), (admin.clone(), manager.clone(), env.ledger().timestamp()));
+        }
+
+        policy.coverage_amount = endorsement.coverage_amount;
+        policy.coverage_asset = endorsement.coverage_asset.clone();
+        policy.end_time = endorsement.end_time;
+        policy.premium_amount = policy
+            .premium_amount
+            .checked_add(premium_adjustment)
+            .ok_or(ContractError::Overflow2)?;
+        env.storage().persistent().set(&DataKey::Policy(policy_id), &policy);
+
+        let version = record_amendment(
+            &env,
+            policy_id,
+            &policy,
+            Some(endorsement_id),
+            premium_adjustment,
+            now,
         );
 
+        endorsement.premium_adjustment = premium_adjustment;
+        endorsement.status = EndorsementStatus::Accepted;
+        endorsement.decided_at = Some(now);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Endorsement(endorsement_id), &endorsement);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingEndorsement(policy_id));
 
+        env.events().publish(
+            (Symbol::new(&env, "EndorsementAccepted"), policy_id),
+            (endorsement_id, holder, version, premium_adjustment, now),
+        );
 
-        Ok(())
+        Ok(version)
     }
 
-    /// Revoke policy manager role from an address (admin only)
-    pub fn revoke_manager_role(
+    /// Decline a pending endorsement. Callable by the holder or a PolicyManager.
+    pub fn decline_endorsement(
         env: Env,
-        admin: Address,
-        manager: Address,
+        actor: Address,
+        endorsement_id: u64,
     ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
+        actor.require_auth();
 
-        insurance_contracts::authorization::revoke_role(&env, &admin, &manager)?;
+        let mut endorsement = Self::get_endorsement(env.clone(), endorsement_id)?;
+        if endorsement.status != EndorsementStatus::Proposed {
+            return Err(ContractError::EndorsementNotPending);
+        }
+
+        let policy = Self::get_policy(env.clone(), endorsement.policy_id)?;
+        if actor != policy.holder {
+            require_policy_management(&env, &actor)?;
+        }
+
+        let now = env.ledger().timestamp();
+        endorsement.status = EndorsementStatus::Declined;
+        endorsement.decided_at = Some(now);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Endorsement(endorsement_id), &endorsement);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingEndorsement(endorsement.policy_id));
 
-        env.events()
-            .publish((Symbol::new(&env, "role_revoked"), manager.clone()), admin);
         env.events().publish(
-            (Symbol::new(&env, "PolicyManagerRevoked"), ()),
-            (admin, manager.clone(), env.ledger().timestamp()),
+            (Symbol::new(&env, "EndorsementDeclined"), endorsement.policy_id),
+            (endorsement_id, actor, now),
         );
 
-
         Ok(())
     }
 
-    /// Get the role of an address
-    pub fn get_user_role(env: Env, address: Address) -> Role {
-        get_role(&env, &address)
+    pub fn get_endorsement(env: Env, endorsement_id: u64) -> Result<Endorsement, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Endorsement(endorsement_id))
+            .ok_or(ContractError::NotFound)
     }
 
-    /// Grant auditor role to an address (admin only)
-    pub fn grant_auditor_role(
+    /// Returns all recorded term versions for a policy, oldest first.
+    pub fn get_policy_amendments(
         env: Env,
-        admin: Address,
-        auditor: Address,
-    ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
-
-        insurance_contracts::authorization::grant_role(
-            &env,
-            &admin,
-            &auditor,
-            Role::Auditor,
-        )?;
+        policy_id: u64,
+    ) -> Result<Vec<PolicyAmendment>, ContractError> {
+        Self::get_policy(env.clone(), policy_id)?;
 
-        env.events()
-            .publish((Symbol::new(&env, "auditor_role_granted"), auditor.clone()), admin);
-        env.events().publish(
-            (Symbol::new(&env, "AuditorGranted"), ()),
-            (admin, auditor.clone(), env.ledger().timestamp()),
+        let mut amendments = Vec::new(&env);
+        for version in 0..get_amendment_count(&env, policy_id) {
+            if let Some(amendment) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PolicyAmendment(policy_id, version))
+            {
+                amendments.push_back(amendment);
+            }
+        }
+        Ok(amendments)
+    }
 
-            );
-        env.events().publish((Symbol::new(&env, "AuditorGrantedFull"), ()), (admin.clone(), auditor.clone(), env.ledger().timestamp()));
-        );
+    /// Returns the terms in force at `timestamp`, used to judge a claim by its loss date.
+    /// Cover continues through the grace period; loss dates inside a lapse window are rejected.
+    pub fn get_policy_terms_at(
+        env: Env,
+        policy_id: u64,
+        timestamp: u64,
+    ) -> Result<PolicyAmendment, ContractError> {
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        let covered_until = policy.end_time.saturating_add(get_grace_period(&env));
+        if timestamp < policy.created_at || timestamp > covered_until {
+            return Err(ContractError::OutsideCoveragePeriod);
+        }
 
+        for window in get_lapse_history(&env, policy_id).iter() {
+            let reinstated = window.reinstated_at.map_or(false, |at| timestamp >= at);
+            if timestamp >= window.lapsed_at && !reinstated {
+                return Err(ContractError::PolicyLapsed);
+            }
+        }
 
+        let mut version = get_amendment_count(&env, policy_id);
+        while version > 0 {
+            version -= 1;
+            let amendment: PolicyAmendment = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PolicyAmendment(policy_id, version))
+                .ok_or(ContractError::NotFound)?;
+            if amendment.effective_from <= timestamp {
+                return Ok(amendment);
+            }
+        }
 
-        Ok(())
+        // Policies issued before amendment tracking: current terms are the original terms
+        Ok(PolicyAmendment {
+            policy_id,
+            version: 0,
+            endorsement_id: None,
+            coverage_amount: policy.coverage_amount,
+            coverage_asset: policy.coverage_asset,
+            premium_amount: policy.premium_amount,
+            end_time: policy.end_time,
+            premium_adjustment: 0,
+            effective_from: policy.start_time,
+        })
     }
 
-    /// Revoke auditor role from an address (admin only)
-    pub fn revoke_auditor_role(
+    /// Set the deductible, co-insurance share and per-peril sub-limits of an ACTIVE policy
+    /// (PolicyManager only). The claims contract applies these when approving a claim.
+    pub fn set_coverage_terms(
         env: Env,
-        admin: Address,
-        auditor: Address,
+        manager: Address,
+        policy_id: u64,
+        terms: CoverageTerms,
     ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
+        manager.require_auth();
+        require_policy_management(&env, &manager)?;
 
-        insurance_contracts::authorization::revoke_role(&env, &admin, &auditor)?;
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "set_coverage_terms")))?;
+
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        if !policy.is_active() {
+            return Err(ContractError::InvalidPolicyState);
+        }
+        validate_coverage_terms(&policy, &terms)?;
+
+        // The new terms apply to losses from now on; earlier losses keep the terms then in force
+        store_coverage_terms(&env, policy_id, &terms, env.ledger().timestamp());
 
-        env.events()
-            .publish((Symbol::new(&env, "auditor_role_revoked"), auditor.clone()), admin);
         env.events().publish(
-            (Symbol::new(&env, "AuditorRevoked"), ()),
-            (admin, auditor.clone(), env.ledger().timestamp()),
+            (Symbol::new(&env, "CoverageTermsSet"), policy_id),
+            (
+                manager,
+                terms.deductible,
+                terms.coinsurance_bps,
+                terms.sub_limits.len(),
+                env.ledger().timestamp(),
+            ),
         );
 
-
         Ok(())
     }
 
-    /// Allow role delegation by eligible users
-    pub fn delegate_role(
-        env: Env,
-        delegator: Address,
-        delegatee: Address,
-        role: Role,
-    ) -> Result<(), ContractError> {
-        delegator.require_auth();
+    /// Returns the loss-sharing terms of a policy (zero deductible and co-insurance if unset).
+    pub fn get_coverage_terms(env: Env, policy_id: u64) -> Result<CoverageTerms, ContractError> {
+        Self::get_policy(env.clone(), policy_id)?;
+        Ok(get_coverage_terms(&env, policy_id))
+    }
 
-        insurance_contracts::authorization::delegate_role(&env, &delegator, &delegatee, role)?;
+    /// Returns the loss-sharing terms in force at `timestamp`, used to assess a claim by its
+    /// loss date. Terms set before history tracking are treated as the terms throughout.
+    pub fn get_coverage_terms_at(
+        env: Env,
+        policy_id: u64,
+        timestamp: u64,
+    ) -> Result<CoverageTerms, ContractError> {
+        Self::get_policy(env.clone(), policy_id)?;
+        let history: Vec<(u64, CoverageTerms)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CoverageTermsHistory(policy_id))
+            .unwrap_or(Vec::new(&env));
+        if history.is_empty() {
+            return Ok(get_coverage_terms(&env, policy_id));
+        }
 
-        env.events()
-            .publish((Symbol::new(&env, "role_delegated"), delegatee.clone(), role.clone()), delegator);
-        env.events().publish((Symbol::new(&env, "RoleDelegated"), ()), (delegator, delegatee.clone(), role.clone(), env.ledger().timestamp()));
+        let mut index = history.len();
+        while index > 0 {
+            index -= 1;
+            let (effective_from, terms) = history.get(index).unwrap();
+            if effective_from <= timestamp {
+                return Ok(terms);
+            }
+        }
 
-        Ok(())
+        // The loss predates the first terms: the gross loss was payable then
+        Ok(CoverageTerms {
+            deductible: 0,
+            coinsurance_bps: 0,
+            sub_limits: Vec::new(&env),
+        })
     }
 
-    /// Revoke a delegated role (admin only)
-    pub fn revoke_delegated_role(
+    /// Register the claims contract allowed to draw down policy limits (admin only)
+    pub fn register_claims_contract(
         env: Env,
         admin: Address,
-        target: Address,
+        claims_contract: Address,
     ) -> Result<(), ContractError> {
         admin.require_auth();
         require_admin(&env, &admin)?;
+        validate_address(&env, &claims_contract)?;
 
-        insurance_contracts::authorization::revoke_delegated_role(&env, &admin, &target)?;
-
-        env.events()
-            .publish((Symbol::new(&env, "delegated_role_revoked"), target.clone()), admin);
+        register_trusted_contract(&env, &admin, &claims_contract)?;
 
+        env.events().publish(
+            (Symbol::new(&env, "ClaimsContractRegistered"), ()),
+            (admin, claims_contract, env.ledger().timestamp()),
+        );
 
         Ok(())
     }
 
-    // ========================================================================
-    // Disaster Recovery & Backup
-    // ========================================================================
-
-    /// Updates the risk pool address in case of emergency or migration.
-    /// This ensures the policy contract points to a valid risk pool.
-    pub fn update_risk_pool(
+    /// Set the aggregate and per-occurrence claim limits of a policy (PolicyManager only).
+    /// The aggregate cannot be set below what has already been drawn.
+    pub fn set_claim_limits(
         env: Env,
-        admin: Address,
-        new_risk_pool: Address,
-    ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
-        validate_address(&env, &new_risk_pool)?;
+        manager: Address,
+        policy_id: u64,
+        aggregate_limit: i128,
+        per_occurrence_limit: i128,
+    ) -> Result<(), ContractError> {
+        manager.require_auth();
+        require_policy_management(&env, &manager)?;
 
-        let mut config = Self::get_config(env.clone())?;
-        let old_risk_pool = config.risk_pool;
-        
-        if old_risk_pool == new_risk_pool {
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        let limits = get_claim_limits(&env, policy_id, &policy);
+        if aggregate_limit <= 0
+            || aggregate_limit < limits.drawn_down
+            || per_occurrence_limit <= 0
+            || per_occurrence_limit > aggregate_limit
+        {
             return Err(ContractError::InvalidInput);
         }
 
-        config.risk_pool = new_risk_pool.clone();
-        env.storage().persistent().set(&DataKey::Config, &config);
-
-        // Ensure the new risk pool is trusted
-        register_trusted_contract(&env, &admin, &new_risk_pool)?;
+        env.storage().persistent().set(
+            &DataKey::ClaimLimits(policy_id),
+            &(aggregate_limit, per_occurrence_limit),
+        );
 
         env.events().publish(
-            (Symbol::new(&env, "RiskPoolUpdated"), ()),
-            (admin, old_risk_pool, new_risk_pool, env.ledger().timestamp()),
+            (Symbol::new(&env, "ClaimLimitsSet"), policy_id),
+            (manager, aggregate_limit, per_occurrence_limit, env.ledger().timestamp()),
         );
 
         Ok(())
     }
 
-    /// Synchronizes the active policy list with the actual state of a policy.
-    /// This is a recovery function to fix data integrity issues if the list gets out of sync.
-    pub fn sync_policy_state(
+    /// Draw a claim payment down from the policy's remaining aggregate coverage.
+    /// Only callable by a trusted claims contract. The amount is capped by the per-occurrence
+    /// limit and what remains of the aggregate; the amount actually drawn is returned.
+    pub fn draw_down_coverage(
         env: Env,
-        admin: Address,
+        caller_contract: Address,
         policy_id: u64,
-    ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
+        claim_id: u64,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
 
-        // Verify policy exists
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "draw_down_coverage")))?;
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        draw_down(&env, policy_id, claim_id, amount)
+    }
+
+    /// Re-size the drawdown recorded for a claim, e.g. when a dispute or partial approval
+    /// changes what the claim pays. Member claims pass their certificate so its cover is
+    /// adjusted too. Only callable by a trusted claims contract; returns the amount now drawn.
+    pub fn adjust_claim_drawdown(
+        env: Env,
+        caller_contract: Address,
+        policy_id: u64,
+        claim_id: u64,
+        certificate_id: Option<u64>,
+        new_amount: i128,
+    ) -> Result<i128, ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "draw_down_coverage")))?;
+
+        if new_amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let certificate_id = match certificate_id {
+            Some(certificate_id) => certificate_id,
+            None => return adjust_draw_down(&env, policy_id, claim_id, new_amount),
+        };
+
+        let certificate = Self::get_certificate(env.clone(), certificate_id)?;
+        if certificate.master_policy_id != policy_id {
+            return Err(ContractError::InvalidInput);
+        }
+        if certificate.status != CertificateStatus::Active {
+            return Err(ContractError::CertificateNotActive);
+        }
+        let previous: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClaimDrawdown(policy_id, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        let certificate_drawn: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CertificateDrawn(certificate_id))
+            .unwrap_or(0);
+        let certificate_headroom = certificate
+            .coverage_amount
+            .saturating_sub(certificate_drawn)
+            .max(0)
+            .saturating_add(previous);
+
+        let drawn = adjust_draw_down(
+            &env,
+            policy_id,
+            claim_id,
+            new_amount.min(certificate_headroom),
+        )?;
+        env.storage().persistent().set(
+            &DataKey::CertificateDrawn(certificate_id),
+            &(certificate_drawn + drawn - previous),
+        );
+
+        Ok(drawn)
+    }
+
+    /// Returns the claim limits of a policy with the amount drawn and remaining.
+    pub fn get_claim_limits(env: Env, policy_id: u64) -> Result<ClaimLimits, ContractError> {
         let policy = Self::get_policy(env.clone(), policy_id)?;
-        let is_active = policy.is_active();
+        Ok(get_claim_limits(&env, policy_id, &policy))
+    }
 
-        let mut active_list: Vec<u64> = env
+    /// Returns the aggregate coverage still available for new claims.
+    pub fn get_remaining_coverage(env: Env, policy_id: u64) -> Result<i128, ContractError> {
+        Ok(Self::get_claim_limits(env, policy_id)?.remaining)
+    }
+
+    /// Designate the beneficiaries who receive claim payouts (holder only).
+    /// Each designation is appended to the policy's beneficiary history; returns its version.
+    pub fn set_beneficiaries(
+        env: Env,
+        holder: Address,
+        policy_id: u64,
+        beneficiaries: Vec<Beneficiary>,
+        contingent: Option<Address>,
+    ) -> Result<u32, ContractError> {
+        holder.require_auth();
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "set_beneficiaries")))?;
+
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        if policy.holder != holder {
+            return Err(ContractError::Unauthorized);
+        }
+        if policy.is_cancelled() || policy.is_expired() {
+            return Err(ContractError::InvalidPolicyState);
+        }
+        validate_beneficiaries(&beneficiaries, &contingent)?;
+
+        let designation = BeneficiaryDesignation {
+            beneficiaries,
+            contingent,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Beneficiaries(policy_id), &designation);
+
+        let version: u32 = env
             .storage()
             .persistent()
-            .get(&ACTIVE_POLICY_LIST)
-            .unwrap_or_else(|| Vec::new(&env));
+            .get(&DataKey::BeneficiaryChangeCount(policy_id))
+            .unwrap_or(0);
+        let now = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &DataKey::BeneficiaryChange(policy_id, version),
+            &BeneficiaryChange {
+                policy_id,
+                version,
+                changed_by: holder.clone(),
+                designation: designation.clone(),
+                changed_at: now,
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::BeneficiaryChangeCount(policy_id), &(version + 1));
 
-        let mut found_index = None;
-        for i in 0..active_list.len() {
-            if active_list.get(i).unwrap() == policy_id {
-                found_index = Some(i);
-                break;
+        env.events().publish(
+            (Symbol::new(&env, "BeneficiariesChanged"), policy_id),
+            (
+                holder,
+                version,
+                designation.beneficiaries.len(),
+                designation.contingent,
+                now,
+            ),
+        );
+
+        Ok(version)
+    }
+
+    /// Returns the current beneficiary designation (empty if payouts go to the claimant).
+    pub fn get_beneficiaries(
+        env: Env,
+        policy_id: u64,
+    ) -> Result<BeneficiaryDesignation, ContractError> {
+        Self::get_policy(env.clone(), policy_id)?;
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::Beneficiaries(policy_id))
+            .unwrap_or(BeneficiaryDesignation {
+                beneficiaries: Vec::new(&env),
+                contingent: None,
+            }))
+    }
+
+    /// Returns every beneficiary designation made on a policy, oldest first.
+    pub fn get_beneficiary_history(
+        env: Env,
+        policy_id: u64,
+    ) -> Result<Vec<BeneficiaryChange>, ContractError> {
+        Self::get_policy(env.clone(), policy_id)?;
+
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryChangeCount(policy_id))
+            .unwrap_or(0);
+        let mut history = Vec::new(&env);
+        for version in 0..count {
+            if let Some(change) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::BeneficiaryChange(policy_id, version))
+            {
+                history.push_back(change);
             }
         }
+        Ok(history)
+    }
 
-        if is_active && found_index.is_none() {
-            // Policy is active but missing from list -> Add it
-            active_list.push_back(policy_id);
-            env.storage().persistent().set(&ACTIVE_POLICY_LIST, &active_list);
-            
-            env.events().publish(
-                (Symbol::new(&env, "PolicyStateSynced"), policy_id),
-                (Symbol::new(&env, "AddedToActiveList"), env.ledger().timestamp()),
+    /// Turn an ACTIVE policy into a group master policy that members can be enrolled under
+    /// (PolicyManager only). The policy holder is the group sponsor and pays member premiums.
+    pub fn create_master_policy(
+        env: Env,
+        manager: Address,
+        policy_id: u64,
+    ) -> Result<(), ContractError> {
+        manager.require_auth();
+        require_policy_management(&env, &manager)?;
+
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        if !policy.is_active() {
+            return Err(ContractError::InvalidPolicyState);
+        }
+        if env.storage().persistent().has(&DataKey::GroupPolicy(policy_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        env.storage().persistent().set(
+            &DataKey::GroupPolicy(policy_id),
+            &GroupPolicySummary {
+                master_policy_id: policy_id,
+                member_count: 0,
+                active_members: 0,
+                certificate_coverage: 0,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "MasterPolicyCreated"), policy_id),
+            (manager, policy.holder, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Enroll a member under a master policy (sponsor only).
+    /// The sponsor pays the pro-rata premium for the member's coverage for the rest of the term.
+    pub fn enroll_member(
+        env: Env,
+        sponsor: Address,
+        master_policy_id: u64,
+        member: Address,
+        coverage_amount: i128,
+    ) -> Result<u64, ContractError> {
+        sponsor.require_auth();
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "enroll_member")))?;
+
+        let master = Self::get_policy(env.clone(), master_policy_id)?;
+        let mut group = get_group_policy(&env, master_policy_id)?;
+        if master.holder != sponsor {
+            return Err(ContractError::Unauthorized);
+        }
+        if !master.is_active() {
+            return Err(ContractError::InvalidPolicyState);
+        }
+        if coverage_amount <= 0 || coverage_amount > master.coverage_amount {
+            return Err(ContractError::InvalidAmount);
+        }
+        let member_key = DataKey::MemberCertificate(master_policy_id, member.clone());
+        if env.storage().persistent().has(&member_key) {
+            return Err(ContractError::MemberAlreadyEnrolled);
+        }
+
+        let now = env.ledger().timestamp();
+        let premium_amount = calculate_certificate_premium(&master, coverage_amount, now)?;
+        if premium_amount > 0 {
+            collect_premium(
+                &env,
+                master_policy_id,
+                &sponsor,
+                &master.premium_asset,
+                premium_amount,
+                false,
+            )?;
+        }
+
+        let certificate_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CertificateCounter)
+            .unwrap_or(0u64)
+            + 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::CertificateCounter, &certificate_id);
+
+        let certificate = MemberCertificate {
+            id: certificate_id,
+            master_policy_id,
+            member: member.clone(),
+            coverage_amount,
+            premium_amount,
+            enrolled_at: now,
+            removed_at: None,
+            status: CertificateStatus::Active,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Certificate(certificate_id), &certificate);
+        env.storage().persistent().set(&member_key, &certificate_id);
+        env.storage().persistent().set(
+            &DataKey::GroupMember(master_policy_id, group.member_count),
+            &certificate_id,
+        );
+
+        group.member_count += 1;
+        group.active_members += 1;
+        group.certificate_coverage = group
+            .certificate_coverage
+            .checked_add(coverage_amount)
+            .ok_or(ContractError::Overflow2)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::GroupPolicy(master_policy_id), &group);
+
+        env.events().publish(
+            (Symbol::new(&env, "MemberEnrolled"), master_policy_id),
+            (certificate_id, member, coverage_amount, premium_amount, now),
+        );
+
+        Ok(certificate_id)
+    }
+
+    /// Remove a member from a master policy mid-term (sponsor only).
+    /// The unearned share of the certificate premium is refunded to the sponsor.
+    pub fn remove_member(
+        env: Env,
+        sponsor: Address,
+        certificate_id: u64,
+    ) -> Result<i128, ContractError> {
+        sponsor.require_auth();
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "remove_member")))?;
+
+        let mut certificate = Self::get_certificate(env.clone(), certificate_id)?;
+        let master = Self::get_policy(env.clone(), certificate.master_policy_id)?;
+        let mut group = get_group_policy(&env, certificate.master_policy_id)?;
+        if master.holder != sponsor {
+            return Err(ContractError::Unauthorized);
+        }
+        if certificate.status != CertificateStatus::Active {
+            return Err(ContractError::CertificateNotActive);
+        }
+
+        let now = env.ledger().timestamp();
+        let refund_amount = pool_refund_share(
+            &env,
+            certificate.master_policy_id,
+            calculate_certificate_refund(&certificate, master.end_time, now)?,
+        )?;
+
+        certificate.status = CertificateStatus::Removed;
+        certificate.removed_at = Some(now);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Certificate(certificate_id), &certificate);
+        env.storage().persistent().remove(&DataKey::MemberCertificate(
+            certificate.master_policy_id,
+            certificate.member.clone(),
+        ));
+
+        group.active_members -= 1;
+        group.certificate_coverage = group
+            .certificate_coverage
+            .checked_sub(certificate.coverage_amount)
+            .ok_or(ContractError::Overflow2)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::GroupPolicy(certificate.master_policy_id), &group);
+
+        if refund_amount > 0 {
+            let config = Self::get_config(env.clone())?;
+            env.invoke_contract::<()>(
+                &config.risk_pool,
+                &Symbol::new(&env, "refund_premium"),
+                (
+                    env.current_contract_address(),
+                    sponsor.clone(),
+                    refund_amount,
+                    master.premium_asset.clone(),
+                )
+                    .into_val(&env),
             );
-        } else if !is_active && found_index.is_some() {
-            // Policy is not active but present in list -> Remove it
-            let index = found_index.unwrap();
-            active_list.remove(index);
-            env.storage().persistent().set(&ACTIVE_POLICY_LIST, &active_list);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "MemberRemoved"), certificate.master_policy_id),
+            (certificate_id, certificate.member, refund_amount, now),
+        );
+
+        Ok(refund_amount)
+    }
+
+    /// Draw a member's claim down from their certificate and the master policy's aggregate.
+    /// Only callable by a trusted claims contract; returns the amount actually drawn.
+    pub fn draw_down_certificate(
+        env: Env,
+        caller_contract: Address,
+        certificate_id: u64,
+        claim_id: u64,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "draw_down_coverage")))?;
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let certificate = Self::get_certificate(env.clone(), certificate_id)?;
+        if certificate.status != CertificateStatus::Active {
+            return Err(ContractError::CertificateNotActive);
+        }
+        let certificate_drawn: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CertificateDrawn(certificate_id))
+            .unwrap_or(0);
+        let certificate_remaining = certificate.coverage_amount.saturating_sub(certificate_drawn);
+        if certificate_remaining <= 0 {
+            return Err(ContractError::AggregateLimitExhausted);
+        }
+
+        let drawn = draw_down(
+            &env,
+            certificate.master_policy_id,
+            claim_id,
+            amount.min(certificate_remaining),
+        )?;
+        env.storage().persistent().set(
+            &DataKey::CertificateDrawn(certificate_id),
+            &(certificate_drawn + drawn),
+        );
+
+        Ok(drawn)
+    }
+
+    /// Returns what a member can still claim: the unused certificate cover, bounded by the
+    /// master policy's remaining aggregate.
+    pub fn get_certificate_remaining_coverage(
+        env: Env,
+        certificate_id: u64,
+    ) -> Result<i128, ContractError> {
+        let certificate = Self::get_certificate(env.clone(), certificate_id)?;
+        let drawn: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CertificateDrawn(certificate_id))
+            .unwrap_or(0);
+        let master_remaining =
+            Self::get_remaining_coverage(env, certificate.master_policy_id)?;
+        Ok(certificate
+            .coverage_amount
+            .saturating_sub(drawn)
+            .max(0)
+            .min(master_remaining))
+    }
+
+    pub fn get_certificate(
+        env: Env,
+        certificate_id: u64,
+    ) -> Result<MemberCertificate, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Certificate(certificate_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Returns a member's active certificate under a master policy.
+    pub fn get_member_certificate(
+        env: Env,
+        master_policy_id: u64,
+        member: Address,
+    ) -> Result<MemberCertificate, ContractError> {
+        let certificate_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MemberCertificate(master_policy_id, member))
+            .ok_or(ContractError::NotFound)?;
+        Self::get_certificate(env, certificate_id)
+    }
+
+    pub fn get_group_summary(
+        env: Env,
+        master_policy_id: u64,
+    ) -> Result<GroupPolicySummary, ContractError> {
+        get_group_policy(&env, master_policy_id)
+    }
+
+    /// Returns a page of certificates under a master policy in enrollment order,
+    /// including removed members.
+    ///
+    /// # Arguments
+    /// * `start_index` - Zero-based enrollment index to start from
+    /// * `limit` - Maximum number of certificates to return (capped at 50)
+    pub fn get_group_members(
+        env: Env,
+        master_policy_id: u64,
+        start_index: u32,
+        limit: u32,
+    ) -> Result<PaginatedMembersResult, ContractError> {
+        let group = get_group_policy(&env, master_policy_id)?;
+        let effective_limit = if limit == 0 || limit > MAX_PAGINATION_LIMIT {
+            MAX_PAGINATION_LIMIT
+        } else {
+            limit
+        };
+
+        let mut certificates = Vec::new(&env);
+        let end_index = core::cmp::min(start_index.saturating_add(effective_limit), group.member_count);
+        for index in start_index..end_index {
+            let certificate_id: Option<u64> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::GroupMember(master_policy_id, index));
+            if let Some(certificate_id) = certificate_id {
+                certificates.push_back(Self::get_certificate(env.clone(), certificate_id)?);
+            }
+        }
+
+        Ok(PaginatedMembersResult {
+            certificates,
+            total_count: group.member_count,
+        })
+    }
+
+    /// Expires a policy. Only allowed when the policy is ACTIVE.
+    pub fn expire_policy(env: Env, actor: Address, policy_id: u64) -> Result<(), ContractError> {
+        require_admin(&env, &actor)?;
+
+        // Check emergency pause status for policy expiration
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "expire_policy")))?;
+
+        // Use the state machine to transition to EXPIRED
+        PolicyStateMachine::transition(&env, policy_id, PolicyState::EXPIRED, actor)?;
+
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, ContractError> {
+        insurance_contracts::authorization::get_admin(&env).ok_or(ContractError::NotInitialized)
+    }
+
+    pub fn get_config(env: Env) -> Result<Config, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Config)
+            .ok_or(ContractError::NotInitialized)
+    }
+
+    pub fn get_risk_pool(env: Env) -> Result<Address, ContractError> {
+        let config: Config = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Config)
+            .ok_or(ContractError::NotInitialized)?;
+        Ok(config.risk_pool)
+    }
+
+    pub fn get_policy_count(env: Env) -> u64 {
+        env.storage().persistent().get(&DataKey::PolicyCounter).unwrap_or(0u64)
+    }
+
+    /// Returns a paginated list of active policies with structured view data.
+    /// This is a read-only function optimized for frontend/indexer consumption.
+    ///
+    /// # Arguments
+    /// * `start_index` - Zero-based index to start from in the active policy list
+    /// * `limit` - Maximum number of policies to return (capped at 50)
+    ///
+    /// # Returns
+    /// * `PaginatedPoliciesResult` containing the policies and total count
+    ///
+    /// # Example
+    /// To get the first page: `get_active_policies(0, 50)`
+    /// To get the second page: `get_active_policies(50, 50)`
+    pub fn get_active_policies(
+        env: Env,
+        start_index: u32,
+        limit: u32,
+    ) -> PaginatedPoliciesResult {
+        // Use performance monitoring
+        PerformanceMonitor::track_operation(&env, "get_active_policies", || {
+            Self::get_active_policies_impl(env.clone(), start_index, limit)
+        }).unwrap_or_else(|_| PaginatedPoliciesResult {
+            policies: Vec::new(&env),
+            total_count: 0,
+        })
+    }
+
+    /// Optimized implementation of active policies query
+    fn get_active_policies_impl(
+        env: Env,
+        start_index: u32,
+        limit: u32,
+    ) -> Result<PaginatedPoliciesResult, ContractError> {
+        // Use optimized batched query
+        let (policies_vec, total_count) = OptimizedPolicyContract::get_policies_batched(
+            &env,
+            start_index,
+            limit,
+            None, // No state filter
+        );
+
+        Ok(PaginatedPoliciesResult {
+            policies: policies_vec,
+            total_count,
+        })
+    }
+
+    /// Get policies by specific state with optimization
+    pub fn get_policies_by_state(
+        env: Env,
+        state: PolicyState,
+        start_index: u32,
+        limit: u32,
+    ) -> PaginatedPoliciesResult {
+        PerformanceMonitor::track_operation(&env, "get_policies_by_state", || {
+            Self::get_policies_by_state_impl(env.clone(), state, start_index, limit)
+        }).unwrap_or_else(|_| PaginatedPoliciesResult {
+            policies: Vec::new(&env),
+            total_count: 0,
+        })
+    }
+
+    fn get_policies_by_state_impl(
+        env: Env,
+        state: PolicyState,
+        start_index: u32,
+        limit: u32,
+    ) -> Result<PaginatedPoliciesResult, ContractError> {
+        // Use optimized batched query with state filter
+        let (policies_vec, total_count) = OptimizedPolicyContract::get_policies_batched(
+            &env,
+            start_index,
+            limit,
+            Some(state),
+        );
+
+        Ok(PaginatedPoliciesResult {
+            policies: policies_vec,
+            total_count,
+        })
+    }
+
+    /// Returns the count of currently active policies.
+    pub fn get_active_policy_count(env: Env) -> u32 {
+        let active_list: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&ACTIVE_POLICY_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+        active_list.len()
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+        // Verify identity and require admin permission
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        set_paused(&env, true);
+
+        env.events().publish((Symbol::new(&env, "paused"), ()), admin);
+
+        Ok(())
+    }
+
+    pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
+        // Verify identity and require admin permission
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        set_paused(&env, false);
+
+        env.events().publish((Symbol::new(&env, "unpaused"), ()), admin);
+        env.events().publish(
+            (Symbol::new(&env, "ContractUnpaused"), ()),
+            (admin, env.ledger().timestamp()),
+        );
+
+
+        Ok(())
+    }
+
+    /// Emergency pause the entire contract
+    pub fn emergency_pause(
+        env: Env,
+        admin: Address,
+        reason: Symbol,
+        max_duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        
+        EmergencyPause::activate_emergency_pause(&env, &admin, reason, max_duration_seconds)
+    }
+
+    /// Deactivate emergency pause
+    pub fn emergency_unpause(
+        env: Env,
+        admin: Address,
+        reason: Symbol,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        
+        EmergencyPause::deactivate_emergency_pause(&env, &admin, reason)
+    }
+
+    /// Pause specific functions
+    pub fn pause_functions(
+        env: Env,
+        admin: Address,
+        functions: Vec<Symbol>,
+        reason: Symbol,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        
+        EmergencyPause::pause_functions(&env, &admin, &functions, reason)
+    }
+
+    /// Unpause specific functions
+    pub fn unpause_functions(
+        env: Env,
+        admin: Address,
+        functions: Vec<Symbol>,
+        reason: Symbol,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        
+        EmergencyPause::unpause_functions(&env, &admin, &functions, reason)
+    }
+
+    /// Get emergency pause configuration
+    pub fn get_emergency_pause_config(env: Env) -> Result<insurance_contracts::emergency_pause::EmergencyPauseConfig, ContractError> {
+        EmergencyPause::get_pause_config(&env)
+    }
+
+    /// Get emergency pause history
+    pub fn get_emergency_pause_history(env: Env, limit: u32) -> Vec<insurance_contracts::emergency_pause::EmergencyPauseEvent> {
+        EmergencyPause::get_pause_history(&env, limit)
+    }
+
+    /// Grant policy manager role to an address (admin only)
+    pub fn grant_manager_role(
+        env: Env,
+        admin: Address,
+        manager: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::grant_role(
+            &env,
+            &admin,
+            &manager,
+            Role::PolicyManager,
+        )?;
+
+        env.events()
+            .publish((Symbol::new(&env, "role_granted"), manager.clone()), admin);
+        env.events().publish(
+            (Symbol::new(&env, "PolicyManagerGranted"), ()),
+            (admin, manager.clone(), env.ledger().timestamp()),
+
+            );
+        env.events().publish((Symbol::new(&env, "PolicyManagerGrantedFull"), ()), (admin.clone(), manager.clone(), env.ledger().timestamp()));
+        );
+
+
+
+        Ok(())
+    }
+
+    /// Revoke policy manager role from an address (admin only)
+    pub fn revoke_manager_role(
+        env: Env,
+        admin: Address,
+        manager: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::revoke_role(&env, &admin, &manager)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "role_revoked"), manager.clone()), admin);
+        env.events().publish(
+            (Symbol::new(&env, "PolicyManagerRevoked"), ()),
+            (admin, manager.clone(), env.ledger().timestamp()),
+        );
+
+
+        Ok(())
+    }
+
+    /// Get the role of an address
+    pub fn get_user_role(env: Env, address: Address) -> Role {
+        get_role(&env, &address)
+    }
+
+    /// Grant auditor role to an address (admin only)
+    pub fn grant_auditor_role(
+        env: Env,
+        admin: Address,
+        auditor: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::grant_role(
+            &env,
+            &admin,
+            &auditor,
+            Role::Auditor,
+        )?;
+
+        env.events()
+            .publish((Symbol::new(&env, "auditor_role_granted"), auditor.clone()), admin);
+        env.events().publish(
+            (Symbol::new(&env, "AuditorGranted"), ()),
+            (admin, auditor.clone(), env.ledger().timestamp()),
+
+            );
+        env.events().publish((Symbol::new(&env, "AuditorGrantedFull"), ()), (admin.clone(), auditor.clone(), env.ledger().timestamp()));
+        );
+
+
+
+        Ok(())
+    }
+
+    /// Revoke auditor role from an address (admin only)
+    pub fn revoke_auditor_role(
+        env: Env,
+        admin: Address,
+        auditor: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::revoke_role(&env, &admin, &auditor)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "auditor_role_revoked"), auditor.clone()), admin);
+        env.events().publish(
+            (Symbol::new(&env, "AuditorRevoked"), ()),
+            (admin, auditor.clone(), env.ledger().timestamp()),
+        );
+
+
+        Ok(())
+    }
+
+    /// Allow role delegation by eligible users
+    pub fn delegate_role(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+        role: Role,
+    ) -> Result<(), ContractError> {
+        delegator.require_auth();
+
+        insurance_contracts::authorization::delegate_role(&env, &delegator, &delegatee, role)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "role_delegated"), delegatee.clone(), role.clone()), delegator);
+        env.events().publish((Symbol::new(&env, "RoleDelegated"), ()), (delegator, delegatee.clone(), role.clone(), env.ledger().timestamp()));
+
+        Ok(())
+    }
+
+    /// Revoke a delegated role (admin only)
+    pub fn revoke_delegated_role(
+        env: Env,
+        admin: Address,
+        target: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::revoke_delegated_role(&env, &admin, &target)?;
+
+        env.events()
+            .publish((Symbol::new(&env, "delegated_role_revoked"), target.clone()), admin);
+
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Disaster Recovery & Backup
+    // ========================================================================
+
+    /// Updates the risk pool address in case of emergency or migration.
+    /// This ensures the policy contract points to a valid risk pool.
+    pub fn update_risk_pool(
+        env: Env,
+        admin: Address,
+        new_risk_pool: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        validate_address(&env, &new_risk_pool)?;
+
+        let mut config = Self::get_config(env.clone())?;
+        let old_risk_pool = config.risk_pool;
+        
+        if old_risk_pool == new_risk_pool {
+            return Err(ContractError::InvalidInput);
+        }
+
+        config.risk_pool = new_risk_pool.clone();
+        env.storage().persistent().set(&DataKey::Config, &config);
+
+        // Ensure the new risk pool is trusted
+        register_trusted_contract(&env, &admin, &new_risk_pool)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "RiskPoolUpdated"), ()),
+            (admin, old_risk_pool, new_risk_pool, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Synchronizes the active policy list with the actual state of a policy.
+    /// This is a recovery function to fix data integrity issues if the list gets out of sync.
+    pub fn sync_policy_state(
+        env: Env,
+        admin: Address,
+        policy_id: u64,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        // Verify policy exists
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+        let is_active = policy.is_active();
+
+        let mut active_list: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&ACTIVE_POLICY_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut found_index = None;
+        for i in 0..active_list.len() {
+            if active_list.get(i).unwrap() == policy_id {
+                found_index = Some(i);
+                break;
+            }
+        }
+
+        if is_active && found_index.is_none() {
+            // Policy is active but missing from list -> Add it
+            active_list.push_back(policy_id);
+            env.storage().persistent().set(&ACTIVE_POLICY_LIST, &active_list);
+            
+            env.events().publish(
+                (Symbol::new(&env, "PolicyStateSynced"), policy_id),
+                (Symbol::new(&env, "AddedToActiveList"), env.ledger().timestamp()),
+            );
+        } else if !is_active && found_index.is_some() {
+            // Policy is not active but present in list -> Remove it
+            let index = found_index.unwrap();
+            active_list.remove(index);
+            env.storage().persistent().set(&ACTIVE_POLICY_LIST, &active_list);
+
+            env.events().publish(
+                (Symbol::new(&env, "PolicyStateSynced"), policy_id),
+                (Symbol::new(&env, "RemovedFromActiveList"), env.ledger().timestamp()),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Emits a snapshot of the policy state to the event log.
+    /// This facilitates off-chain backup and verification.
+    pub fn snapshot_policy(
+        env: Env,
+        admin: Address,
+        policy_id: u64,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let policy = Self::get_policy(env.clone(), policy_id)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "PolicySnapshot"), policy_id),
+            (policy, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    fn with_contract_env<T>(env: &Env, f: impl FnOnce() -> T) -> T {
+        let cid = env.register_contract(None, PolicyContract);
+        env.as_contract(&cid, f)
+    }
+
+    #[contract]
+    pub struct MockRiskPool;
+
+    #[contractimpl]
+    impl MockRiskPool {
+        pub fn credit_premium(
+            _env: Env,
+            _caller_contract: Address,
+            _asset: shared::types::Asset,
+            _amount: i128,
+        ) {
+        }
+
+        pub fn refund_premium(
+            env: Env,
+            _caller_contract: Address,
+            _recipient: Address,
+            amount: i128,
+            _asset: shared::types::Asset,
+        ) {
+            env.storage().instance().set(&Symbol::new(&env, "refunded"), &amount);
+        }
+
+        pub fn refunded(env: Env) -> i128 {
+            env.storage().instance().get(&Symbol::new(&env, "refunded")).unwrap_or(0)
+        }
+    }
+
+    #[contract]
+    pub struct MockTreasury;
+
+    #[contractimpl]
+    impl MockTreasury {
+        pub fn get_fee_percentage(_env: Env) -> u32 {
+            500
+        }
+
+        pub fn deposit_premium_fee(_env: Env, _from: Address, _amount: i128) {}
+
+        pub fn pay_keeper_bounty(
+            _env: Env,
+            _caller_contract: Address,
+            _keeper: Address,
+            _amount: i128,
+        ) {
+        }
+    }
+
+    #[contract]
+    pub struct MockProductTemplate;
+
+    #[contractimpl]
+    impl MockProductTemplate {
+        pub fn quote_premium(
+            _env: Env,
+            _template_id: u64,
+            coverage_amount: i128,
+            _duration_days: u32,
+            _deductible: i128,
+            _custom_values: Vec<shared::types::CustomParamValue>,
+        ) -> i128 {
+            coverage_amount / 100
+        }
+    }
+
+    /// Registers a Stellar asset contract for Native premiums and funds the holder.
+    fn setup_premium_token(env: &Env, admin: &Address, holder: &Address) -> Address {
+        env.mock_all_auths();
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        soroban_sdk::token::StellarAssetClient::new(env, &token)
+            .mint(holder, &(MAX_PREMIUM_AMOUNT * 10));
+        PolicyContract::set_asset_token(
+            env.clone(),
+            admin.clone(),
+            shared::types::Asset::Native,
+            token.clone(),
+        )
+        .unwrap();
+        token
+    }
+
+    #[test]
+    fn test_valid_policy_issuance() {
+        let env = Env::default();
+
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let coverage = MIN_COVERAGE_AMOUNT + 1000;
+            let premium = MIN_PREMIUM_AMOUNT + 100;
+            let duration = 30;
+
+            let policy_id = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                coverage,
+                premium,
+                duration,
+                false,
+                None, // coverage_asset - defaults to Native
+                None, // premium_asset - defaults to Native
+                None, // allow_multi_asset_claims - defaults to false
+                None, // product_id
+            )
+            .unwrap();
+
+            assert_eq!(policy_id, 1);
+            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
+            assert_eq!(policy.holder, holder);
+            assert_eq!(policy.coverage_amount, coverage);
+            assert_eq!(policy.premium_amount, premium);
+            assert_eq!(policy.state(), PolicyState::ACTIVE);
+            // Verify default asset values
+            assert!(matches!(policy.coverage_asset, shared::types::Asset::Native));
+            assert!(matches!(policy.premium_asset, shared::types::Asset::Native));
+            assert_eq!(policy.allow_multi_asset_claims, false);
+        });
+    }
+
+    #[test]
+    fn test_invalid_coverage_too_low() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let result = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MIN_COVERAGE_AMOUNT - 1,
+                MIN_PREMIUM_AMOUNT + 100,
+                30,
+                false,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            assert_eq!(result, Err(ContractError::InvalidAmount));
+        });
+    }
+
+    #[test]
+    fn test_invalid_coverage_too_high() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let result = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MAX_COVERAGE_AMOUNT + 1,
+                MIN_PREMIUM_AMOUNT + 100,
+                30,
+                false,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            assert_eq!(result, Err(ContractError::InvalidAmount));
+        });
+    }
+
+    #[test]
+    fn test_invalid_premium_too_low() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let result = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MIN_COVERAGE_AMOUNT + 1000,
+                MIN_PREMIUM_AMOUNT - 1,
+                30,
+                false,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            assert_eq!(result, Err(ContractError::InvalidPremium));
+        });
+    }
+
+    #[test]
+    fn test_invalid_premium_too_high() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let result = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MIN_COVERAGE_AMOUNT + 1000,
+                MAX_PREMIUM_AMOUNT + 1,
+                30,
+                false,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            assert_eq!(result, Err(ContractError::InvalidPremium));
+        });
+    }
+
+    #[test]
+    fn test_invalid_duration_too_short() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let result = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MIN_COVERAGE_AMOUNT + 1000,
+                MIN_PREMIUM_AMOUNT + 100,
+                MIN_POLICY_DURATION_DAYS - 1,
+                false,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            assert_eq!(result, Err(ContractError::InvalidInput));
+        });
+    }
+
+    #[test]
+    fn test_invalid_duration_too_long() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let result = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MIN_COVERAGE_AMOUNT + 1000,
+                MIN_PREMIUM_AMOUNT + 100,
+                MAX_POLICY_DURATION_DAYS + 1,
+                false,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            assert_eq!(result, Err(ContractError::InvalidInput));
+        });
+    }
+
+    #[test]
+    fn test_duplicate_policy_issuance_not_possible() {
+        // Since policy IDs are unique via counter, duplicate issuance isn't possible
+        // This test ensures the counter increments properly
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let coverage = MIN_COVERAGE_AMOUNT + 1000;
+            let premium = MIN_PREMIUM_AMOUNT + 100;
+            let duration = 30;
+
+            let policy_id1 = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                coverage,
+                premium,
+                duration,
+                false,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            let policy_id2 = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                coverage,
+                premium,
+                duration,
+                false,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            assert_eq!(policy_id1, 1);
+            assert_eq!(policy_id2, 2);
+            assert_ne!(policy_id1, policy_id2);
+        });
+    }
+
+    #[test]
+    fn test_state_machine_valid_transitions() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let coverage = MIN_COVERAGE_AMOUNT + 1000;
+            let premium = MIN_PREMIUM_AMOUNT + 100;
+            let duration = 30;
+
+            let policy_id = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                coverage,
+                premium,
+                duration,
+                false,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            // Test ACTIVE -> CANCELLED
+            PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, admin.clone())
+                .unwrap();
+            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
+            assert_eq!(policy.state(), PolicyState::CANCELLED);
+
+            // Check history
+            let history = PolicyStateMachine::get_policy_history(&env, policy_id);
+            assert_eq!(history.len(), 1);
+            let h0 = history.get(0).unwrap();
+            assert_eq!(h0.previous_state, PolicyState::ACTIVE);
+            assert_eq!(h0.new_state, PolicyState::CANCELLED);
+        });
+    }
+
+    #[test]
+    fn test_state_machine_invalid_transitions() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let coverage = MIN_COVERAGE_AMOUNT + 1000;
+            let premium = MIN_PREMIUM_AMOUNT + 100;
+            let duration = 30;
+
+            let policy_id = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                coverage,
+                premium,
+                duration,
+                false,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            // Transition to CANCELLED
+            PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, admin.clone())
+                .unwrap();
+
+            // Try invalid transition from CANCELLED to EXPIRED
+            let result = PolicyStateMachine::transition(
+                &env,
+                policy_id,
+                PolicyState::EXPIRED,
+                admin.clone(),
+            );
+            assert_eq!(result, Err(ContractError::InvalidStateTransition));
+        });
+    }
+
+    #[test]
+    fn test_state_based_access_control() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let coverage = MIN_COVERAGE_AMOUNT + 1000;
+            let premium = MIN_PREMIUM_AMOUNT + 100;
+            let duration = 30;
+
+            let policy_id = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                coverage,
+                premium,
+                duration,
+                false,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            // Cancel the policy
+            PolicyContract::cancel_policy(env.clone(), admin.clone(), policy_id).unwrap();
+
+            // Try to cancel again - should fail due to state
+            let result = PolicyContract::cancel_policy(env.clone(), admin.clone(), policy_id);
+            assert_eq!(result, Err(ContractError::InvalidStateTransition));
+        });
+    }
+
+    #[test]
+    fn test_cancel_policy_refunds_unearned_premium() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
+            PolicyContract::set_short_rate_penalty(env.clone(), admin.clone(), 7, 1_000).unwrap();
+
+            let premium = 3_000_000;
+            let policy_id = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MIN_COVERAGE_AMOUNT + 1000,
+                premium,
+                30,
+                false,
+                None,
+                None,
+                None,
+                Some(7),
+            )
+            .unwrap();
+
+            // Cancel after 10 of 30 days: 2/3 unearned, less a 10% short-rate penalty
+            env.ledger().with_mut(|li| li.timestamp += 10 * 86400);
+            let expected = 1_800_000;
+            assert_eq!(
+                PolicyContract::get_cancellation_refund(env.clone(), policy_id).unwrap(),
+                expected
+            );
+
+            PolicyContract::cancel_policy(env.clone(), admin.clone(), policy_id).unwrap();
+
+            let refunded: i128 = env.invoke_contract(
+                &risk_pool,
+                &Symbol::new(&env, "refunded"),
+                Vec::new(&env),
+            );
+            assert_eq!(refunded, expected);
+
+            let history = PolicyStateMachine::get_policy_history(&env, policy_id);
+            let last = history.get(history.len() - 1).unwrap();
+            assert_eq!(last.new_state, PolicyState::CANCELLED);
+            assert_eq!(last.refund_amount, expected);
+        });
+    }
+
+    #[test]
+    fn test_cancellation_refunds_only_the_pool_share() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+            let treasury = env.register_contract(None, MockTreasury);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            PolicyContract::set_treasury(env.clone(), admin.clone(), treasury).unwrap();
+            setup_premium_token(&env, &admin, &holder);
+
+            let policy_id = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MIN_COVERAGE_AMOUNT + 1000,
+                3_000_000,
+                30,
+                false,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
 
-            env.events().publish(
-                (Symbol::new(&env, "PolicyStateSynced"), policy_id),
-                (Symbol::new(&env, "RemovedFromActiveList"), env.ledger().timestamp()),
+            // 2/3 unearned is 2_000_000; the treasury keeps its 5% fee on it
+            env.ledger().with_mut(|li| li.timestamp += 10 * 86400);
+            assert_eq!(
+                PolicyContract::get_cancellation_refund(env.clone(), policy_id).unwrap(),
+                1_900_000
             );
-        }
+            PolicyContract::cancel_policy(env.clone(), admin.clone(), policy_id).unwrap();
 
-        Ok(())
+            let refunded: i128 = env.invoke_contract(
+                &risk_pool,
+                &Symbol::new(&env, "refunded"),
+                Vec::new(&env),
+            );
+            assert_eq!(refunded, 1_900_000);
+        });
     }
 
-    /// Emits a snapshot of the policy state to the event log.
-    /// This facilitates off-chain backup and verification.
-    pub fn snapshot_policy(
-        env: Env,
-        admin: Address,
-        policy_id: u64,
-    ) -> Result<(), ContractError> {
-        admin.require_auth();
-        require_admin(&env, &admin)?;
-
-        let policy = Self::get_policy(env.clone(), policy_id)?;
+    #[test]
+    fn test_set_short_rate_penalty_rejects_over_100_percent() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
-        env.events().publish(
-            (Symbol::new(&env, "PolicySnapshot"), policy_id),
-            (policy, env.ledger().timestamp()),
-        );
+            env.mock_all_auths();
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool).unwrap();
 
-        Ok(())
+            let result = PolicyContract::set_short_rate_penalty(env.clone(), admin, 1, 10_001);
+            assert_eq!(result, Err(ContractError::InvalidInput));
+        });
     }
-}
 
+    #[test]
+    fn test_set_coverage_terms_validates_limits() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            setup_premium_token(&env, &admin, &holder);
 
-    fn with_contract_env<T>(env: &Env, f: impl FnOnce() -> T) -> T {
-        let cid = env.register_contract(None, PolicyContract);
-        env.as_contract(&cid, f)
+            let coverage = MIN_COVERAGE_AMOUNT * 10;
+            let policy_id = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                coverage,
+                MIN_PREMIUM_AMOUNT,
+                30,
+                false,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            // Unset terms pay the gross loss
+            let terms = PolicyContract::get_coverage_terms(env.clone(), policy_id).unwrap();
+            assert_eq!(terms.deductible, 0);
+            assert_eq!(terms.coinsurance_bps, 0);
+
+            let mut sub_limits = Vec::new(&env);
+            sub_limits.push_back(shared::types::PerilSubLimit {
+                peril: Symbol::new(&env, "flood"),
+                limit: coverage / 2,
+            });
+            let terms = CoverageTerms {
+                deductible: MIN_COVERAGE_AMOUNT,
+                coinsurance_bps: 2_000,
+                sub_limits: sub_limits.clone(),
+            };
+            PolicyContract::set_coverage_terms(env.clone(), manager.clone(), policy_id, terms.clone())
+                .unwrap();
+            assert_eq!(
+                PolicyContract::get_coverage_terms(env.clone(), policy_id).unwrap(),
+                terms
+            );
+
+            // Deductible cannot swallow the whole coverage
+            let result = PolicyContract::set_coverage_terms(
+                env.clone(),
+                manager.clone(),
+                policy_id,
+                CoverageTerms {
+                    deductible: coverage,
+                    coinsurance_bps: 0,
+                    sub_limits: Vec::new(&env),
+                },
+            );
+            assert_eq!(result, Err(ContractError::InvalidCoverageTerms));
+
+            // A peril may only be capped once
+            sub_limits.push_back(shared::types::PerilSubLimit {
+                peril: Symbol::new(&env, "flood"),
+                limit: coverage / 4,
+            });
+            let result = PolicyContract::set_coverage_terms(
+                env.clone(),
+                manager.clone(),
+                policy_id,
+                CoverageTerms {
+                    deductible: 0,
+                    coinsurance_bps: 0,
+                    sub_limits,
+                },
+            );
+            assert_eq!(result, Err(ContractError::InvalidCoverageTerms));
+        });
     }
 
     #[test]
-    fn test_valid_policy_issuance() {
+    fn test_coverage_terms_are_versioned_by_effective_date() {
         let env = Env::default();
-
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
-            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            setup_premium_token(&env, &admin, &holder);
 
-            let coverage = MIN_COVERAGE_AMOUNT + 1000;
-            let premium = MIN_PREMIUM_AMOUNT + 100;
-            let duration = 30;
-
+            let coverage = MIN_COVERAGE_AMOUNT * 10;
             let policy_id = PolicyContract::issue_policy(
                 env.clone(),
                 manager.clone(),
                 holder.clone(),
                 coverage,
-                premium,
-                duration,
+                MIN_PREMIUM_AMOUNT,
+                30,
                 false,
-                None, // coverage_asset - defaults to Native
-                None, // premium_asset - defaults to Native
-                None, // allow_multi_asset_claims - defaults to false
+                None,
+                None,
+                None,
+                None,
             )
             .unwrap();
+            let issued_at = env.ledger().timestamp();
+
+            env.ledger().with_mut(|li| li.timestamp += 5 * 86400);
+            let first = CoverageTerms {
+                deductible: MIN_COVERAGE_AMOUNT,
+                coinsurance_bps: 0,
+                sub_limits: Vec::new(&env),
+            };
+            PolicyContract::set_coverage_terms(env.clone(), manager.clone(), policy_id, first.clone())
+                .unwrap();
 
-            assert_eq!(policy_id, 1);
-            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
-            assert_eq!(policy.holder, holder);
-            assert_eq!(policy.coverage_amount, coverage);
-            assert_eq!(policy.premium_amount, premium);
-            assert_eq!(policy.state(), PolicyState::ACTIVE);
-            // Verify default asset values
-            assert!(matches!(policy.coverage_asset, shared::types::Asset::Native));
-            assert!(matches!(policy.premium_asset, shared::types::Asset::Native));
-            assert_eq!(policy.allow_multi_asset_claims, false);
+            env.ledger().with_mut(|li| li.timestamp += 5 * 86400);
+            let second = CoverageTerms {
+                deductible: MIN_COVERAGE_AMOUNT * 2,
+                coinsurance_bps: 1_000,
+                sub_limits: Vec::new(&env),
+            };
+            PolicyContract::set_coverage_terms(env.clone(), manager.clone(), policy_id, second.clone())
+                .unwrap();
+
+            // A loss before any terms were set pays the gross loss
+            let terms =
+                PolicyContract::get_coverage_terms_at(env.clone(), policy_id, issued_at + 86400)
+                    .unwrap();
+            assert_eq!(terms.deductible, 0);
+            assert_eq!(terms.coinsurance_bps, 0);
+
+            // Later changes do not reach back past the loss date
+            assert_eq!(
+                PolicyContract::get_coverage_terms_at(env.clone(), policy_id, issued_at + 7 * 86400)
+                    .unwrap(),
+                first
+            );
+            assert_eq!(
+                PolicyContract::get_coverage_terms_at(
+                    env.clone(),
+                    policy_id,
+                    env.ledger().timestamp()
+                )
+                .unwrap(),
+                second
+            );
+            assert_eq!(
+                PolicyContract::get_coverage_terms(env.clone(), policy_id).unwrap(),
+                second
+            );
         });
     }
 
     #[test]
-    fn test_invalid_coverage_too_low() {
+    fn test_claim_drawdown_respects_limits() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let claims = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
-            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            setup_premium_token(&env, &admin, &holder);
 
-            let result = PolicyContract::issue_policy(
+            let coverage = MIN_COVERAGE_AMOUNT * 20;
+            let policy_id = PolicyContract::issue_policy(
                 env.clone(),
                 manager.clone(),
                 holder.clone(),
-                MIN_COVERAGE_AMOUNT - 1,
-                MIN_PREMIUM_AMOUNT + 100,
+                coverage,
+                MIN_PREMIUM_AMOUNT,
                 30,
                 false,
                 None,
                 None,
                 None,
+                None,
+            )
+            .unwrap();
+            assert_eq!(
+                PolicyContract::get_remaining_coverage(env.clone(), policy_id).unwrap(),
+                coverage
             );
 
-            assert_eq!(result, Err(ContractError::InvalidAmount));
+            let aggregate = MIN_COVERAGE_AMOUNT * 10;
+            let per_occurrence = MIN_COVERAGE_AMOUNT * 6;
+            PolicyContract::set_claim_limits(
+                env.clone(),
+                manager.clone(),
+                policy_id,
+                aggregate,
+                per_occurrence,
+            )
+            .unwrap();
+
+            // Untrusted callers cannot draw down coverage
+            let result = PolicyContract::draw_down_coverage(
+                env.clone(),
+                claims.clone(),
+                policy_id,
+                1,
+                MIN_COVERAGE_AMOUNT,
+            );
+            assert_eq!(result, Err(ContractError::NotTrustedContract));
+            PolicyContract::register_claims_contract(env.clone(), admin.clone(), claims.clone())
+                .unwrap();
+
+            // First claim is capped per occurrence, the second by what remains
+            let drawn = PolicyContract::draw_down_coverage(
+                env.clone(),
+                claims.clone(),
+                policy_id,
+                1,
+                MIN_COVERAGE_AMOUNT * 8,
+            )
+            .unwrap();
+            assert_eq!(drawn, per_occurrence);
+            let drawn = PolicyContract::draw_down_coverage(
+                env.clone(),
+                claims.clone(),
+                policy_id,
+                2,
+                MIN_COVERAGE_AMOUNT * 5,
+            )
+            .unwrap();
+            assert_eq!(drawn, aggregate - per_occurrence);
+
+            // The same claim cannot draw twice, and an exhausted aggregate pays nothing more
+            let result =
+                PolicyContract::draw_down_coverage(env.clone(), claims.clone(), policy_id, 2, 1);
+            assert_eq!(result, Err(ContractError::AlreadyExists));
+            let result =
+                PolicyContract::draw_down_coverage(env.clone(), claims.clone(), policy_id, 3, 1);
+            assert_eq!(result, Err(ContractError::AggregateLimitExhausted));
+
+            let limits = PolicyContract::get_claim_limits(env.clone(), policy_id).unwrap();
+            assert_eq!(limits.drawn_down, aggregate);
+            assert_eq!(limits.remaining, 0);
+
+            // Shrinking a claim's drawdown frees coverage that a later increase can take back
+            let drawn = PolicyContract::adjust_claim_drawdown(
+                env.clone(),
+                claims.clone(),
+                policy_id,
+                1,
+                None,
+                MIN_COVERAGE_AMOUNT * 2,
+            )
+            .unwrap();
+            assert_eq!(drawn, MIN_COVERAGE_AMOUNT * 2);
+            assert_eq!(
+                PolicyContract::get_remaining_coverage(env.clone(), policy_id).unwrap(),
+                MIN_COVERAGE_AMOUNT * 4
+            );
+            let drawn = PolicyContract::adjust_claim_drawdown(
+                env.clone(),
+                claims.clone(),
+                policy_id,
+                2,
+                None,
+                MIN_COVERAGE_AMOUNT * 9,
+            )
+            .unwrap();
+            assert_eq!(drawn, per_occurrence);
+            let result = PolicyContract::adjust_claim_drawdown(
+                env.clone(),
+                claims.clone(),
+                policy_id,
+                3,
+                None,
+                1,
+            );
+            assert_eq!(result, Err(ContractError::NotFound));
         });
     }
 
     #[test]
-    fn test_invalid_coverage_too_high() {
+    fn test_set_beneficiaries_holder_only_and_audited() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let spouse = Address::generate(&env);
+            let child = Address::generate(&env);
+            let estate = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
-            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            setup_premium_token(&env, &admin, &holder);
 
-            let result = PolicyContract::issue_policy(
+            let policy_id = PolicyContract::issue_policy(
                 env.clone(),
                 manager.clone(),
                 holder.clone(),
-                MAX_COVERAGE_AMOUNT + 1,
-                MIN_PREMIUM_AMOUNT + 100,
+                MIN_COVERAGE_AMOUNT * 10,
+                MIN_PREMIUM_AMOUNT,
                 30,
                 false,
                 None,
                 None,
                 None,
+                None,
+            )
+            .unwrap();
+
+            let mut beneficiaries = Vec::new(&env);
+            beneficiaries.push_back(Beneficiary {
+                recipient: spouse.clone(),
+                share_bps: 6_000,
+            });
+            beneficiaries.push_back(Beneficiary {
+                recipient: child.clone(),
+                share_bps: 4_000,
+            });
+
+            // Only the holder may designate beneficiaries
+            let result = PolicyContract::set_beneficiaries(
+                env.clone(),
+                manager.clone(),
+                policy_id,
+                beneficiaries.clone(),
+                None,
             );
+            assert_eq!(result, Err(ContractError::Unauthorized));
 
-            assert_eq!(result, Err(ContractError::InvalidAmount));
+            let version = PolicyContract::set_beneficiaries(
+                env.clone(),
+                holder.clone(),
+                policy_id,
+                beneficiaries.clone(),
+                Some(estate.clone()),
+            )
+            .unwrap();
+            assert_eq!(version, 0);
+
+            let designation = PolicyContract::get_beneficiaries(env.clone(), policy_id).unwrap();
+            assert_eq!(designation.beneficiaries, beneficiaries);
+            assert_eq!(designation.contingent, Some(estate));
+
+            // Shares must total 100%
+            let mut short = Vec::new(&env);
+            short.push_back(Beneficiary {
+                recipient: spouse,
+                share_bps: 9_000,
+            });
+            let result =
+                PolicyContract::set_beneficiaries(env.clone(), holder.clone(), policy_id, short, None);
+            assert_eq!(result, Err(ContractError::InvalidBeneficiaries));
+
+            // Clearing the designation is recorded as a new version
+            let version = PolicyContract::set_beneficiaries(
+                env.clone(),
+                holder.clone(),
+                policy_id,
+                Vec::new(&env),
+                None,
+            )
+            .unwrap();
+            assert_eq!(version, 1);
+
+            let history = PolicyContract::get_beneficiary_history(env.clone(), policy_id).unwrap();
+            assert_eq!(history.len(), 2);
+            assert_eq!(history.get(0).unwrap().designation.beneficiaries, beneficiaries);
+            assert_eq!(history.get(1).unwrap().changed_by, holder);
         });
     }
 
     #[test]
-    fn test_invalid_premium_too_low() {
+    fn test_group_policy_member_enrollment_and_removal() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
-            let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let sponsor = Address::generate(&env);
+            let alice = Address::generate(&env);
+            let bob = Address::generate(&env);
+            let claims = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            PolicyContract::register_claims_contract(env.clone(), admin.clone(), claims.clone())
+                .unwrap();
+            let token = setup_premium_token(&env, &admin, &sponsor);
+            let token_client = soroban_sdk::token::Client::new(&env, &token);
 
-            let result = PolicyContract::issue_policy(
+            let master_id = PolicyContract::issue_policy(
                 env.clone(),
                 manager.clone(),
-                holder.clone(),
-                MIN_COVERAGE_AMOUNT + 1000,
-                MIN_PREMIUM_AMOUNT - 1,
+                sponsor.clone(),
+                MIN_COVERAGE_AMOUNT * 100,
+                1_000_000,
                 30,
                 false,
                 None,
                 None,
                 None,
+                None,
+            )
+            .unwrap();
+
+            // Members can only be enrolled once the policy is a master policy
+            let result = PolicyContract::enroll_member(
+                env.clone(),
+                sponsor.clone(),
+                master_id,
+                alice.clone(),
+                MIN_COVERAGE_AMOUNT * 10,
             );
+            assert_eq!(result, Err(ContractError::NotMasterPolicy));
+            PolicyContract::create_master_policy(env.clone(), manager.clone(), master_id).unwrap();
 
-            assert_eq!(result, Err(ContractError::InvalidPremium));
+            // A tenth of the master coverage for the full term costs a tenth of the premium
+            let balance_before = token_client.balance(&sponsor);
+            let alice_cert = PolicyContract::enroll_member(
+                env.clone(),
+                sponsor.clone(),
+                master_id,
+                alice.clone(),
+                MIN_COVERAGE_AMOUNT * 10,
+            )
+            .unwrap();
+            assert_eq!(balance_before - token_client.balance(&sponsor), 100_000);
+
+            let result = PolicyContract::enroll_member(
+                env.clone(),
+                sponsor.clone(),
+                master_id,
+                alice.clone(),
+                MIN_COVERAGE_AMOUNT,
+            );
+            assert_eq!(result, Err(ContractError::MemberAlreadyEnrolled));
+
+            let bob_cert = PolicyContract::enroll_member(
+                env.clone(),
+                sponsor.clone(),
+                master_id,
+                bob.clone(),
+                MIN_COVERAGE_AMOUNT * 5,
+            )
+            .unwrap();
+
+            let page = PolicyContract::get_group_members(env.clone(), master_id, 1, 1).unwrap();
+            assert_eq!(page.total_count, 2);
+            assert_eq!(page.certificates.len(), 1);
+            assert_eq!(page.certificates.get(0).unwrap().member, bob);
+
+            // Removing Alice half-way through the term refunds half her premium
+            env.ledger().with_mut(|li| li.timestamp += 15 * 86400);
+            let refund =
+                PolicyContract::remove_member(env.clone(), sponsor.clone(), alice_cert).unwrap();
+            assert_eq!(refund, 50_000);
+            let refunded: i128 =
+                env.invoke_contract(&risk_pool, &Symbol::new(&env, "refunded"), Vec::new(&env));
+            assert_eq!(refunded, 50_000);
+
+            let summary = PolicyContract::get_group_summary(env.clone(), master_id).unwrap();
+            assert_eq!(summary.member_count, 2);
+            assert_eq!(summary.active_members, 1);
+            assert_eq!(summary.certificate_coverage, MIN_COVERAGE_AMOUNT * 5);
+            let result = PolicyContract::remove_member(env.clone(), sponsor.clone(), alice_cert);
+            assert_eq!(result, Err(ContractError::CertificateNotActive));
+
+            // Member claims are capped by the certificate and draw from the master aggregate
+            let drawn = PolicyContract::draw_down_certificate(
+                env.clone(),
+                claims.clone(),
+                bob_cert,
+                1,
+                MIN_COVERAGE_AMOUNT * 8,
+            )
+            .unwrap();
+            assert_eq!(drawn, MIN_COVERAGE_AMOUNT * 5);
+            assert_eq!(
+                PolicyContract::get_remaining_coverage(env.clone(), master_id).unwrap(),
+                MIN_COVERAGE_AMOUNT * 95
+            );
+
+            // A removed member's certificate can no longer be drawn on or adjusted
+            let result = PolicyContract::draw_down_certificate(
+                env.clone(),
+                claims.clone(),
+                alice_cert,
+                2,
+                MIN_COVERAGE_AMOUNT,
+            );
+            assert_eq!(result, Err(ContractError::CertificateNotActive));
+            let result = PolicyContract::adjust_claim_drawdown(
+                env.clone(),
+                claims.clone(),
+                master_id,
+                2,
+                Some(alice_cert),
+                MIN_COVERAGE_AMOUNT,
+            );
+            assert_eq!(result, Err(ContractError::CertificateNotActive));
         });
     }
 
     #[test]
-    fn test_invalid_premium_too_high() {
+    fn test_endorsement_increases_coverage_pro_rata() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            let token = setup_premium_token(&env, &admin, &holder);
+            let token_client = soroban_sdk::token::Client::new(&env, &token);
 
-            let result = PolicyContract::issue_policy(
+            let coverage = 10_000_000;
+            let premium = 3_000_000;
+            let policy_id = PolicyContract::issue_policy(
                 env.clone(),
                 manager.clone(),
                 holder.clone(),
-                MIN_COVERAGE_AMOUNT + 1000,
-                MAX_PREMIUM_AMOUNT + 1,
+                coverage,
+                premium,
                 30,
                 false,
                 None,
                 None,
                 None,
+                None,
+            )
+            .unwrap();
+
+            // Double the coverage with 20 of 30 days left
+            env.ledger().with_mut(|li| li.timestamp += 10 * 86400);
+            let endorsement_id = PolicyContract::propose_endorsement(
+                env.clone(),
+                manager.clone(),
+                policy_id,
+                Some(coverage * 2),
+                None,
+                None,
+            )
+            .unwrap();
+
+            // Only one pending endorsement per policy
+            let result = PolicyContract::propose_endorsement(
+                env.clone(),
+                manager.clone(),
+                policy_id,
+                None,
+                None,
+                Some(60),
             );
+            assert_eq!(result, Err(ContractError::EndorsementPending));
 
-            assert_eq!(result, Err(ContractError::InvalidPremium));
+            let balance_before = token_client.balance(&holder);
+            let version =
+                PolicyContract::accept_endorsement(env.clone(), holder.clone(), endorsement_id)
+                    .unwrap();
+            assert_eq!(version, 1);
+            assert_eq!(balance_before - token_client.balance(&holder), 2_000_000);
+
+            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
+            assert_eq!(policy.coverage_amount, coverage * 2);
+            assert_eq!(policy.premium_amount, premium + 2_000_000);
+
+            let endorsement = PolicyContract::get_endorsement(env.clone(), endorsement_id).unwrap();
+            assert_eq!(endorsement.status, EndorsementStatus::Accepted);
+            assert_eq!(endorsement.premium_adjustment, 2_000_000);
         });
     }
 
     #[test]
-    fn test_invalid_duration_too_short() {
+    fn test_policy_terms_follow_loss_date() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            setup_premium_token(&env, &admin, &holder);
 
-            let result = PolicyContract::issue_policy(
+            let coverage = 10_000_000;
+            let policy_id = PolicyContract::issue_policy(
                 env.clone(),
                 manager.clone(),
                 holder.clone(),
-                MIN_COVERAGE_AMOUNT + 1000,
-                MIN_PREMIUM_AMOUNT + 100,
-                MIN_POLICY_DURATION_DAYS - 1,
+                coverage,
+                3_000_000,
+                30,
                 false,
                 None,
                 None,
                 None,
+                None,
+            )
+            .unwrap();
+
+            // Halve coverage after 15 days; the holder receives returned premium
+            env.ledger().with_mut(|li| li.timestamp += 15 * 86400);
+            let endorsement_id = PolicyContract::propose_endorsement(
+                env.clone(),
+                manager.clone(),
+                policy_id,
+                Some(coverage / 2),
+                None,
+                None,
+            )
+            .unwrap();
+            PolicyContract::accept_endorsement(env.clone(), holder.clone(), endorsement_id)
+                .unwrap();
+
+            let refunded: i128 = env.invoke_contract(
+                &risk_pool,
+                &Symbol::new(&env, "refunded"),
+                Vec::new(&env),
             );
+            assert_eq!(refunded, 750_000);
 
-            assert_eq!(result, Err(ContractError::InvalidInput));
+            let before = PolicyContract::get_policy_terms_at(env.clone(), policy_id, 86400).unwrap();
+            assert_eq!(before.version, 0);
+            assert_eq!(before.coverage_amount, coverage);
+
+            let after =
+                PolicyContract::get_policy_terms_at(env.clone(), policy_id, 20 * 86400).unwrap();
+            assert_eq!(after.version, 1);
+            assert_eq!(after.coverage_amount, coverage / 2);
+            assert_eq!(after.endorsement_id, Some(endorsement_id));
+
+            let result = PolicyContract::get_policy_terms_at(env.clone(), policy_id, 38 * 86400);
+            assert_eq!(result, Err(ContractError::OutsideCoveragePeriod));
         });
     }
 
     #[test]
-    fn test_invalid_duration_too_long() {
+    fn test_quote_and_purchase_policy() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
-            let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+            let product_template = env.register_contract(None, MockProductTemplate);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            setup_premium_token(&env, &admin, &holder);
+            let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+            soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&holder, &1_000_000);
+            let token_client = soroban_sdk::token::Client::new(&env, &token);
+            let usdc = shared::types::Asset::Contract(token.clone());
+            PolicyContract::set_asset_token(env.clone(), admin.clone(), usdc.clone(), token.clone())
+                .unwrap();
+            PolicyContract::set_product_template(env.clone(), admin.clone(), product_template)
+                .unwrap();
+            PolicyContract::set_product_capacity(env.clone(), admin.clone(), 3, 50_000_000)
                 .unwrap();
 
-            let result = PolicyContract::issue_policy(
+            let quote = PolicyContract::quote_policy(
                 env.clone(),
-                manager.clone(),
                 holder.clone(),
-                MIN_COVERAGE_AMOUNT + 1000,
-                MIN_PREMIUM_AMOUNT + 100,
-                MAX_POLICY_DURATION_DAYS + 1,
+                3,
+                20_000_000,
+                90,
+                0,
+                Vec::new(&env),
                 false,
-                None,
-                None,
-                None,
+                Some(usdc.clone()),
+                Some(usdc.clone()),
+            )
+            .unwrap();
+            assert_eq!(quote.premium_amount, 200_000);
+            assert_eq!(quote.status, QuoteStatus::Open);
+            assert_eq!(
+                PolicyContract::get_product_capacity(env.clone(), 3),
+                Some(30_000_000)
             );
 
-            assert_eq!(result, Err(ContractError::InvalidInput));
+            // A digest for different terms is rejected
+            let result = PolicyContract::purchase_policy(
+                env.clone(),
+                holder.clone(),
+                quote.id,
+                BytesN::from_array(&env, &[1u8; 32]),
+            );
+            assert_eq!(result, Err(ContractError::QuoteMismatch));
+
+            // Only the holder the quote was issued to can redeem it
+            let result = PolicyContract::purchase_policy(
+                env.clone(),
+                Address::generate(&env),
+                quote.id,
+                quote.digest.clone(),
+            );
+            assert_eq!(result, Err(ContractError::Unauthorized));
+
+            let balance_before = token_client.balance(&holder);
+            let policy_id =
+                PolicyContract::purchase_policy(env.clone(), holder.clone(), quote.id, quote.digest)
+                    .unwrap();
+            assert_eq!(balance_before - token_client.balance(&holder), 200_000);
+
+            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
+            assert_eq!(policy.holder, holder);
+            assert_eq!(policy.coverage_amount, 20_000_000);
+            assert_eq!(policy.product_id, Some(3));
+            assert_eq!(policy.coverage_asset, usdc);
+            assert_eq!(policy.premium_asset, usdc);
+
+            let quote = PolicyContract::get_quote(env.clone(), quote.id).unwrap();
+            assert_eq!(quote.status, QuoteStatus::Purchased);
         });
     }
 
     #[test]
-    fn test_duplicate_policy_issuance_not_possible() {
-        // Since policy IDs are unique via counter, duplicate issuance isn't possible
-        // This test ensures the counter increments properly
+    fn test_expired_quote_releases_capacity() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
-            let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+            let product_template = env.register_contract(None, MockProductTemplate);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            setup_premium_token(&env, &admin, &holder);
+            PolicyContract::set_product_template(env.clone(), admin.clone(), product_template)
+                .unwrap();
+            PolicyContract::set_product_capacity(env.clone(), admin.clone(), 3, 20_000_000)
                 .unwrap();
 
-            let coverage = MIN_COVERAGE_AMOUNT + 1000;
-            let premium = MIN_PREMIUM_AMOUNT + 100;
-            let duration = 30;
-
-            let policy_id1 = PolicyContract::issue_policy(
+            let quote = PolicyContract::quote_policy(
                 env.clone(),
-                manager.clone(),
                 holder.clone(),
-                coverage,
-                premium,
-                duration,
+                3,
+                20_000_000,
+                90,
+                0,
+                Vec::new(&env),
                 false,
                 None,
                 None,
-                None,
             )
             .unwrap();
 
-            let policy_id2 = PolicyContract::issue_policy(
+            // Capacity is locked by the open quote
+            let result = PolicyContract::quote_policy(
                 env.clone(),
-                manager.clone(),
                 holder.clone(),
-                coverage,
-                premium,
-                duration,
+                3,
+                10_000_000,
+                90,
+                0,
+                Vec::new(&env),
                 false,
                 None,
                 None,
-                None,
-            )
-            .unwrap();
+            );
+            assert_eq!(result, Err(ContractError::InsufficientCapacity));
 
-            assert_eq!(policy_id1, 1);
-            assert_eq!(policy_id2, 2);
-            assert_ne!(policy_id1, policy_id2);
+            assert_eq!(
+                PolicyContract::expire_quote(env.clone(), quote.id),
+                Err(ContractError::InvalidState)
+            );
+
+            env.ledger().with_mut(|li| li.timestamp += QUOTE_VALIDITY_SECS + 1);
+            let result = PolicyContract::purchase_policy(
+                env.clone(),
+                holder.clone(),
+                quote.id,
+                quote.digest.clone(),
+            );
+            assert_eq!(result, Err(ContractError::QuoteExpired));
+
+            PolicyContract::expire_quote(env.clone(), quote.id).unwrap();
+            assert_eq!(
+                PolicyContract::get_product_capacity(env.clone(), 3),
+                Some(20_000_000)
+            );
         });
     }
 
     #[test]
-    fn test_state_machine_valid_transitions() {
+    fn test_policy_renewal() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            let token = setup_premium_token(&env, &admin, &holder);
 
             let coverage = MIN_COVERAGE_AMOUNT + 1000;
             let premium = MIN_PREMIUM_AMOUNT + 100;
@@ -1591,163 +5280,263 @@ mod tests {
                 coverage,
                 premium,
                 duration,
-                false,
+                true, // Auto renew enabled
+                None,
                 None,
                 None,
                 None,
             )
             .unwrap();
 
-            // Test ACTIVE -> CANCELLED
-            PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, admin.clone())
-                .unwrap();
+            // Renew by holder
+            PolicyContract::renew_policy(env.clone(), holder.clone(), policy_id, 30).unwrap();
+
             let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
-            assert_eq!(policy.state(), PolicyState::CANCELLED);
+            // Duration was 30 days, renewed for 30 days. Total duration from start should be 60 days.
+            assert_eq!(policy.end_time, policy.start_time + 60 * 86400);
+            // The renewal is recorded as a new version of the terms
+            let terms = PolicyContract::get_policy_terms_at(
+                env.clone(),
+                policy_id,
+                policy.start_time + 45 * 86400,
+            )
+            .unwrap();
+            assert_eq!(terms.version, 1);
+            assert_eq!(terms.end_time, policy.end_time);
+
+            // Renew by manager (allowed because auto_renew is true); the premium is drawn
+            // from the holder's allowance
+            soroban_sdk::token::Client::new(&env, &token).approve(
+                &holder,
+                &env.current_contract_address(),
+                &(MAX_PREMIUM_AMOUNT),
+                &(env.ledger().sequence() + 1000),
+            );
+            PolicyContract::renew_policy(env.clone(), manager.clone(), policy_id, 30).unwrap();
+            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
+            assert_eq!(policy.end_time, policy.start_time + 90 * 86400);
 
-            // Check history
-            let history = PolicyStateMachine::get_policy_history(&env, policy_id);
-            assert_eq!(history.len(), 1);
-            let h0 = history.get(0).unwrap();
-            assert_eq!(h0.previous_state, PolicyState::ACTIVE);
-            assert_eq!(h0.new_state, PolicyState::CANCELLED);
+            // Disable auto renew
+            PolicyContract::set_auto_renew(env.clone(), holder.clone(), policy_id, false).unwrap();
+
+            // Renew by manager (should fail)
+            let res = PolicyContract::renew_policy(env.clone(), manager.clone(), policy_id, 30);
+            assert_eq!(res, Err(ContractError::Unauthorized));
         });
     }
 
     #[test]
-    fn test_state_machine_invalid_transitions() {
+    fn test_renewal_charges_premium_within_grace_period() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            let token = setup_premium_token(&env, &admin, &holder);
+            let token_client = soroban_sdk::token::Client::new(&env, &token);
+            PolicyContract::set_grace_period(env.clone(), admin.clone(), 5 * 86400).unwrap();
 
-            let coverage = MIN_COVERAGE_AMOUNT + 1000;
-            let premium = MIN_PREMIUM_AMOUNT + 100;
-            let duration = 30;
-
+            let premium = 3_000_000;
             let policy_id = PolicyContract::issue_policy(
                 env.clone(),
                 manager.clone(),
                 holder.clone(),
-                coverage,
+                MIN_COVERAGE_AMOUNT + 1000,
                 premium,
-                duration,
+                30,
                 false,
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
-            // Transition to CANCELLED
-            PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, admin.clone())
-                .unwrap();
+            // Two days into the grace period the holder can still renew
+            env.ledger().with_mut(|li| li.timestamp += 32 * 86400);
+            let balance_before = token_client.balance(&holder);
+            PolicyContract::renew_policy(env.clone(), holder.clone(), policy_id, 15).unwrap();
+            assert_eq!(balance_before - token_client.balance(&holder), premium / 2);
 
-            // Try invalid transition from CANCELLED to EXPIRED
-            let result = PolicyStateMachine::transition(
-                &env,
-                policy_id,
-                PolicyState::EXPIRED,
-                admin.clone(),
-            );
-            assert_eq!(result, Err(ContractError::InvalidStateTransition));
+            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
+            assert_eq!(policy.end_time, 45 * 86400);
+            assert_eq!(policy.premium_amount, premium + premium / 2);
+
+            // Once the grace period has run out renewal is refused
+            env.ledger().with_mut(|li| li.timestamp = 50 * 86400 + 1);
+            let result = PolicyContract::renew_policy(env.clone(), holder.clone(), policy_id, 15);
+            assert_eq!(result, Err(ContractError::GracePeriodElapsed));
         });
     }
 
     #[test]
-    fn test_state_based_access_control() {
+    fn test_lapse_and_reinstatement() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            let token = setup_premium_token(&env, &admin, &holder);
+            let token_client = soroban_sdk::token::Client::new(&env, &token);
+            PolicyContract::set_grace_period(env.clone(), admin.clone(), 5 * 86400).unwrap();
+            PolicyContract::set_reinstatement_fee(env.clone(), admin.clone(), 1_000).unwrap();
 
-            let coverage = MIN_COVERAGE_AMOUNT + 1000;
-            let premium = MIN_PREMIUM_AMOUNT + 100;
-            let duration = 30;
-
+            let premium = 3_000_000;
             let policy_id = PolicyContract::issue_policy(
                 env.clone(),
                 manager.clone(),
                 holder.clone(),
-                coverage,
+                MIN_COVERAGE_AMOUNT + 1000,
                 premium,
-                duration,
+                30,
                 false,
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
-            // Cancel the policy
-            PolicyContract::cancel_policy(env.clone(), admin.clone(), policy_id).unwrap();
+            env.ledger().with_mut(|li| li.timestamp = 33 * 86400);
+            assert_eq!(
+                PolicyContract::lapse_policy(env.clone(), policy_id),
+                Err(ContractError::GracePeriodNotElapsed)
+            );
 
-            // Try to cancel again - should fail due to state
-            let result = PolicyContract::cancel_policy(env.clone(), admin.clone(), policy_id);
-            assert_eq!(result, Err(ContractError::InvalidStateTransition));
+            env.ledger().with_mut(|li| li.timestamp = 40 * 86400);
+            PolicyContract::lapse_policy(env.clone(), policy_id).unwrap();
+            assert_eq!(
+                PolicyContract::get_policy_state(env.clone(), policy_id).unwrap(),
+                PolicyState::LAPSED
+            );
+            assert_eq!(PolicyContract::get_active_policy_count(env.clone()), 0);
+
+            // Reinstate for 30 days: premium plus a 10% reinstatement fee
+            let balance_before = token_client.balance(&holder);
+            PolicyContract::reinstate_policy(env.clone(), holder.clone(), policy_id, 30).unwrap();
+            assert_eq!(balance_before - token_client.balance(&holder), 3_300_000);
+            assert_eq!(PolicyContract::get_active_policy_count(env.clone()), 1);
+
+            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
+            assert_eq!(policy.state(), PolicyState::ACTIVE);
+            assert_eq!(policy.start_time, 40 * 86400);
+            assert_eq!(policy.end_time, 70 * 86400);
+
+            // Losses during the original term and after reinstatement are covered;
+            // losses inside the lapse window are not
+            assert!(PolicyContract::get_policy_terms_at(env.clone(), policy_id, 10 * 86400).is_ok());
+            assert!(PolicyContract::get_policy_terms_at(env.clone(), policy_id, 50 * 86400).is_ok());
+            assert_eq!(
+                PolicyContract::get_policy_terms_at(env.clone(), policy_id, 36 * 86400),
+                Err(ContractError::PolicyLapsed)
+            );
+
+            let history = PolicyContract::get_lapse_history(env.clone(), policy_id);
+            assert_eq!(history.len(), 1);
+            assert_eq!(
+                history.get(0).unwrap(),
+                LapseWindow {
+                    lapsed_at: 35 * 86400,
+                    reinstated_at: Some(40 * 86400),
+                }
+            );
         });
     }
 
     #[test]
-    fn test_policy_renewal() {
+    fn test_keeper_sweep_renews_expires_and_lapses() {
         let env = Env::default();
         with_contract_env(&env, || {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let other_holder = Address::generate(&env);
+            let keeper = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+            let treasury = env.register_contract(None, MockTreasury);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
                 .unwrap();
+            let token = setup_premium_token(&env, &admin, &holder);
+            soroban_sdk::token::StellarAssetClient::new(&env, &token)
+                .mint(&other_holder, &(MAX_PREMIUM_AMOUNT * 10));
+            PolicyContract::set_treasury(env.clone(), admin.clone(), treasury).unwrap();
+            PolicyContract::set_grace_period(env.clone(), admin.clone(), 5 * 86400).unwrap();
+            PolicyContract::set_keeper_bounty(env.clone(), admin.clone(), 10).unwrap();
+
+            let issue = |holder: &Address, auto_renew: bool| {
+                PolicyContract::issue_policy(
+                    env.clone(),
+                    manager.clone(),
+                    holder.clone(),
+                    MIN_COVERAGE_AMOUNT + 1000,
+                    3_000_000,
+                    30,
+                    auto_renew,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap()
+            };
+            let renewing = issue(&holder, true);
+            let expiring = issue(&other_holder, false);
+            let lapsing = issue(&other_holder, true);
+
+            // Only the first holder grants the allowance automated renewals draw on
+            soroban_sdk::token::Client::new(&env, &token).approve(
+                &holder,
+                &env.current_contract_address(),
+                &MAX_PREMIUM_AMOUNT,
+                &(env.ledger().sequence() + 1000),
+            );
 
-            let coverage = MIN_COVERAGE_AMOUNT + 1000;
-            let premium = MIN_PREMIUM_AMOUNT + 100;
-            let duration = 30;
-
-            let policy_id = PolicyContract::issue_policy(
-                env.clone(),
-                manager.clone(),
-                holder.clone(),
-                coverage,
-                premium,
-                duration,
-                true, // Auto renew enabled
-                None,
-                None,
-                None,
-            )
-            .unwrap();
-
-            // Renew by holder
-            PolicyContract::renew_policy(env.clone(), holder.clone(), policy_id, 30).unwrap();
-
-            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
-            // Duration was 30 days, renewed for 30 days. Total duration from start should be 60 days.
-            assert_eq!(policy.end_time, policy.start_time + 60 * 86400);
-
-            // Renew by manager (allowed because auto_renew is true)
-            PolicyContract::renew_policy(env.clone(), manager.clone(), policy_id, 30).unwrap();
-            let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
-            assert_eq!(policy.end_time, policy.start_time + 90 * 86400);
-
-            // Disable auto renew
-            PolicyContract::set_auto_renew(env.clone(), holder.clone(), policy_id, false).unwrap();
+            // Within the grace period: only the funded auto-renew policy is acted on
+            env.ledger().with_mut(|li| li.timestamp = 32 * 86400);
+            let result = PolicyContract::sweep_policies(env.clone(), keeper.clone(), 0, 25).unwrap();
+            assert_eq!(result.processed, 3);
+            assert_eq!(result.renewed, 1);
+            assert_eq!(result.expired + result.lapsed, 0);
+            assert_eq!(result.bounty_paid, 10);
+            assert_eq!(result.next_cursor, None);
+
+            let policy = PolicyContract::get_policy(env.clone(), renewing).unwrap();
+            assert_eq!(policy.end_time, 60 * 86400);
+
+            // After the grace period, sweep in pages of two
+            env.ledger().with_mut(|li| li.timestamp = 36 * 86400);
+            let result = PolicyContract::sweep_policies(env.clone(), keeper.clone(), 0, 2).unwrap();
+            assert_eq!(result.expired, 1);
+            assert_eq!(result.next_cursor, Some(1));
+
+            let result = PolicyContract::sweep_policies(env.clone(), keeper.clone(), 1, 2).unwrap();
+            assert_eq!(result.lapsed, 1);
+            assert_eq!(result.next_cursor, None);
+
+            assert_eq!(
+                PolicyContract::get_policy_state(env.clone(), expiring).unwrap(),
+                PolicyState::EXPIRED
+            );
+            assert_eq!(
+                PolicyContract::get_policy_state(env.clone(), lapsing).unwrap(),
+                PolicyState::LAPSED
+            );
+            assert_eq!(PolicyContract::get_active_policy_count(env.clone()), 1);
 
-            // Renew by manager (should fail)
-            let res = PolicyContract::renew_policy(env.clone(), manager.clone(), policy_id, 30);
-            assert_eq!(res, Err(ContractError::Unauthorized));
+            let result = PolicyContract::sweep_policies(env.clone(), keeper.clone(), 0, 26);
+            assert_eq!(result, Err(ContractError::InvalidInput));
         });
     }
 
@@ -1758,11 +5547,12 @@ mod tests {
             let admin = Address::generate(&env);
             let manager = Address::generate(&env);
             let holder = Address::generate(&env);
-            let risk_pool = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
             let new_risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
             PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+            setup_premium_token(&env, &admin, &holder);
 
             // 1. Test Update Risk Pool
             PolicyContract::update_risk_pool(env.clone(), admin.clone(), new_risk_pool.clone()).unwrap();
@@ -1781,6 +5571,10 @@ mod tests {
                 premium,
                 duration,
                 false,
+                None,
+                None,
+                None,
+                None,
             ).unwrap();
 
             PolicyContract::snapshot_policy(env.clone(), admin.clone(), policy_id).unwrap();
@@ -1801,4 +5595,78 @@ mod tests {
             assert_eq!(active_count_final, 0);
         });
     }
+
+    #[test]
+    fn test_issue_policy_collects_premium() {
+        let env = Env::default();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+            let treasury = env.register_contract(None, MockTreasury);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+            PolicyContract::set_treasury(env.clone(), admin.clone(), treasury.clone()).unwrap();
+            let token = setup_premium_token(&env, &admin, &holder);
+            let token_client = soroban_sdk::token::Client::new(&env, &token);
+            let starting_balance = token_client.balance(&holder);
+
+            let premium = 1_000_000;
+            PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MIN_COVERAGE_AMOUNT + 1000,
+                premium,
+                30,
+                false,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            // 5% treasury fee, remainder to the risk pool
+            assert_eq!(token_client.balance(&holder), starting_balance - premium);
+            assert_eq!(token_client.balance(&treasury), 50_000);
+            assert_eq!(token_client.balance(&risk_pool), 950_000);
+        });
+    }
+
+    #[test]
+    fn test_issue_policy_fails_without_premium_token() {
+        let env = Env::default();
+        env.mock_all_auths();
+        with_contract_env(&env, || {
+            let admin = Address::generate(&env);
+            let manager = Address::generate(&env);
+            let holder = Address::generate(&env);
+            let risk_pool = env.register_contract(None, MockRiskPool);
+
+            PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+                .unwrap();
+
+            let result = PolicyContract::issue_policy(
+                env.clone(),
+                manager.clone(),
+                holder.clone(),
+                MIN_COVERAGE_AMOUNT + 1000,
+                MIN_PREMIUM_AMOUNT + 100,
+                30,
+                false,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            assert_eq!(result, Err(ContractError::UnsupportedAsset));
+            assert_eq!(PolicyContract::get_policy_count(env.clone()), 0);
+        });
+    }
 }
@@ -580,39 +580,12 @@ impl ProductTemplateContract {
             return Err(ContractError::Paused);
         }
         
-        // Get template
-        let template: ProductTemplate = env.storage().persistent().get(&(TEMPLATE, template_id))
-            .ok_or(ContractError::NotFound)?;
-        
-        // Template must be active
-        if template.status != TemplateStatus::Active {
-            return Err(ContractError::InvalidTemplateStatus);
-        }
-        
-        // Validate coverage amount
-        if coverage_amount < template.min_coverage || coverage_amount > template.max_coverage {
-            return Err(ContractError::InvalidInput);
-        }
-        
-        // Validate duration
-        if duration_days < template.min_duration_days || duration_days > template.max_duration_days {
-            return Err(ContractError::InvalidInput);
-        }
-        
-        // Validate deductible
-        if deductible < template.min_deductible || deductible > template.max_deductible {
-            return Err(ContractError::InvalidInput);
-        }
-        
-        // Validate custom parameters
-        Self::validate_custom_parameters(&env, &template, &custom_values)?;
-        
-        // Calculate premium based on template model
-        let premium_amount = Self::calculate_premium(
+        let premium_amount = Self::price_policy(
             &env,
-            &template,
+            template_id,
             coverage_amount,
             duration_days,
+            deductible,
             &custom_values,
         )?;
         
@@ -650,6 +623,75 @@ impl ProductTemplateContract {
         Ok(policy_id)
     }
     
+    /// Price a policy against an active template without creating it.
+    /// Used by the policy contract to issue holder quotes.
+    pub fn quote_premium(
+        env: Env,
+        template_id: u64,
+        coverage_amount: i128,
+        duration_days: u32,
+        deductible: i128,
+        custom_values: Vec<CustomParamValue>,
+    ) -> Result<i128, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        
+        Self::price_policy(
+            &env,
+            template_id,
+            coverage_amount,
+            duration_days,
+            deductible,
+            &custom_values,
+        )
+    }
+    
+    fn price_policy(
+        env: &Env,
+        template_id: u64,
+        coverage_amount: i128,
+        duration_days: u32,
+        deductible: i128,
+        custom_values: &Vec<CustomParamValue>,
+    ) -> Result<i128, ContractError> {
+        // Get template
+        let template: ProductTemplate = env.storage().persistent().get(&(TEMPLATE, template_id))
+            .ok_or(ContractError::NotFound)?;
+        
+        // Template must be active
+        if template.status != TemplateStatus::Active {
+            return Err(ContractError::InvalidTemplateStatus);
+        }
+        
+        // Validate coverage amount
+        if coverage_amount < template.min_coverage || coverage_amount > template.max_coverage {
+            return Err(ContractError::InvalidInput);
+        }
+        
+        // Validate duration
+        if duration_days < template.min_duration_days || duration_days > template.max_duration_days {
+            return Err(ContractError::InvalidInput);
+        }
+        
+        // Validate deductible
+        if deductible < template.min_deductible || deductible > template.max_deductible {
+            return Err(ContractError::InvalidInput);
+        }
+        
+        // Validate custom parameters
+        Self::validate_custom_parameters(env, &template, custom_values)?;
+        
+        // Calculate premium based on template model
+        Self::calculate_premium(
+            env,
+            &template,
+            coverage_amount,
+            duration_days,
+            custom_values,
+        )
+    }
+    
     fn validate_custom_parameters(
         env: &Env,
         template: &ProductTemplate,
@@ -738,6 +738,56 @@ mod tests {
         assert_eq!(policy.deductible, 100000);
     }
     
+    #[test]
+    fn test_quote_premium_matches_created_policy() {
+        let (env, admin, governance) = setup_test_env();
+        initialize_contract(&env, &admin, &governance);
+        
+        let creator = Address::generate(&env);
+        let holder = Address::generate(&env);
+        let template_id = create_test_template(&env, &creator);
+        
+        // Quotes require an active template
+        let result = ProductTemplateContract::quote_premium(
+            env.clone(),
+            template_id,
+            10000000,
+            90,
+            100000,
+            Vec::new(&env),
+        );
+        assert_eq!(result, Err(ContractError::InvalidTemplateStatus));
+        
+        ProductTemplateContract::submit_template_for_review(env.clone(), creator.clone(), template_id).unwrap();
+        ProductTemplateContract::change_template_status(env.clone(), admin.clone(), template_id, TemplateStatus::Approved).unwrap();
+        ProductTemplateContract::change_template_status(env.clone(), admin.clone(), template_id, TemplateStatus::Active).unwrap();
+        
+        let quoted = ProductTemplateContract::quote_premium(
+            env.clone(),
+            template_id,
+            10000000,
+            90,
+            100000,
+            Vec::new(&env),
+        ).unwrap();
+        
+        let policy_id = ProductTemplateContract::create_policy_from_template(
+            env.clone(),
+            holder.clone(),
+            template_id,
+            10000000,
+            90,
+            100000,
+            Vec::new(&env),
+        ).unwrap();
+        
+        let policy = ProductTemplateContract::get_template_policy(env.clone(), policy_id).unwrap();
+        assert_eq!(policy.premium_amount, quoted);
+        
+        // Quoting does not create policies
+        assert_eq!(ProductTemplateContract::get_template_policy_count(env.clone()).unwrap(), 1);
+    }
+    
     #[test]
     fn test_create_policy_from_template_invalid_status() {
         let (env, admin, governance) = setup_test_env();
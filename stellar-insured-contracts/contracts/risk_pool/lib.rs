@@ -1,6 +1,7 @@
 #![no_std]
 use soroban_sdk::{contract, contracterror, contractimpl, Address, Env, Symbol};
-use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, Env, Symbol, Vec};
+use soroban_sdk::{IntoVal, Val};
 
 // Import authorization from the common library
 use insurance_contracts::authorization::{
@@ -11,6 +12,9 @@ use insurance_contracts::authorization::{
 // Import invariant checks and error types
 use insurance_invariants::{InvariantError, ProtocolInvariants};
 
+// Import token custody helpers
+use insurance_contracts::token::{self, TokenError};
+
 // Import gas optimization utilities
 use insurance_contracts::gas_optimization::{GasOptimizer, PerformanceMonitor};
 
@@ -26,6 +30,35 @@ const POOL_STATS: Symbol = Symbol::short("POOL_ST");
 const PROVIDER: Symbol = Symbol::short("PROVIDER");
 const RESERVED_TOTAL: Symbol = Symbol::short("RSV_TOT");
 const CLAIM_RESERVATION: Symbol = Symbol::short("CLM_RSV");
+const WITHDRAWAL_CONFIG: Symbol = Symbol::short("WD_CFG");
+const WITHDRAWAL: Symbol = Symbol::short("WD_REQ");
+const WITHDRAWAL_COUNTER: Symbol = Symbol::short("WD_CNT");
+const WITHDRAWAL_QUEUE: Symbol = Symbol::short("WD_QUEUE");
+const PENDING_WITHDRAWAL: Symbol = Symbol::short("WD_PEND");
+const LP_SHARES: Symbol = Symbol::short("LP_SHR");
+const TRANCHE_CONFIG: Symbol = Symbol::short("TR_CFG");
+const TRANCHE: Symbol = Symbol::short("TRANCHE");
+const TREATIES: Symbol = Symbol::short("TREATIES");
+const CESSION: Symbol = Symbol::short("CESSION");
+const ASSUMED: Symbol = Symbol::short("ASSUMED");
+const RETENTION_USED: Symbol = Symbol::short("RET_USED");
+const CLAIM_INCURRED: Symbol = Symbol::short("CLM_INC");
+const LOCKED_SHARES: Symbol = Symbol::short("LP_LOCK");
+const SHARE_ALLOWANCE: Symbol = Symbol::short("LP_ALW");
+const CUSTODY: Symbol = Symbol::short("CUSTODY");
+const CUSTODY_ASSETS: Symbol = Symbol::short("CST_AST");
+
+/// Basis points denominator for utilization
+const BASIS_POINTS: i128 = 10_000;
+
+/// Fixed-point scale the share price is quoted in (7 decimals, like stroops)
+const SHARE_PRICE_SCALE: i128 = 10_000_000;
+
+/// Tranche every pool starts with; untranched deposits and withdrawals use it
+const DEFAULT_TRANCHE: u32 = 0;
+
+/// How many reinsurers deep a new treaty's chain of cessions is followed looking for a cycle
+const MAX_CESSION_DEPTH: u32 = 5;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -46,6 +79,10 @@ pub enum ContractError {
     LiquidityViolation = 100,
     InvalidAmount = 103,
     Overflow = 107,
+    UnsupportedAsset = 110,
+    TransferFailed = 111,
+    // Withdrawal errors
+    CooldownActive = 112,
 }
 
 impl From<insurance_contracts::authorization::AuthError> for ContractError {
@@ -78,6 +115,15 @@ impl From<InvariantError> for ContractError {
     }
 }
 
+impl From<TokenError> for ContractError {
+    fn from(err: TokenError) -> Self {
+        match err {
+            TokenError::UnsupportedAsset => ContractError::UnsupportedAsset,
+            TokenError::TransferFailed => ContractError::TransferFailed,
+        }
+    }
+}
+
 /// Structured view of risk pool statistics for frontend/indexer consumption.
 /// Contains both raw stats and derived metrics for efficient data transfer.
 #[contracttype]
@@ -100,6 +146,189 @@ pub struct RiskPoolStatsView {
     pub utilization_rate_bps: u32,
 }
 
+/// Cooldown, solvency buffer and utilization cap for LP withdrawals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalConfig {
+    /// Time between a withdrawal starting its cooldown and becoming completable
+    pub cooldown_secs: u64,
+    /// Liquidity kept on top of claim reservations that withdrawals may not touch
+    pub solvency_buffer: i128,
+    /// At or above this utilization new requests queue instead of starting their cooldown
+    pub max_utilization_bps: u32,
+}
+
+/// A class of LP capital and its place in the loss waterfall.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrancheConfig {
+    pub id: u32,
+    /// Share of premium income credited to the tranche, in basis points
+    pub premium_share_bps: u32,
+    /// Order in which the tranche absorbs claim losses; rank 0 takes the first loss
+    pub waterfall_rank: u32,
+}
+
+/// Capital and share supply of one tranche.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TrancheState {
+    /// Tranche NAV; the capital of all tranches adds up to the pool's total liquidity
+    pub capital: i128,
+    pub total_shares: i128,
+    pub premium_earned: i128,
+    pub losses_absorbed: i128,
+}
+
+/// A tranche's capital and how much of the reserved claims would land on it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrancheExposure {
+    pub config: TrancheConfig,
+    pub state: TrancheState,
+    /// Reserved claim liquidity the tranche absorbs if every reservation is paid out
+    pub reserved_exposure: i128,
+}
+
+/// A tranche's net asset value and the LP shares outstanding against it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolNav {
+    /// Capital owned by the tranche's LPs: deposits and premium income less losses and withdrawals
+    pub net_asset_value: i128,
+    pub total_shares: i128,
+    /// Value of one share, scaled by `SHARE_PRICE_SCALE`
+    pub share_price: i128,
+}
+
+/// How a treaty shares claims with the reinsurer.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TreatyKind {
+    /// The reinsurer takes `ceded_share_bps` of every claim from the first unit
+    QuotaShare,
+    /// The reinsurer pays the part of each claim above the retention
+    ExcessOfLoss,
+}
+
+/// A reinsurance treaty ceding risk to another risk pool contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReinsuranceTreaty {
+    pub id: u32,
+    pub kind: TreatyKind,
+    /// Risk pool contract that assumes the ceded risk
+    pub reinsurer: Address,
+    /// Quota share only: share of each claim ceded, in basis points
+    pub ceded_share_bps: u32,
+    /// Excess of loss only: claims at or below this amount stay entirely with the pool
+    pub retention: i128,
+    /// Excess of loss only: most the pool retains across all claims under the treaty;
+    /// once it is used up the reinsurer responds from the first unit of each claim
+    pub aggregate_retention: Option<i128>,
+    /// Most the reinsurer takes on a single claim
+    pub limit: i128,
+    /// Share of premium income forwarded to the reinsurer, in basis points
+    pub ceded_premium_bps: u32,
+}
+
+/// The part of one claim's reservation held by a reinsurer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cession {
+    pub treaty_id: u32,
+    pub reinsurer: Address,
+    pub kind: TreatyKind,
+    pub ceded_share_bps: u32,
+    /// Retention applied to this claim, after any aggregate retention already used
+    pub retention: i128,
+    pub limit: i128,
+    /// Still reserved with the reinsurer
+    pub reserved: i128,
+    /// Already paid out by the reinsurer
+    pub paid: i128,
+}
+
+/// Shares an owner has allowed a spender to move on its behalf.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareAllowance {
+    pub amount: i128,
+    /// Last ledger the allowance can be used in
+    pub expiration_ledger: u32,
+}
+
+/// On-chain token balance of one asset set against the pool's own record of it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustodyReconciliation {
+    pub asset: shared::types::Asset,
+    /// Balance the token contract reports for the pool
+    pub on_chain_balance: i128,
+    /// Balance the pool expects from the transfers it has made
+    pub recorded_balance: i128,
+    /// `on_chain_balance - recorded_balance`; positive means unaccounted surplus
+    pub drift: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WithdrawalStatus {
+    /// Waiting for utilization to fall below the cap
+    Queued,
+    /// Cooling down; completable from `available_at`
+    Pending,
+    Completed,
+    Cancelled,
+}
+
+/// A provider's request to take liquidity out of the pool.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRequest {
+    pub id: u64,
+    pub provider: Address,
+    pub tranche_id: u32,
+    /// Value requested, at the NAV when the request was made
+    pub amount: i128,
+    /// Shares locked for the request and burned when it completes.
+    /// The payout is their value at completion, so NAV moves during the cooldown are shared.
+    pub shares: i128,
+    /// Principal the request takes out of the provider balance, held as pending until the
+    /// request completes or is cancelled
+    pub principal: i128,
+    pub requested_at: u64,
+    /// Set once the request leaves the queue and its cooldown starts
+    pub available_at: Option<u64>,
+    pub status: WithdrawalStatus,
+}
+
+fn get_withdrawal_config(env: &Env) -> WithdrawalConfig {
+    env.storage()
+        .persistent()
+        .get(&WITHDRAWAL_CONFIG)
+        .unwrap_or(WithdrawalConfig {
+            cooldown_secs: 7 * 86_400,
+            solvency_buffer: 0,
+            max_utilization_bps: 8_000,
+        })
+}
+
+/// Share of pool liquidity reserved for claims, in basis points
+fn utilization_bps(env: &Env) -> Result<u32, ContractError> {
+    let stats: (i128, i128, i128, u64) =
+        env.storage().persistent().get(&POOL_STATS).ok_or(ContractError::NotFound)?;
+    let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
+    if stats.0 <= 0 {
+        return Ok(if reserved_total > 0 { BASIS_POINTS as u32 } else { 0 });
+    }
+    let utilization = reserved_total
+        .checked_mul(BASIS_POINTS)
+        .ok_or(ContractError::Overflow)?
+        / stats.0;
+    Ok(utilization.min(BASIS_POINTS) as u32)
+}
+
 fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError> {
     Ok(())
 }
@@ -114,6 +343,7 @@ fn set_paused(env: &Env, paused: bool) {
 
 /// I1: Check liquidity preservation invariant
 /// Ensures: total_liquidity >= reserved_for_claims
+/// Withdrawals are additionally held to `check_withdrawal_solvency` before they complete.
 fn check_liquidity_invariant(env: &Env) -> Result<(), ContractError> {
     let stats: (i128, i128, i128, u64) =
         env.storage().persistent().get(&POOL_STATS).ok_or(ContractError::NotFound)?;
@@ -128,6 +358,723 @@ fn check_liquidity_invariant(env: &Env) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// I1: A withdrawal of `amount` must leave reserved claims plus the solvency buffer covered,
+/// and the tranche paying it must still cover the reservations its waterfall rank carries
+fn check_withdrawal_solvency(env: &Env, tranche_id: u32, amount: i128) -> Result<(), ContractError> {
+    let exposure = RiskPoolContract::get_tranche_exposure(env.clone(), tranche_id)?;
+    let tranche_remaining = exposure
+        .state
+        .capital
+        .checked_sub(amount)
+        .ok_or(ContractError::Overflow)?;
+    if tranche_remaining < exposure.reserved_exposure {
+        return Err(ContractError::InsufficientFunds);
+    }
+
+    let stats: (i128, i128, i128, u64) =
+        env.storage().persistent().get(&POOL_STATS).ok_or(ContractError::NotFound)?;
+    let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
+    let config = get_withdrawal_config(env);
+
+    let required = reserved_total
+        .checked_add(config.solvency_buffer)
+        .ok_or(ContractError::Overflow)?;
+    let remaining = stats.0.checked_sub(amount).ok_or(ContractError::Overflow)?;
+    if remaining < required {
+        return Err(ContractError::InsufficientFunds);
+    }
+    Ok(())
+}
+
+fn get_tranche_configs(env: &Env) -> Vec<TrancheConfig> {
+    env.storage().persistent().get(&TRANCHE_CONFIG).unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_tranche_state(env: &Env, tranche_id: u32) -> Result<TrancheState, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&(TRANCHE, tranche_id))
+        .ok_or(ContractError::NotFound)
+}
+
+fn set_tranche_state(env: &Env, tranche_id: u32, state: &TrancheState) {
+    env.storage().persistent().set(&(TRANCHE, tranche_id), state);
+}
+
+/// Split premium income across tranches by their premium share.
+/// Rounding dust goes to the most senior tranche.
+fn allocate_premium(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let configs = get_tranche_configs(env);
+    let mut remaining = amount;
+    for (i, config) in configs.iter().enumerate() {
+        let share = if i as u32 + 1 == configs.len() {
+            remaining
+        } else {
+            amount
+                .checked_mul(config.premium_share_bps as i128)
+                .ok_or(ContractError::Overflow)?
+                / BASIS_POINTS
+        };
+        let mut state = get_tranche_state(env, config.id)?;
+        state.capital = state.capital.checked_add(share).ok_or(ContractError::Overflow)?;
+        state.premium_earned = state.premium_earned.checked_add(share).ok_or(ContractError::Overflow)?;
+        set_tranche_state(env, config.id, &state);
+        remaining -= share;
+    }
+    Ok(())
+}
+
+/// Take a refunded premium back from the tranches it was credited to.
+/// Whatever a tranche can no longer cover falls through the loss waterfall.
+fn reverse_premium(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let configs = get_tranche_configs(env);
+    let mut remaining = amount;
+    let mut shortfall = 0i128;
+    for (i, config) in configs.iter().enumerate() {
+        let share = if i as u32 + 1 == configs.len() {
+            remaining
+        } else {
+            amount
+                .checked_mul(config.premium_share_bps as i128)
+                .ok_or(ContractError::Overflow)?
+                / BASIS_POINTS
+        };
+        let mut state = get_tranche_state(env, config.id)?;
+        let taken = share.min(state.capital);
+        state.capital -= taken;
+        state.premium_earned = state.premium_earned.checked_sub(taken).ok_or(ContractError::Overflow)?;
+        set_tranche_state(env, config.id, &state);
+        shortfall += share - taken;
+        remaining -= share;
+    }
+    if shortfall > 0 {
+        apply_loss(env, shortfall)?;
+    }
+    Ok(())
+}
+
+/// Apply a loss to the tranches in waterfall order; senior capital is only touched
+/// once every junior tranche is exhausted.
+fn apply_loss(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let mut remaining = amount;
+    for config in get_tranche_configs(env).iter() {
+        if remaining == 0 {
+            break;
+        }
+        let mut state = get_tranche_state(env, config.id)?;
+        let absorbed = remaining.min(state.capital);
+        if absorbed == 0 {
+            continue;
+        }
+        state.capital -= absorbed;
+        state.losses_absorbed = state.losses_absorbed.checked_add(absorbed).ok_or(ContractError::Overflow)?;
+        set_tranche_state(env, config.id, &state);
+        remaining -= absorbed;
+
+        env.events().publish(
+            (Symbol::new(env, "tranche_loss"), config.id),
+            (absorbed, state.capital),
+        );
+    }
+    if remaining > 0 {
+        return Err(ContractError::InsufficientFunds);
+    }
+    Ok(())
+}
+
+fn share_balance(env: &Env, tranche_id: u32, owner: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(LP_SHARES, tranche_id, owner.clone()))
+        .unwrap_or(0i128)
+}
+
+fn locked_shares(env: &Env, tranche_id: u32, owner: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(LOCKED_SHARES, tranche_id, owner.clone()))
+        .unwrap_or(0i128)
+}
+
+/// Shares of `tranche_id` worth `amount` at its current NAV. Rounds up when the shares
+/// are given up, so rounding never favours the holder over the rest of the tranche.
+fn shares_for_amount(
+    env: &Env,
+    tranche_id: u32,
+    amount: i128,
+    round_up: bool,
+) -> Result<i128, ContractError> {
+    let state = get_tranche_state(env, tranche_id)?;
+    if state.total_shares == 0 {
+        // The first deposit prices shares at par
+        return Ok(amount);
+    }
+    if state.capital <= 0 {
+        return Err(ContractError::InvalidState);
+    }
+    let product = amount.checked_mul(state.total_shares).ok_or(ContractError::Overflow)?;
+    let shares = product / state.capital;
+    Ok(if round_up && product % state.capital != 0 { shares + 1 } else { shares })
+}
+
+/// Value of `shares` of `tranche_id` at its current NAV, rounded down
+fn amount_for_shares(env: &Env, tranche_id: u32, shares: i128) -> Result<i128, ContractError> {
+    let state = get_tranche_state(env, tranche_id)?;
+    if state.total_shares == 0 {
+        return Ok(0);
+    }
+    Ok(shares.checked_mul(state.capital).ok_or(ContractError::Overflow)? / state.total_shares)
+}
+
+fn set_share_balance(env: &Env, tranche_id: u32, owner: &Address, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&(LP_SHARES, tranche_id, owner.clone()), &balance);
+}
+
+/// Mint shares against capital just added to the tranche
+fn mint_shares(
+    env: &Env,
+    tranche_id: u32,
+    to: &Address,
+    shares: i128,
+    capital: i128,
+) -> Result<(), ContractError> {
+    let mut state = get_tranche_state(env, tranche_id)?;
+    state.capital = state.capital.checked_add(capital).ok_or(ContractError::Overflow)?;
+    state.total_shares = state.total_shares.checked_add(shares).ok_or(ContractError::Overflow)?;
+    set_tranche_state(env, tranche_id, &state);
+
+    let balance = share_balance(env, tranche_id, to).checked_add(shares).ok_or(ContractError::Overflow)?;
+    set_share_balance(env, tranche_id, to, balance);
+    Ok(())
+}
+
+/// Burn shares against capital just paid out of the tranche
+fn burn_shares(
+    env: &Env,
+    tranche_id: u32,
+    from: &Address,
+    shares: i128,
+    capital: i128,
+) -> Result<(), ContractError> {
+    let balance = share_balance(env, tranche_id, from);
+    if balance < shares {
+        return Err(ContractError::InsufficientFunds);
+    }
+    let mut state = get_tranche_state(env, tranche_id)?;
+    state.capital = state.capital.checked_sub(capital).ok_or(ContractError::Overflow)?;
+    state.total_shares = state.total_shares.checked_sub(shares).ok_or(ContractError::Overflow)?;
+    set_tranche_state(env, tranche_id, &state);
+
+    set_share_balance(env, tranche_id, from, balance - shares);
+    Ok(())
+}
+
+/// Move unlocked shares between holders
+fn move_shares(
+    env: &Env,
+    tranche_id: u32,
+    from: &Address,
+    to: &Address,
+    shares: i128,
+) -> Result<(), ContractError> {
+    validate_amount(shares)?;
+    let balance = share_balance(env, tranche_id, from);
+    let unlocked = balance
+        .checked_sub(locked_shares(env, tranche_id, from))
+        .ok_or(ContractError::Overflow)?;
+    if unlocked < shares {
+        return Err(ContractError::InsufficientFunds);
+    }
+    let principal = principal_for_shares(env, tranche_id, from, shares)?;
+    set_share_balance(env, tranche_id, from, balance - shares);
+    let to_balance = share_balance(env, tranche_id, to).checked_add(shares).ok_or(ContractError::Overflow)?;
+    set_share_balance(env, tranche_id, to, to_balance);
+
+    // The principal behind the shares follows them to the new holder
+    if principal > 0 {
+        OptimizedRiskPool::update_provider_info_optimized(env, from, -principal, 0)?;
+        if !env.storage().persistent().has(&(PROVIDER, to.clone())) {
+            env.storage()
+                .persistent()
+                .set(&(PROVIDER, to.clone()), &(0i128, 0i128, env.ledger().timestamp()));
+            OptimizedRiskPool::update_pool_stats_optimized(env, 0, 0, 0, 1)?;
+        }
+        OptimizedRiskPool::update_provider_info_optimized(env, to, principal, 0)?;
+    }
+    Ok(())
+}
+
+/// Share of `owner`'s principal not pending withdrawal that backs `shares` of `tranche_id`,
+/// pro rata to their value against the value of all the owner's unlocked shares
+fn principal_for_shares(
+    env: &Env,
+    tranche_id: u32,
+    owner: &Address,
+    shares: i128,
+) -> Result<i128, ContractError> {
+    let free_principal = match OptimizedRiskPool::get_provider_info_optimized(env, owner) {
+        Ok(info) => OptimizedRiskPool::from_compact_amount(info.balance - info.pending_withdrawal),
+        Err(_) => return Ok(0),
+    };
+    if free_principal <= 0 {
+        return Ok(0);
+    }
+
+    let mut unlocked_value: i128 = 0;
+    for tranche in get_tranche_configs(env).iter() {
+        let unlocked = share_balance(env, tranche.id, owner)
+            .checked_sub(locked_shares(env, tranche.id, owner))
+            .ok_or(ContractError::Overflow)?;
+        unlocked_value = unlocked_value
+            .checked_add(amount_for_shares(env, tranche.id, unlocked)?)
+            .ok_or(ContractError::Overflow)?;
+    }
+    if unlocked_value <= 0 {
+        return Ok(0);
+    }
+
+    let moved_value = amount_for_shares(env, tranche_id, shares)?;
+    Ok(free_principal
+        .checked_mul(moved_value)
+        .ok_or(ContractError::Overflow)?
+        / unlocked_value)
+}
+
+/// Track shares held back for withdrawals that have not completed yet
+fn adjust_locked_shares(
+    env: &Env,
+    tranche_id: u32,
+    owner: &Address,
+    change: i128,
+) -> Result<(), ContractError> {
+    let locked = locked_shares(env, tranche_id, owner)
+        .checked_add(change)
+        .ok_or(ContractError::Overflow)?;
+    if locked < 0 || locked > share_balance(env, tranche_id, owner) {
+        return Err(ContractError::InvalidState);
+    }
+    env.storage()
+        .persistent()
+        .set(&(LOCKED_SHARES, tranche_id, owner.clone()), &locked);
+    Ok(())
+}
+
+/// Add `asset` to the set of assets `reconcile_balances` checks
+fn track_custody_asset(env: &Env, asset: &shared::types::Asset) {
+    let mut assets: Vec<shared::types::Asset> =
+        env.storage().persistent().get(&CUSTODY_ASSETS).unwrap_or(Vec::new(env));
+    if !assets.contains(asset) {
+        assets.push_back(asset.clone());
+        env.storage().persistent().set(&CUSTODY_ASSETS, &assets);
+    }
+}
+
+/// Apply `change` to the pool's recorded holdings of `asset` after a token transfer
+fn record_custody(env: &Env, asset: &shared::types::Asset, change: i128) -> Result<(), ContractError> {
+    let key = (CUSTODY, asset.clone());
+    let recorded: i128 = env.storage().persistent().get(&key).unwrap_or(0i128);
+    let updated = recorded.checked_add(change).ok_or(ContractError::Overflow)?;
+    if updated < 0 {
+        return Err(ContractError::InsufficientFunds);
+    }
+    env.storage().persistent().set(&key, &updated);
+    track_custody_asset(env, asset);
+    Ok(())
+}
+
+/// Reserve `amount` of free liquidity under `key`, returning the new reserved total
+fn reserve_under<K>(env: &Env, key: &K, amount: i128) -> Result<i128, ContractError>
+where
+    K: IntoVal<Env, Val>,
+{
+    let stats: (i128, i128, i128, u64) =
+        env.storage().persistent().get(&POOL_STATS).ok_or(ContractError::NotFound)?;
+
+    let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
+
+    let available = stats.0.checked_sub(reserved_total).ok_or(ContractError::Overflow)?;
+    if available < amount {
+        return Err(ContractError::InsufficientFunds);
+    }
+
+    // Safe arithmetic for reservation
+    let new_reserved_total = reserved_total.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+    env.storage().persistent().set(&RESERVED_TOTAL, &new_reserved_total);
+    env.storage().persistent().set(key, &amount);
+
+    // I1: Assert liquidity invariant holds after reservation
+    check_liquidity_invariant(env)?;
+
+    Ok(new_reserved_total)
+}
+
+/// Move the reservation under `key` to `new_amount`; zero releases it entirely.
+/// Returns the previous amount and the new reserved total.
+fn adjust_under<K>(env: &Env, key: &K, new_amount: i128) -> Result<(i128, i128), ContractError>
+where
+    K: IntoVal<Env, Val>,
+{
+    if new_amount < 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let reserved: i128 = env.storage().persistent().get(key).ok_or(ContractError::NotFound)?;
+
+    let stats: (i128, i128, i128, u64) =
+        env.storage().persistent().get(&POOL_STATS).ok_or(ContractError::NotFound)?;
+    let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
+
+    let delta = new_amount.checked_sub(reserved).ok_or(ContractError::Overflow)?;
+    if delta > 0 {
+        let available = stats.0.checked_sub(reserved_total).ok_or(ContractError::Overflow)?;
+        if available < delta {
+            return Err(ContractError::InsufficientFunds);
+        }
+    }
+
+    let new_reserved_total = reserved_total.checked_add(delta).ok_or(ContractError::Overflow)?;
+    env.storage().persistent().set(&RESERVED_TOTAL, &new_reserved_total);
+    if new_amount == 0 {
+        env.storage().persistent().remove(key);
+    } else {
+        env.storage().persistent().set(key, &new_amount);
+    }
+
+    // I1: Assert liquidity invariant holds after the adjustment
+    check_liquidity_invariant(env)?;
+
+    Ok((reserved, new_reserved_total))
+}
+
+/// Pay `amount` of the reservation under `key` out as a claim, sending
+/// `transfer_amount` of `asset` to `recipient`. A payment in the base asset comes out of
+/// provider capital; one in another asset is drawn from that asset's own holdings and
+/// only releases the reservation.
+fn pay_reserved<K>(
+    env: &Env,
+    key: &K,
+    recipient: &Address,
+    asset: &shared::types::Asset,
+    amount: i128,
+    transfer_amount: i128,
+) -> Result<(), ContractError>
+where
+    K: IntoVal<Env, Val>,
+{
+    let mut stats: (i128, i128, i128, u64) =
+        env.storage().persistent().get(&POOL_STATS).ok_or(ContractError::NotFound)?;
+
+    let mut reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
+
+    let reserved: i128 = env.storage().persistent().get(key).ok_or(ContractError::NotFound)?;
+
+    if reserved <= 0 {
+        return Err(ContractError::InvalidState);
+    }
+    if amount > reserved {
+        return Err(ContractError::InsufficientFunds);
+    }
+    if reserved_total < amount {
+        return Err(ContractError::InvalidState);
+    }
+    let base_asset = *asset == shared::types::Asset::Native;
+    if base_asset && stats.0 < amount {
+        return Err(ContractError::InsufficientFunds);
+    }
+
+    // Safe arithmetic for payout
+    reserved_total = reserved_total.checked_sub(amount).ok_or(ContractError::Overflow)?;
+    stats.1 = stats.1.checked_add(amount).ok_or(ContractError::Overflow)?;
+    if base_asset {
+        stats.0 = stats.0.checked_sub(amount).ok_or(ContractError::Overflow)?;
+
+        // The loss runs down the tranche waterfall, junior capital first
+        apply_loss(env, amount)?;
+    }
+
+    env.storage().persistent().set(&RESERVED_TOTAL, &reserved_total);
+    if amount == reserved {
+        env.storage().persistent().remove(key);
+    } else {
+        env.storage().persistent().set(key, &(reserved - amount));
+    }
+    env.storage().persistent().set(&POOL_STATS, &stats);
+
+    release_funds(env, asset, recipient, transfer_amount)?;
+
+    // I1: Assert liquidity invariant holds after payout
+    check_liquidity_invariant(env)
+}
+
+fn get_treaties(env: &Env) -> Vec<ReinsuranceTreaty> {
+    env.storage().persistent().get(&TREATIES).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Work out what each treaty takes of a new claim reservation. Every treaty in force gets a
+/// cession, even one taking nothing yet, so the terms applied to the claim are kept with it.
+fn compute_cessions(env: &Env, amount: i128) -> Result<Vec<Cession>, ContractError> {
+    let mut cessions = Vec::new(env);
+    for treaty in get_treaties(env).iter() {
+        let retention = match treaty.aggregate_retention {
+            Some(aggregate) => {
+                let used: i128 = env
+                    .storage()
+                    .persistent()
+                    .get(&(RETENTION_USED, treaty.id))
+                    .unwrap_or(0i128);
+                treaty.retention.min((aggregate - used).max(0))
+            }
+            None => treaty.retention,
+        };
+        cessions.push_back(Cession {
+            treaty_id: treaty.id,
+            reinsurer: treaty.reinsurer,
+            kind: treaty.kind,
+            ceded_share_bps: treaty.ceded_share_bps,
+            retention,
+            limit: treaty.limit,
+            reserved: 0,
+            paid: 0,
+        });
+    }
+
+    let ceded = ceded_amounts(env, &cessions, amount)?;
+    for i in 0..cessions.len() {
+        let mut cession = cessions.get(i).ok_or(ContractError::InvalidState)?;
+        cession.reserved = ceded.get(i).unwrap_or(0);
+        cessions.set(i, cession);
+    }
+    Ok(cessions)
+}
+
+/// What each cession takes of a claim of `incurred` in total under the terms it was made on.
+/// Cessions apply in treaty order and together never take more than the claim.
+fn ceded_amounts(env: &Env, cessions: &Vec<Cession>, incurred: i128) -> Result<Vec<i128>, ContractError> {
+    let mut amounts = Vec::new(env);
+    let mut retained = incurred;
+    for cession in cessions.iter() {
+        let share = match cession.kind {
+            TreatyKind::QuotaShare => incurred
+                .checked_mul(cession.ceded_share_bps as i128)
+                .ok_or(ContractError::Overflow)?
+                / BASIS_POINTS,
+            TreatyKind::ExcessOfLoss => (incurred - cession.retention).max(0),
+        };
+        let ceded = share.min(cession.limit).min(retained).max(0);
+        amounts.push_back(ceded);
+        retained -= ceded;
+    }
+    Ok(amounts)
+}
+
+/// Reservations each cession should hold once a claim's total becomes `incurred`, given what
+/// each has already paid; the pool keeps the rest of `reserved` itself
+fn cession_targets(
+    env: &Env,
+    cessions: &Vec<Cession>,
+    incurred: i128,
+    reserved: i128,
+) -> Result<Vec<i128>, ContractError> {
+    let due = ceded_amounts(env, cessions, incurred)?;
+    let mut targets = Vec::new(env);
+    let mut left = reserved;
+    for (i, cession) in cessions.iter().enumerate() {
+        let target = (due.get(i as u32).unwrap_or(0) - cession.paid).max(0).min(left);
+        targets.push_back(target);
+        left -= target;
+    }
+    Ok(targets)
+}
+
+/// Share a claim payment of `amount` between the pool and its cessions so cumulative
+/// payments follow the treaty layers: a payment still inside the retention is met by the
+/// pool alone. Returns the pool's part and each cession's part.
+fn allocate_payment(
+    env: &Env,
+    local: i128,
+    cessions: &Vec<Cession>,
+    paid_before: i128,
+    amount: i128,
+) -> Result<(i128, Vec<i128>), ContractError> {
+    let due = ceded_amounts(env, cessions, paid_before + amount)?;
+    let mut parts = Vec::new(env);
+    let mut left = amount;
+    for (i, cession) in cessions.iter().enumerate() {
+        let part = (due.get(i as u32).unwrap_or(0) - cession.paid)
+            .max(0)
+            .min(cession.reserved)
+            .min(left);
+        parts.push_back(part);
+        left -= part;
+    }
+
+    // The pool meets the rest; anything beyond its own reservation falls to the cessions
+    let local_part = left.min(local);
+    left -= local_part;
+    for i in 0..parts.len() {
+        if left == 0 {
+            break;
+        }
+        let cession = cessions.get(i).ok_or(ContractError::InvalidState)?;
+        let part = parts.get(i).unwrap_or(0);
+        let extra = (cession.reserved - part).min(left);
+        parts.set(i, part + extra);
+        left -= extra;
+    }
+    if left > 0 {
+        return Err(ContractError::InsufficientFunds);
+    }
+    Ok((local_part, parts))
+}
+
+/// Count a claim moving from `old_incurred` to `new_incurred` against the aggregate
+/// retention of each excess of loss treaty it was ceded under
+fn record_retention_use(env: &Env, cessions: &Vec<Cession>, old_incurred: i128, new_incurred: i128) {
+    for cession in cessions.iter() {
+        if cession.kind != TreatyKind::ExcessOfLoss {
+            continue;
+        }
+        let delta = new_incurred.min(cession.retention) - old_incurred.min(cession.retention);
+        if delta == 0 {
+            continue;
+        }
+        let key = (RETENTION_USED, cession.treaty_id);
+        let used: i128 = env.storage().persistent().get(&key).unwrap_or(0i128);
+        env.storage().persistent().set(&key, &(used + delta).max(0));
+    }
+}
+
+/// Whether risk ceded to `reinsurer` can find its way back to this pool through the
+/// reinsurer's own treaties. Chains deeper than `depth` are treated as cycles.
+fn cedes_back(env: &Env, reinsurer: &Address, depth: u32) -> bool {
+    if depth == 0 {
+        return true;
+    }
+    let treaties: Vec<ReinsuranceTreaty> = env.invoke_contract(
+        reinsurer,
+        &Symbol::new(env, "get_reinsurance_treaties"),
+        Vec::<Val>::new(env),
+    );
+    for treaty in treaties.iter() {
+        if treaty.reinsurer == env.current_contract_address()
+            || cedes_back(env, &treaty.reinsurer, depth - 1)
+        {
+            return true;
+        }
+    }
+    false
+}
+
+/// Split `amount` in proportion to `parts`; the largest part takes the rounding
+fn split_pro_rata(env: &Env, amount: i128, parts: &Vec<i128>) -> Result<Vec<i128>, ContractError> {
+    let mut total = 0i128;
+    let mut largest = 0u32;
+    for (i, part) in parts.iter().enumerate() {
+        total = total.checked_add(part).ok_or(ContractError::Overflow)?;
+        if part > parts.get(largest).unwrap_or(0) {
+            largest = i as u32;
+        }
+    }
+    if total <= 0 {
+        return Err(ContractError::InvalidState);
+    }
+
+    let mut shares = Vec::new(env);
+    let mut allocated = 0i128;
+    for part in parts.iter() {
+        let share = amount.checked_mul(part).ok_or(ContractError::Overflow)? / total;
+        shares.push_back(share);
+        allocated += share;
+    }
+    let remainder = amount - allocated;
+    shares.set(largest, shares.get(largest).unwrap_or(0) + remainder);
+    Ok(shares)
+}
+
+/// Forward each treaty's share of a premium to its reinsurer, returning the total ceded
+fn cede_premium(env: &Env, asset: &shared::types::Asset, amount: i128) -> Result<i128, ContractError> {
+    let this = env.current_contract_address();
+    let mut ceded_total = 0i128;
+    for treaty in get_treaties(env).iter() {
+        let ceded = amount
+            .checked_mul(treaty.ceded_premium_bps as i128)
+            .ok_or(ContractError::Overflow)?
+            / BASIS_POINTS;
+        if ceded <= 0 {
+            continue;
+        }
+        release_funds(env, asset, &treaty.reinsurer, ceded)?;
+        env.invoke_contract::<()>(
+            &treaty.reinsurer,
+            &Symbol::new(env, "credit_premium"),
+            (this.clone(), asset.clone(), ceded).into_val(env),
+        );
+        ceded_total = ceded_total.checked_add(ceded).ok_or(ContractError::Overflow)?;
+
+        env.events().publish(
+            (Symbol::new(env, "premium_ceded"), treaty.id),
+            (treaty.reinsurer, ceded, asset.clone()),
+        );
+    }
+    Ok(ceded_total)
+}
+
+/// Have each reinsurer refund its ceded share of a premium refund straight to `recipient`,
+/// returning the total they paid
+fn claw_back_premium(
+    env: &Env,
+    asset: &shared::types::Asset,
+    recipient: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    let this = env.current_contract_address();
+    let mut clawed_total = 0i128;
+    for treaty in get_treaties(env).iter() {
+        let clawed = amount
+            .checked_mul(treaty.ceded_premium_bps as i128)
+            .ok_or(ContractError::Overflow)?
+            / BASIS_POINTS;
+        if clawed <= 0 {
+            continue;
+        }
+        env.invoke_contract::<()>(
+            &treaty.reinsurer,
+            &Symbol::new(env, "refund_premium"),
+            (this.clone(), recipient.clone(), clawed, asset.clone()).into_val(env),
+        );
+        clawed_total = clawed_total.checked_add(clawed).ok_or(ContractError::Overflow)?;
+
+        env.events().publish(
+            (Symbol::new(env, "premium_clawed_back"), treaty.id),
+            (treaty.reinsurer, clawed, asset.clone()),
+        );
+    }
+    Ok(clawed_total)
+}
+
+/// Move `amount` of `asset` out of the pool to `recipient` and record it
+fn release_funds(
+    env: &Env,
+    asset: &shared::types::Asset,
+    recipient: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    token::transfer(env, asset, &env.current_contract_address(), recipient, amount)?;
+    record_custody(env, asset, -amount)
+}
+
+/// Provider capital and premiums are accounted in the pool's base asset only, so NAV,
+/// shares and withdrawals never mix assets
+fn require_base_asset(asset: &shared::types::Asset) -> Result<(), ContractError> {
+    if *asset != shared::types::Asset::Native {
+        return Err(ContractError::UnsupportedAsset);
+    }
+    Ok(())
+}
+
 /// I4: Validate amount is positive and within safe range
 fn validate_amount(amount: i128) -> Result<(), ContractError> {
     if amount <= 0 {
@@ -170,11 +1117,25 @@ impl RiskPoolContract {
         // Register claims contract as trusted for cross-contract calls
         register_trusted_contract(&env, &admin, &claims_contract)?;
 
-        env.storage().persistent().set(&CONFIG, &(xlm_token, min_provider_stake));
+        env.storage().persistent().set(&CONFIG, &(xlm_token.clone(), min_provider_stake));
+
+        // The XLM token backs the Native asset that liquidity is deposited in
+        token::set_asset_token(&env, &shared::types::Asset::Native, &xlm_token);
+        track_custody_asset(&env, &shared::types::Asset::Native);
 
         let stats = (0i128, 0i128, 0i128, 0u64);
         env.storage().persistent().set(&POOL_STATS, &stats);
 
+        // All capital starts in a single tranche that earns every premium
+        let mut tranches = Vec::new(&env);
+        tranches.push_back(TrancheConfig {
+            id: DEFAULT_TRANCHE,
+            premium_share_bps: BASIS_POINTS as u32,
+            waterfall_rank: 0,
+        });
+        env.storage().persistent().set(&TRANCHE_CONFIG, &tranches);
+        set_tranche_state(&env, DEFAULT_TRANCHE, &TrancheState::default());
+
         env.events().publish((Symbol::new(&env, "initialized"), ()), admin);
 
         Ok(())
@@ -185,17 +1146,31 @@ impl RiskPoolContract {
         provider: Address,
         amount: i128,
     ) -> Result<(), ContractError> {
-        // Use performance monitoring
-        PerformanceMonitor::track_operation(&env, "deposit_liquidity", || {
-            Self::deposit_liquidity_impl(env.clone(), provider.clone(), amount)
+        Self::deposit_to_tranche(env, provider, DEFAULT_TRANCHE, amount)
+    }
+
+    /// Deposit base-asset liquidity into one tranche, minting its shares at the tranche NAV
+    pub fn deposit_to_tranche(
+        env: Env,
+        provider: Address,
+        tranche_id: u32,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        // Use performance monitoring
+        PerformanceMonitor::track_operation(&env, "deposit_liquidity", || {
+            Self::deposit_liquidity_impl(env.clone(), provider.clone(), tranche_id, amount)
         })
     }
 
     fn deposit_liquidity_impl(
         env: Env,
         provider: Address,
+        tranche_id: u32,
         amount: i128,
     ) -> Result<(), ContractError> {
+        let asset = shared::types::Asset::Native;
+        provider.require_auth();
+
         if is_paused(&env) {
             return Err(ContractError::Paused);
         }
@@ -223,16 +1198,26 @@ impl RiskPoolContract {
             return Err(ContractError::InvalidInput);
         }
 
+        // Shares are priced at the tranche NAV before the deposit lands
+        let shares = shares_for_amount(&env, tranche_id, amount, false)?;
+        if shares <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        token::transfer(&env, &asset, &provider, &env.current_contract_address(), amount)?;
+        record_custody(&env, &asset, amount)?;
+
         // Use optimized update operations
         OptimizedRiskPool::update_provider_info_optimized(&env, &provider, amount, amount)?;
         OptimizedRiskPool::update_pool_stats_optimized(&env, amount, 0, amount, 0)?;
+        mint_shares(&env, tranche_id, &provider, shares, amount)?;
 
         // I1: Assert liquidity invariant holds after deposit
         check_liquidity_invariant(&env)?;
 
         env.events().publish(
             (Symbol::new(&env, "liquidity_deposited"), provider.clone()),
-            (amount, current_stake + amount),
+            (amount, current_stake + amount, asset, tranche_id, shares),
         );
 
         Ok(())
@@ -262,6 +1247,8 @@ impl RiskPoolContract {
         Ok(provider_info)
     }
 
+    /// Reserve liquidity for a claim. Parts of the claim covered by reinsurance treaties
+    /// are reserved with the reinsurers; the pool only reserves what it retains.
     pub fn reserve_liquidity(
         env: Env,
         caller_contract: Address,
@@ -279,133 +1266,1220 @@ impl RiskPoolContract {
         // I4: Amount Non-Negativity - amount must be positive
         validate_amount(amount)?;
 
-        if env.storage().persistent().has(&(CLAIM_RESERVATION, claim_id)) {
+        if env.storage().persistent().has(&(CLAIM_RESERVATION, claim_id))
+            || env.storage().persistent().has(&(CESSION, claim_id))
+        {
             return Err(ContractError::AlreadyExists);
         }
 
-        let stats: (i128, i128, i128, u64) =
+        let cessions = compute_cessions(&env, amount)?;
+        let mut retained = amount;
+        for cession in cessions.iter() {
+            retained -= cession.reserved;
+        }
+        record_retention_use(&env, &cessions, 0, amount);
+
+        let new_reserved_total = if retained > 0 {
+            reserve_under(&env, &(CLAIM_RESERVATION, claim_id), retained)?
+        } else {
+            env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128)
+        };
+
+        let this = env.current_contract_address();
+        for cession in cessions.iter() {
+            if cession.reserved == 0 {
+                continue;
+            }
+            env.invoke_contract::<()>(
+                &cession.reinsurer,
+                &Symbol::new(&env, "assume_reservation"),
+                (this.clone(), claim_id, cession.reserved).into_val(&env),
+            );
+
+            env.events().publish(
+                (Symbol::new(&env, "claim_ceded"), claim_id),
+                (cession.treaty_id, cession.reinsurer, cession.reserved),
+            );
+        }
+        if !cessions.is_empty() {
+            env.storage().persistent().set(&(CESSION, claim_id), &cessions);
+            env.storage().persistent().set(&(CLAIM_INCURRED, claim_id), &amount);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "liquidity_reserved"), claim_id),
+            (amount, new_reserved_total),
+        );
+
+        Ok(())
+    }
+
+    /// Move an existing claim reservation to `new_amount`, reserving more free liquidity or
+    /// releasing the difference back to the pool. Only callable by a trusted contract.
+    /// The ceded parts are worked out afresh under each cession's terms, so a claim that
+    /// falls back inside a retention is released by its reinsurer.
+    pub fn adjust_reservation(
+        env: Env,
+        caller_contract: Address,
+        claim_id: u64,
+        new_amount: i128,
+    ) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(new_amount)?;
+
+        let local_key = (CLAIM_RESERVATION, claim_id);
+        let local: Option<i128> = env.storage().persistent().get(&local_key);
+        let mut cessions: Vec<Cession> =
+            env.storage().persistent().get(&(CESSION, claim_id)).unwrap_or_else(|| Vec::new(&env));
+        if local.is_none() && cessions.is_empty() {
+            return Err(ContractError::NotFound);
+        }
+
+        let mut reserved = local.unwrap_or(0);
+        for cession in cessions.iter() {
+            reserved = reserved.checked_add(cession.reserved).ok_or(ContractError::Overflow)?;
+        }
+        let incurred: i128 = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_INCURRED, claim_id))
+            .unwrap_or(reserved);
+        let new_incurred = (incurred - reserved)
+            .checked_add(new_amount)
+            .ok_or(ContractError::Overflow)?;
+
+        let targets = cession_targets(&env, &cessions, new_incurred, new_amount)?;
+        let local_target = new_amount - targets.iter().sum::<i128>();
+        let new_reserved_total = if local.is_some() {
+            adjust_under(&env, &local_key, local_target)?.1
+        } else if local_target > 0 {
+            reserve_under(&env, &local_key, local_target)?
+        } else {
+            env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128)
+        };
+
+        let this = env.current_contract_address();
+        for i in 0..cessions.len() {
+            let mut cession = cessions.get(i).ok_or(ContractError::InvalidState)?;
+            let target = targets.get(i).unwrap_or(0);
+            if target == cession.reserved {
+                continue;
+            }
+            // A reinsurer with nothing reserved for the claim yet assumes a new reservation
+            let function = if cession.reserved == 0 {
+                "assume_reservation"
+            } else {
+                "adjust_assumed_reservation"
+            };
+            env.invoke_contract::<()>(
+                &cession.reinsurer,
+                &Symbol::new(&env, function),
+                (this.clone(), claim_id, target).into_val(&env),
+            );
+            cession.reserved = target;
+            cessions.set(i, cession);
+        }
+        if !cessions.is_empty() {
+            record_retention_use(&env, &cessions, incurred, new_incurred);
+            env.storage().persistent().set(&(CESSION, claim_id), &cessions);
+            env.storage().persistent().set(&(CLAIM_INCURRED, claim_id), &new_incurred);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "reservation_adjusted"), claim_id),
+            (reserved, new_amount, new_reserved_total),
+        );
+
+        Ok(())
+    }
+
+    pub fn payout_reserved_claim(
+        env: Env,
+        caller_contract: Address,
+        claim_id: u64,
+        recipient: Address,
+    ) -> Result<(), ContractError> {
+        // Default to Native asset for backward compatibility
+        Self::payout_reserved_claim_multi_asset(
+            env,
+            caller_contract,
+            claim_id,
+            recipient,
+            shared::types::Asset::Native,
+            None,
+            None,
+        )
+    }
+
+    /// Multi-asset version of payout_reserved_claim.
+    /// `amount` pays part of the reservation to `recipient`, leaving the rest reserved for
+    /// further payees; `None` pays whatever remains reserved for the claim.
+    /// `asset_amount` is the quantity of `payout_asset` transferred when the claim was converted
+    /// into another asset; `None` transfers the released amount itself.
+    /// Reinsurers pay their ceded share of each payment to `recipient` directly; payments are
+    /// met by the pool up to its retention before reinsurers pay the layers above it.
+    pub fn payout_reserved_claim_multi_asset(
+        env: Env,
+        caller_contract: Address,
+        claim_id: u64,
+        recipient: Address,
+        payout_asset: shared::types::Asset,
+        amount: Option<i128>,
+        asset_amount: Option<i128>,
+    ) -> Result<(), ContractError> {
+        // Verify that the caller is a trusted contract (e.g., claims contract)
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_address(&env, &recipient)?;
+
+        let local_key = (CLAIM_RESERVATION, claim_id);
+        let local: Option<i128> = env.storage().persistent().get(&local_key);
+        let mut cessions: Vec<Cession> =
+            env.storage().persistent().get(&(CESSION, claim_id)).unwrap_or_else(|| Vec::new(&env));
+        if local.is_none() && cessions.is_empty() {
+            return Err(ContractError::NotFound);
+        }
+
+        let mut reserved = local.unwrap_or(0);
+        for cession in cessions.iter() {
+            reserved = reserved.checked_add(cession.reserved).ok_or(ContractError::Overflow)?;
+        }
+        if reserved <= 0 {
+            return Err(ContractError::InvalidState);
+        }
+
+        let amount = amount.unwrap_or(reserved);
+        validate_amount(amount)?;
+        if amount > reserved {
+            return Err(ContractError::InsufficientFunds);
+        }
+
+        let transfer_amount = asset_amount.unwrap_or(amount);
+        validate_amount(transfer_amount)?;
+
+        let incurred: i128 = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_INCURRED, claim_id))
+            .unwrap_or(reserved);
+        let (local_amount, ceded_parts) =
+            allocate_payment(&env, local.unwrap_or(0), &cessions, incurred - reserved, amount)?;
+
+        // A converted payment is transferred in the same proportions
+        let mut amounts = Vec::new(&env);
+        amounts.push_back(local_amount);
+        amounts.append(&ceded_parts);
+        let transfers = split_pro_rata(&env, transfer_amount, &amounts)?;
+
+        if local_amount > 0 {
+            pay_reserved(
+                &env,
+                &local_key,
+                &recipient,
+                &payout_asset,
+                local_amount,
+                transfers.get(0).unwrap_or(0),
+            )?;
+        }
+
+        let this = env.current_contract_address();
+        for i in 0..cessions.len() {
+            let mut cession = cessions.get(i).ok_or(ContractError::InvalidState)?;
+            let part = amounts.get(i + 1).unwrap_or(0);
+            if part > 0 {
+                env.invoke_contract::<()>(
+                    &cession.reinsurer,
+                    &Symbol::new(&env, "pay_assumed_claim"),
+                    (
+                        this.clone(),
+                        claim_id,
+                        recipient.clone(),
+                        payout_asset.clone(),
+                        part,
+                        transfers.get(i + 1).unwrap_or(0),
+                    )
+                        .into_val(&env),
+                );
+                cession.reserved -= part;
+                cession.paid += part;
+
+                env.events().publish(
+                    (Symbol::new(&env, "claim_recovered"), claim_id),
+                    (cession.treaty_id, cession.reinsurer.clone(), part),
+                );
+            }
+            cessions.set(i, cession);
+        }
+        if !cessions.is_empty() {
+            env.storage().persistent().set(&(CESSION, claim_id), &cessions);
+        }
+
+        // Store payout asset information for tracking
+        env.storage().persistent().set(
+            &(Symbol::new(&env, "PAYOUT_ASSET"), claim_id),
+            &payout_asset,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "reserved_claim_payout"), claim_id),
+            (recipient, amount, payout_asset, transfer_amount),
+        );
+
+        Ok(())
+    }
+
+    /// Get the payout asset for a claim
+    pub fn get_claim_payout_asset(
+        env: Env,
+        claim_id: u64,
+    ) -> Result<shared::types::Asset, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(Symbol::new(&env, "PAYOUT_ASSET"), claim_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Balance of `asset` the pool holds and can pay claims from.
+    /// Fails with `UnsupportedAsset` when no token contract is registered for it.
+    pub fn get_asset_liquidity(
+        env: Env,
+        asset: shared::types::Asset,
+    ) -> Result<i128, ContractError> {
+        Ok(token::balance(&env, &asset, &env.current_contract_address())?)
+    }
+
+    /// Recorded holdings of `asset` free to pay `claim_id`: in the base asset, the liquidity
+    /// reserved for every other claim is held back. Other assets are only held as payout
+    /// reserves funded through `fund_payout_asset`.
+    pub fn get_payout_liquidity(
+        env: Env,
+        asset: shared::types::Asset,
+        claim_id: u64,
+    ) -> Result<i128, ContractError> {
+        let balance: i128 =
+            env.storage().persistent().get(&(CUSTODY, asset.clone())).unwrap_or(0i128);
+        if asset != shared::types::Asset::Native {
+            return Ok(balance);
+        }
+
+        let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
+        let own: i128 =
+            env.storage().persistent().get(&(CLAIM_RESERVATION, claim_id)).unwrap_or(0i128);
+        let held_for_others = reserved_total.checked_sub(own).ok_or(ContractError::Overflow)?;
+        Ok(balance.checked_sub(held_for_others).ok_or(ContractError::Overflow)?.max(0))
+    }
+
+    /// Balance of `asset` the pool expects to hold from the transfers it has recorded
+    pub fn get_recorded_balance(env: Env, asset: shared::types::Asset) -> i128 {
+        env.storage().persistent().get(&(CUSTODY, asset)).unwrap_or(0i128)
+    }
+
+    /// Compare the pool's on-chain token balances with its recorded holdings.
+    /// Emits `custody_drift` for every asset whose balances disagree; anyone may call it.
+    pub fn reconcile_balances(env: Env) -> Result<Vec<CustodyReconciliation>, ContractError> {
+        let assets: Vec<shared::types::Asset> =
+            env.storage().persistent().get(&CUSTODY_ASSETS).unwrap_or(Vec::new(&env));
+        let pool = env.current_contract_address();
+
+        let mut report = Vec::new(&env);
+        for asset in assets.iter() {
+            let on_chain_balance = token::balance(&env, &asset, &pool)?;
+            let recorded_balance: i128 =
+                env.storage().persistent().get(&(CUSTODY, asset.clone())).unwrap_or(0i128);
+            let drift = on_chain_balance
+                .checked_sub(recorded_balance)
+                .ok_or(ContractError::Overflow)?;
+
+            if drift != 0 {
+                env.events().publish(
+                    (Symbol::new(&env, "custody_drift"), asset.clone()),
+                    (on_chain_balance, recorded_balance, drift),
+                );
+            }
+
+            report.push_back(CustodyReconciliation {
+                asset,
+                on_chain_balance,
+                recorded_balance,
+                drift,
+            });
+        }
+
+        Ok(report)
+    }
+
+    /// Register the policy contract as a trusted caller for premium refunds (admin only)
+    pub fn register_policy_contract(
+        env: Env,
+        admin: Address,
+        policy_contract: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        register_trusted_contract(&env, &admin, &policy_contract)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "policy_contract_registered"), ()),
+            policy_contract,
+        );
+
+        Ok(())
+    }
+
+    /// Register the token contract backing an asset (admin only)
+    pub fn set_asset_token(
+        env: Env,
+        admin: Address,
+        asset: shared::types::Asset,
+        token_address: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        token::set_asset_token(&env, &asset, &token_address);
+        track_custody_asset(&env, &asset);
+
+        env.events().publish(
+            (Symbol::new(&env, "asset_token_set"), ()),
+            (asset, token_address),
+        );
+
+        Ok(())
+    }
+
+    /// Refund unearned premium to a policyholder on cancellation.
+    /// Only callable by a trusted policy contract or ceding pool. Reinsurers refund the share
+    /// of premium they were ceded; the pool's own part must come out of free liquidity.
+    pub fn refund_premium(
+        env: Env,
+        caller_contract: Address,
+        recipient: Address,
+        amount: i128,
+        asset: shared::types::Asset,
+    ) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        // I4: Amount Non-Negativity - amount must be positive
+        validate_amount(amount)?;
+        require_base_asset(&asset)?;
+
+        let mut stats: (i128, i128, i128, u64) =
             env.storage().persistent().get(&POOL_STATS).ok_or(ContractError::NotFound)?;
 
         let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
 
+        // Only the retained premium was credited here; reinsurers refund what they were ceded
+        let clawed = claw_back_premium(&env, &asset, &recipient, amount)?;
+        let retained = amount.checked_sub(clawed).ok_or(ContractError::Overflow)?;
+
+        // Refunds may never dip into liquidity reserved for claims
         let available = stats.0.checked_sub(reserved_total).ok_or(ContractError::Overflow)?;
-        if available < amount {
+        if available < retained {
             return Err(ContractError::InsufficientFunds);
         }
 
-        // Safe arithmetic for reservation
-        let new_reserved_total =
-            reserved_total.checked_add(amount).ok_or(ContractError::Overflow)?;
+        if retained > 0 {
+            stats.0 = stats.0.checked_sub(retained).ok_or(ContractError::Overflow)?;
+            env.storage().persistent().set(&POOL_STATS, &stats);
+            reverse_premium(&env, retained)?;
+
+            release_funds(&env, &asset, &recipient, retained)?;
+        }
+
+        // I1: Assert liquidity invariant holds after refund
+        check_liquidity_invariant(&env)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "premium_refunded"), recipient),
+            (amount, asset, stats.0, clawed),
+        );
+
+        Ok(())
+    }
+
+    /// Fund the pool's holdings of a non-base `asset` that claims may be paid in (admin only).
+    /// These holdings are not provider capital and pay claims settled in that asset.
+    pub fn fund_payout_asset(
+        env: Env,
+        admin: Address,
+        asset: shared::types::Asset,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        validate_amount(amount)?;
+        if asset == shared::types::Asset::Native {
+            return Err(ContractError::InvalidInput);
+        }
+
+        token::transfer(&env, &asset, &admin, &env.current_contract_address(), amount)?;
+        record_custody(&env, &asset, amount)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "payout_asset_funded"), admin),
+            (asset, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Credit premium a trusted policy contract or ceding pool has already transferred into
+    /// the pool. Each treaty's ceded share is forwarded to its reinsurer; the rest joins free
+    /// liquidity without belonging to any provider.
+    pub fn credit_premium(
+        env: Env,
+        caller_contract: Address,
+        asset: shared::types::Asset,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        // I4: Amount Non-Negativity - amount must be positive
+        validate_amount(amount)?;
+        require_base_asset(&asset)?;
+
+        record_custody(&env, &asset, amount)?;
+
+        // Reinsurers get their share of the premium as it comes in
+        let ceded = cede_premium(&env, &asset, amount)?;
+        let retained = amount.checked_sub(ceded).ok_or(ContractError::Overflow)?;
 
-        env.storage().persistent().set(&RESERVED_TOTAL, &new_reserved_total);
-        env.storage().persistent().set(&(CLAIM_RESERVATION, claim_id), &amount);
+        OptimizedRiskPool::update_pool_stats_optimized(&env, retained, 0, retained, 0)?;
+        allocate_premium(&env, retained)?;
 
-        // I1: Assert liquidity invariant holds after reservation
+        // I1: Assert liquidity invariant holds after the credit
         check_liquidity_invariant(&env)?;
 
         env.events().publish(
-            (Symbol::new(&env, "liquidity_reserved"), claim_id),
-            (amount, new_reserved_total),
+            (Symbol::new(&env, "premium_credited"), caller_contract),
+            (amount, asset, ceded),
+        );
+
+        Ok(())
+    }
+
+    /// Add or replace a reinsurance treaty (admin only).
+    /// Ceded premium across all treaties may not exceed 100%, and risk ceded under the treaty
+    /// may not find its way back to this pool through the reinsurer's own treaties.
+    pub fn set_reinsurance_treaty(
+        env: Env,
+        admin: Address,
+        treaty: ReinsuranceTreaty,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let terms_valid = match treaty.kind {
+            TreatyKind::QuotaShare => {
+                treaty.ceded_share_bps > 0
+                    && treaty.ceded_share_bps as i128 <= BASIS_POINTS
+                    && treaty.retention == 0
+                    && treaty.aggregate_retention.is_none()
+            }
+            TreatyKind::ExcessOfLoss => {
+                treaty.ceded_share_bps == 0
+                    && treaty.retention >= 0
+                    && treaty.aggregate_retention.map_or(true, |aggregate| aggregate >= 0)
+            }
+        };
+        if !terms_valid
+            || treaty.limit <= 0
+            || treaty.reinsurer == env.current_contract_address()
+            || cedes_back(&env, &treaty.reinsurer, MAX_CESSION_DEPTH)
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut treaties = get_treaties(&env);
+        let mut ceded_bps = treaty.ceded_premium_bps;
+        let mut position = None;
+        for (i, existing) in treaties.iter().enumerate() {
+            if existing.id == treaty.id {
+                position = Some(i as u32);
+            } else {
+                ceded_bps = ceded_bps
+                    .checked_add(existing.ceded_premium_bps)
+                    .ok_or(ContractError::Overflow)?;
+            }
+        }
+        if ceded_bps as i128 > BASIS_POINTS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        match position {
+            Some(i) => treaties.set(i, treaty.clone()),
+            None => treaties.push_back(treaty.clone()),
+        }
+        env.storage().persistent().set(&TREATIES, &treaties);
+
+        env.events().publish(
+            (Symbol::new(&env, "treaty_set"), treaty.id),
+            (
+                treaty.kind,
+                treaty.reinsurer,
+                treaty.ceded_share_bps,
+                treaty.retention,
+                treaty.aggregate_retention,
+                treaty.limit,
+                treaty.ceded_premium_bps,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Stop ceding under a treaty (admin only). Claims already ceded still settle with the reinsurer.
+    pub fn remove_reinsurance_treaty(env: Env, admin: Address, treaty_id: u32) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let mut treaties = get_treaties(&env);
+        let index = treaties
+            .iter()
+            .position(|t| t.id == treaty_id)
+            .ok_or(ContractError::NotFound)?;
+        treaties.remove(index as u32);
+        env.storage().persistent().set(&TREATIES, &treaties);
+
+        env.events().publish((Symbol::new(&env, "treaty_removed"), treaty_id), admin);
+
+        Ok(())
+    }
+
+    pub fn get_reinsurance_treaties(env: Env) -> Vec<ReinsuranceTreaty> {
+        get_treaties(&env)
+    }
+
+    /// Parts of a claim's reservation ceded to reinsurers
+    pub fn get_claim_cessions(env: Env, claim_id: u64) -> Vec<Cession> {
+        env.storage()
+            .persistent()
+            .get(&(CESSION, claim_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Accept ceded risk and premium from another pool (admin only)
+    pub fn register_cedant(env: Env, admin: Address, cedant: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        register_trusted_contract(&env, &admin, &cedant)?;
+
+        env.events().publish((Symbol::new(&env, "cedant_registered"), ()), cedant);
+
+        Ok(())
+    }
+
+    /// Reserve liquidity for the ceded part of a cedant's claim. Assumed risk is retained
+    /// here and not ceded further.
+    pub fn assume_reservation(
+        env: Env,
+        cedant: Address,
+        claim_id: u64,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        cedant.require_auth();
+        require_trusted_contract(&env, &cedant)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(amount)?;
+
+        let key = (ASSUMED, cedant.clone(), claim_id);
+        if env.storage().persistent().has(&key) {
+            return Err(ContractError::AlreadyExists);
+        }
+        let new_reserved_total = reserve_under(&env, &key, amount)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "reservation_assumed"), cedant),
+            (claim_id, amount, new_reserved_total),
+        );
+
+        Ok(())
+    }
+
+    /// Move an assumed reservation to `new_amount`; zero releases it
+    pub fn adjust_assumed_reservation(
+        env: Env,
+        cedant: Address,
+        claim_id: u64,
+        new_amount: i128,
+    ) -> Result<(), ContractError> {
+        cedant.require_auth();
+        require_trusted_contract(&env, &cedant)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let (reserved, new_reserved_total) =
+            adjust_under(&env, &(ASSUMED, cedant.clone(), claim_id), new_amount)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "assumed_reservation_adjusted"), cedant),
+            (claim_id, reserved, new_amount, new_reserved_total),
+        );
+
+        Ok(())
+    }
+
+    /// Pay the ceded share of a cedant's claim payment straight to the claimant
+    pub fn pay_assumed_claim(
+        env: Env,
+        cedant: Address,
+        claim_id: u64,
+        recipient: Address,
+        payout_asset: shared::types::Asset,
+        amount: i128,
+        asset_amount: i128,
+    ) -> Result<(), ContractError> {
+        cedant.require_auth();
+        require_trusted_contract(&env, &cedant)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_address(&env, &recipient)?;
+        validate_amount(amount)?;
+
+        pay_reserved(
+            &env,
+            &(ASSUMED, cedant.clone(), claim_id),
+            &recipient,
+            &payout_asset,
+            amount,
+            asset_amount,
+        )?;
+
+        env.events().publish(
+            (Symbol::new(&env, "assumed_claim_paid"), cedant),
+            (claim_id, recipient, amount, payout_asset, asset_amount),
+        );
+
+        Ok(())
+    }
+
+    /// Liquidity still reserved for the ceded part of a cedant's claim
+    pub fn get_assumed_reservation(env: Env, cedant: Address, claim_id: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(ASSUMED, cedant, claim_id))
+            .unwrap_or(0i128)
+    }
+
+    /// Set the LP withdrawal cooldown, solvency buffer and utilization cap (admin only)
+    pub fn set_withdrawal_config(
+        env: Env,
+        admin: Address,
+        config: WithdrawalConfig,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if config.solvency_buffer < 0 || config.max_utilization_bps as i128 > BASIS_POINTS {
+            return Err(ContractError::InvalidInput);
+        }
+        env.storage().persistent().set(&WITHDRAWAL_CONFIG, &config);
+
+        env.events().publish(
+            (Symbol::new(&env, "withdrawal_config_set"), ()),
+            (config.cooldown_secs, config.solvency_buffer, config.max_utilization_bps),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_withdrawal_config(env: Env) -> WithdrawalConfig {
+        get_withdrawal_config(&env)
+    }
+
+    /// Ask to withdraw liquidity. The cooldown starts straight away unless utilization is at
+    /// or above the cap, in which case the request queues behind earlier ones.
+    pub fn request_withdrawal(env: Env, provider: Address, amount: i128) -> Result<u64, ContractError> {
+        Self::request_tranche_withdrawal(env, provider, DEFAULT_TRANCHE, amount)
+    }
+
+    /// Request a withdrawal of `amount` from one tranche, locking the shares it will burn
+    pub fn request_tranche_withdrawal(
+        env: Env,
+        provider: Address,
+        tranche_id: u32,
+        amount: i128,
+    ) -> Result<u64, ContractError> {
+        provider.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        // I4: Amount Non-Negativity - amount must be positive
+        validate_amount(amount)?;
+
+        // Shares already locked by pending requests cannot be requested twice
+        let shares = shares_for_amount(&env, tranche_id, amount, true)?;
+        let unlocked = share_balance(&env, tranche_id, &provider)
+            .checked_sub(locked_shares(&env, tranche_id, &provider))
+            .ok_or(ContractError::Overflow)?;
+        if shares > unlocked {
+            return Err(ContractError::InsufficientFunds);
+        }
+
+        // Principal not yet pending backs the request first; gains come out of shares alone
+        let principal = match OptimizedRiskPool::get_provider_info_optimized(&env, &provider) {
+            Ok(info) => amount
+                .min(OptimizedRiskPool::from_compact_amount(info.balance - info.pending_withdrawal))
+                .max(0),
+            Err(_) => 0,
+        };
+
+        let config = get_withdrawal_config(&env);
+        let now = env.ledger().timestamp();
+        let id: u64 = env.storage().persistent().get(&WITHDRAWAL_COUNTER).unwrap_or(0u64) + 1;
+        env.storage().persistent().set(&WITHDRAWAL_COUNTER, &id);
+
+        let mut queue: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&WITHDRAWAL_QUEUE)
+            .unwrap_or_else(|| Vec::new(&env));
+        let queued = !queue.is_empty() || utilization_bps(&env)? >= config.max_utilization_bps;
+        let request = WithdrawalRequest {
+            id,
+            provider: provider.clone(),
+            tranche_id,
+            amount,
+            shares,
+            principal,
+            requested_at: now,
+            available_at: if queued { None } else { Some(now + config.cooldown_secs) },
+            status: if queued { WithdrawalStatus::Queued } else { WithdrawalStatus::Pending },
+        };
+        if queued {
+            queue.push_back(id);
+            env.storage().persistent().set(&WITHDRAWAL_QUEUE, &queue);
+        }
+        env.storage().persistent().set(&(WITHDRAWAL, id), &request);
+        adjust_locked_shares(&env, tranche_id, &provider, shares)?;
+        if principal > 0 {
+            OptimizedRiskPool::adjust_pending_withdrawal(&env, &provider, principal)?;
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "withdrawal_requested"), provider),
+            (id, tranche_id, amount, shares, request.status, request.available_at),
+        );
+
+        Ok(id)
+    }
+
+    /// Start the cooldown of queued requests, oldest first, while utilization is below the cap.
+    /// Anyone may call this. Returns the number of requests released from the queue.
+    pub fn process_withdrawal_queue(env: Env, limit: u32) -> Result<u32, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let config = get_withdrawal_config(&env);
+        if utilization_bps(&env)? >= config.max_utilization_bps {
+            return Ok(0);
+        }
+
+        let mut queue: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&WITHDRAWAL_QUEUE)
+            .unwrap_or_else(|| Vec::new(&env));
+        let now = env.ledger().timestamp();
+        let mut released = 0u32;
+        while released < limit {
+            let Some(id) = queue.pop_front() else {
+                break;
+            };
+            let mut request: WithdrawalRequest =
+                env.storage().persistent().get(&(WITHDRAWAL, id)).ok_or(ContractError::NotFound)?;
+            if request.status != WithdrawalStatus::Queued {
+                continue;
+            }
+            request.status = WithdrawalStatus::Pending;
+            request.available_at = Some(now + config.cooldown_secs);
+            env.storage().persistent().set(&(WITHDRAWAL, id), &request);
+            released += 1;
+
+            env.events().publish(
+                (Symbol::new(&env, "withdrawal_dequeued"), request.provider),
+                (id, request.available_at),
+            );
+        }
+        env.storage().persistent().set(&WITHDRAWAL_QUEUE, &queue);
+
+        Ok(released)
+    }
+
+    /// Complete a withdrawal whose cooldown has passed. Fails while the payout would leave
+    /// reserved claims plus the solvency buffer uncovered.
+    pub fn complete_withdrawal(env: Env, provider: Address, request_id: u64) -> Result<(), ContractError> {
+        provider.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let mut request: WithdrawalRequest = env
+            .storage()
+            .persistent()
+            .get(&(WITHDRAWAL, request_id))
+            .ok_or(ContractError::NotFound)?;
+        if request.provider != provider {
+            return Err(ContractError::Unauthorized);
+        }
+        if request.status != WithdrawalStatus::Pending {
+            return Err(ContractError::InvalidState);
+        }
+        let available_at = request.available_at.ok_or(ContractError::InvalidState)?;
+        if env.ledger().timestamp() < available_at {
+            return Err(ContractError::CooldownActive);
+        }
+
+        // Pay the locked shares' value now, so gains and losses during the cooldown count
+        let payout = amount_for_shares(&env, request.tranche_id, request.shares)?;
+        check_withdrawal_solvency(&env, request.tranche_id, payout)?;
+
+        adjust_locked_shares(&env, request.tranche_id, &provider, -request.shares)?;
+        burn_shares(&env, request.tranche_id, &provider, request.shares, payout)?;
+        OptimizedRiskPool::update_pool_stats_optimized(&env, -payout, 0, 0, 0)?;
+
+        // The provider balance tracks principal still at work; gains come out of shares alone
+        if request.principal > 0 {
+            OptimizedRiskPool::adjust_pending_withdrawal(&env, &provider, -request.principal)?;
+            OptimizedRiskPool::update_provider_info_optimized(&env, &provider, -request.principal, 0)?;
+        }
+
+        release_funds(&env, &shared::types::Asset::Native, &provider, payout)?;
+
+        request.status = WithdrawalStatus::Completed;
+        env.storage().persistent().set(&(WITHDRAWAL, request_id), &request);
+
+        // I1: Assert liquidity invariant holds after withdrawal
+        check_liquidity_invariant(&env)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "liquidity_withdrawn"), provider),
+            (request_id, payout, request.shares),
+        );
+
+        Ok(())
+    }
+
+    /// Cancel a queued or cooling-down withdrawal (provider only)
+    pub fn cancel_withdrawal(env: Env, provider: Address, request_id: u64) -> Result<(), ContractError> {
+        provider.require_auth();
+
+        let mut request: WithdrawalRequest = env
+            .storage()
+            .persistent()
+            .get(&(WITHDRAWAL, request_id))
+            .ok_or(ContractError::NotFound)?;
+        if request.provider != provider {
+            return Err(ContractError::Unauthorized);
+        }
+        if request.status != WithdrawalStatus::Queued && request.status != WithdrawalStatus::Pending {
+            return Err(ContractError::InvalidState);
+        }
+
+        // Queued entries are skipped when the queue is processed
+        request.status = WithdrawalStatus::Cancelled;
+        env.storage().persistent().set(&(WITHDRAWAL, request_id), &request);
+        adjust_locked_shares(&env, request.tranche_id, &provider, -request.shares)?;
+        if request.principal > 0 {
+            OptimizedRiskPool::adjust_pending_withdrawal(&env, &provider, -request.principal)?;
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "withdrawal_cancelled"), provider),
+            (request_id, request.amount, request.shares),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_withdrawal(env: Env, request_id: u64) -> Result<WithdrawalRequest, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(WITHDRAWAL, request_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Request ids waiting for utilization to fall, oldest first
+    pub fn get_withdrawal_queue(env: Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&WITHDRAWAL_QUEUE)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Liquidity a provider has asked to withdraw that has not completed yet
+    pub fn get_pending_withdrawal(env: Env, provider: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(PENDING_WITHDRAWAL, provider))
+            .unwrap_or(0i128)
+    }
+
+    /// Shares of a tranche a provider has locked in withdrawals that have not completed yet
+    pub fn get_locked_shares(env: Env, provider: Address, tranche_id: u32) -> i128 {
+        locked_shares(&env, tranche_id, &provider)
+    }
+
+    /// Define the pool's tranches (admin only). Premium shares must add up to 100%
+    /// and every tranche needs its own waterfall rank. Tranches still holding capital
+    /// or shares cannot be dropped.
+    pub fn define_tranches(
+        env: Env,
+        admin: Address,
+        tranches: Vec<TrancheConfig>,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if tranches.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // Keep tranches in waterfall order, rejecting duplicate ids and ranks
+        let mut ordered: Vec<TrancheConfig> = Vec::new(&env);
+        let mut total_bps = 0u32;
+        for tranche in tranches.iter() {
+            total_bps = total_bps
+                .checked_add(tranche.premium_share_bps)
+                .ok_or(ContractError::Overflow)?;
+            let mut position = ordered.len();
+            for (i, existing) in ordered.iter().enumerate() {
+                if existing.id == tranche.id || existing.waterfall_rank == tranche.waterfall_rank {
+                    return Err(ContractError::InvalidInput);
+                }
+                if position == ordered.len() && tranche.waterfall_rank < existing.waterfall_rank {
+                    position = i as u32;
+                }
+            }
+            ordered.insert(position, tranche);
+        }
+        if total_bps != BASIS_POINTS as u32 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        for current in get_tranche_configs(&env).iter() {
+            if ordered.iter().any(|t| t.id == current.id) {
+                continue;
+            }
+            let state = get_tranche_state(&env, current.id)?;
+            if state.capital != 0 || state.total_shares != 0 {
+                return Err(ContractError::InvalidState);
+            }
+            env.storage().persistent().remove(&(TRANCHE, current.id));
+        }
+        for tranche in ordered.iter() {
+            if !env.storage().persistent().has(&(TRANCHE, tranche.id)) {
+                set_tranche_state(&env, tranche.id, &TrancheState::default());
+            }
+        }
+        env.storage().persistent().set(&TRANCHE_CONFIG, &ordered);
+
+        env.events().publish(
+            (Symbol::new(&env, "tranches_defined"), admin),
+            ordered.len(),
+        );
+
+        Ok(())
+    }
+
+    /// Tranches in waterfall order, first loss first
+    pub fn get_tranches(env: Env) -> Vec<TrancheConfig> {
+        get_tranche_configs(&env)
+    }
+
+    /// A tranche's capital, earnings, losses and share of the currently reserved claims
+    pub fn get_tranche_exposure(env: Env, tranche_id: u32) -> Result<TrancheExposure, ContractError> {
+        let mut remaining: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
+        for config in get_tranche_configs(&env).iter() {
+            let state = get_tranche_state(&env, config.id)?;
+            let reserved_exposure = remaining.min(state.capital);
+            if config.id == tranche_id {
+                return Ok(TrancheExposure {
+                    config,
+                    state,
+                    reserved_exposure,
+                });
+            }
+            remaining -= reserved_exposure;
+        }
+        Err(ContractError::NotFound)
+    }
+
+    /// A tranche's NAV, shares outstanding and the price of one share
+    pub fn get_pool_nav(env: Env, tranche_id: u32) -> Result<PoolNav, ContractError> {
+        let state = get_tranche_state(&env, tranche_id)?;
+        let share_price = if state.total_shares == 0 {
+            SHARE_PRICE_SCALE
+        } else {
+            state
+                .capital
+                .checked_mul(SHARE_PRICE_SCALE)
+                .ok_or(ContractError::Overflow)?
+                / state.total_shares
+        };
+
+        Ok(PoolNav {
+            net_asset_value: state.capital,
+            total_shares: state.total_shares,
+            share_price,
+        })
+    }
+
+    /// Shares of a tranche held by `owner`, including any locked in withdrawals
+    pub fn share_balance(env: Env, owner: Address, tranche_id: u32) -> i128 {
+        share_balance(&env, tranche_id, &owner)
+    }
+
+    /// Current value of the shares of a tranche held by `owner`
+    pub fn share_value(env: Env, owner: Address, tranche_id: u32) -> Result<i128, ContractError> {
+        amount_for_shares(&env, tranche_id, share_balance(&env, tranche_id, &owner))
+    }
+
+    /// Transfer unlocked tranche shares and the principal behind them, e.g. to sell an LP position
+    pub fn transfer_shares(
+        env: Env,
+        from: Address,
+        to: Address,
+        tranche_id: u32,
+        shares: i128,
+    ) -> Result<(), ContractError> {
+        from.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        move_shares(&env, tranche_id, &from, &to, shares)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "shares_transferred"), from, to),
+            (tranche_id, shares),
         );
 
         Ok(())
     }
 
-    pub fn payout_reserved_claim(
-        env: Env,
-        caller_contract: Address,
-        claim_id: u64,
-        recipient: Address,
-    ) -> Result<(), ContractError> {
-        // Default to Native asset for backward compatibility
-        Self::payout_reserved_claim_multi_asset(
-            env,
-            caller_contract,
-            claim_id,
-            recipient,
-            shared::types::Asset::Native,
-        )
-    }
-
-    /// Multi-asset version of payout_reserved_claim
-    pub fn payout_reserved_claim_multi_asset(
+    /// Allow `spender` to move up to `shares` of the owner's tranche shares until
+    /// `expiration_ledger`, e.g. so a lending protocol can seize shares pledged as collateral
+    pub fn approve_shares(
         env: Env,
-        caller_contract: Address,
-        claim_id: u64,
-        recipient: Address,
-        payout_asset: shared::types::Asset,
+        owner: Address,
+        spender: Address,
+        tranche_id: u32,
+        shares: i128,
+        expiration_ledger: u32,
     ) -> Result<(), ContractError> {
-        // Verify that the caller is a trusted contract (e.g., claims contract)
-        caller_contract.require_auth();
-        require_trusted_contract(&env, &caller_contract)?;
+        owner.require_auth();
 
-        if is_paused(&env) {
-            return Err(ContractError::Paused);
+        if shares < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        if shares > 0 && expiration_ledger < env.ledger().sequence() {
+            return Err(ContractError::InvalidInput);
         }
 
-        validate_address(&env, &recipient)?;
+        env.storage().persistent().set(
+            &(SHARE_ALLOWANCE, owner.clone(), spender.clone(), tranche_id),
+            &ShareAllowance {
+                amount: shares,
+                expiration_ledger,
+            },
+        );
 
-        let mut stats: (i128, i128, i128, u64) =
-            env.storage().persistent().get(&POOL_STATS).ok_or(ContractError::NotFound)?;
+        env.events().publish(
+            (Symbol::new(&env, "shares_approved"), owner, spender),
+            (tranche_id, shares, expiration_ledger),
+        );
 
-        let mut reserved_total: i128 =
-            env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
+        Ok(())
+    }
 
-        let amount: i128 = env
+    /// Tranche shares `spender` may still move on behalf of `owner`
+    pub fn share_allowance(env: Env, owner: Address, spender: Address, tranche_id: u32) -> i128 {
+        let allowance: Option<ShareAllowance> = env
             .storage()
             .persistent()
-            .get(&(CLAIM_RESERVATION, claim_id))
-            .ok_or(ContractError::NotFound)?;
-
-        if amount <= 0 {
-            return Err(ContractError::InvalidState);
+            .get(&(SHARE_ALLOWANCE, owner, spender, tranche_id));
+        match allowance {
+            Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => {
+                allowance.amount
+            }
+            _ => 0,
         }
+    }
 
-        if reserved_total < amount {
-            return Err(ContractError::InvalidState);
-        }
+    /// Transfer tranche shares out of an allowance granted by `from`
+    pub fn transfer_shares_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        tranche_id: u32,
+        shares: i128,
+    ) -> Result<(), ContractError> {
+        spender.require_auth();
 
-        if stats.0 < amount {
-            return Err(ContractError::InsufficientFunds);
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
         }
 
-        // Safe arithmetic for payout
-        reserved_total = reserved_total.checked_sub(amount).ok_or(ContractError::Overflow)?;
-        stats.0 = stats.0.checked_sub(amount).ok_or(ContractError::Overflow)?;
-        stats.1 = stats.1.checked_add(amount).ok_or(ContractError::Overflow)?;
-
-        env.storage().persistent().set(&RESERVED_TOTAL, &reserved_total);
-        env.storage().persistent().remove(&(CLAIM_RESERVATION, claim_id));
-        env.storage().persistent().set(&POOL_STATS, &stats);
+        let key = (SHARE_ALLOWANCE, from.clone(), spender, tranche_id);
+        let mut allowance: ShareAllowance =
+            env.storage().persistent().get(&key).ok_or(ContractError::Unauthorized)?;
+        if allowance.expiration_ledger < env.ledger().sequence() || allowance.amount < shares {
+            return Err(ContractError::Unauthorized);
+        }
 
-        // Store payout asset information for tracking
-        env.storage().persistent().set(
-            &(Symbol::new(&env, "PAYOUT_ASSET"), claim_id),
-            &payout_asset,
-        );
+        move_shares(&env, tranche_id, &from, &to, shares)?;
 
-        // I1: Assert liquidity invariant holds after payout
-        check_liquidity_invariant(&env)?;
+        allowance.amount -= shares;
+        env.storage().persistent().set(&key, &allowance);
 
         env.events().publish(
-            (Symbol::new(&env, "reserved_claim_payout"), claim_id),
-            (recipient, amount, payout_asset),
+            (Symbol::new(&env, "shares_transferred"), from, to),
+            (tranche_id, shares),
         );
 
         Ok(())
     }
 
-    /// Get the payout asset for a claim
-    pub fn get_claim_payout_asset(
-        env: Env,
-        claim_id: u64,
-    ) -> Result<shared::types::Asset, ContractError> {
-        env.storage()
-            .persistent()
-            .get(&(Symbol::new(&env, "PAYOUT_ASSET"), claim_id))
-            .ok_or(ContractError::NotFound)
-    }
-
 pub fn payout_claim(
     env: Env,
     manager: Address,
@@ -455,8 +2529,23 @@ pub fn payout_claim(
 
     let mut stats: (i128, i128, i128, u64) =
         env.storage().persistent().get(&POOL_STATS).ok_or(ContractError::NotFound)?;
-    
-    // ... rest of your existing logic for subtracting from stats.0 and adding to stats.1 ...
+    let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap_or(0i128);
+
+    // Direct payouts come out of free liquidity, never out of claim reservations
+    let available = stats.0.checked_sub(reserved_total).ok_or(ContractError::Overflow)?;
+    if available < amount {
+        return Err(ContractError::InsufficientFunds);
+    }
+
+    stats.0 = stats.0.checked_sub(amount).ok_or(ContractError::Overflow)?;
+    stats.1 = stats.1.checked_add(amount).ok_or(ContractError::Overflow)?;
+    env.storage().persistent().set(&POOL_STATS, &stats);
+    apply_loss(&env, amount)?;
+
+    release_funds(&env, &shared::types::Asset::Native, &recipient, amount)?;
+
+    // I1: Assert liquidity invariant holds after payout
+    check_liquidity_invariant(&env)?;
 
     env.events().publish((Symbol::new(&env, "claim_payout"), recipient), (amount,));
     Ok(())
@@ -688,12 +2777,17 @@ mod tests {
         env.mock_all_auths();
 
         let admin = Address::generate(&env);
-        let xlm_token = Address::generate(&env);
+        let xlm_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
         let claims_contract = Address::generate(&env);
 
         (env, admin, xlm_token, claims_contract)
     }
 
+    /// Mint `amount` of the pool's XLM token to `provider` so it can deposit
+    fn fund(env: &Env, xlm_token: &Address, provider: &Address, amount: i128) {
+        soroban_sdk::token::StellarAssetClient::new(env, xlm_token).mint(provider, &amount);
+    }
+
     fn initialize_pool(
         env: &Env,
         admin: &Address,
@@ -790,6 +2884,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 5000);
 
         let result = RiskPoolContract::deposit_liquidity(
             env.clone(),
@@ -805,6 +2900,10 @@ mod tests {
 
         let provider_info = RiskPoolContract::get_provider_info(env.clone(), provider.clone()).unwrap();
         assert_eq!(provider_info.1, 5000);  // total_deposited by provider
+
+        // The deposit moved real tokens from the provider into the pool
+        let token_client = soroban_sdk::token::Client::new(&env, &xlm_token);
+        assert_eq!(token_client.balance(&provider), 0);
     }
 
     #[test]
@@ -814,7 +2913,10 @@ mod tests {
 
         let provider = Address::generate(&env);
 
+        fund(&env, &xlm_token, &provider, 5000);
+
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 5000).unwrap();
+        fund(&env, &xlm_token, &provider, 3000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 3000).unwrap();
 
         let stats = RiskPoolContract::get_pool_stats(env.clone()).unwrap();
@@ -833,8 +2935,12 @@ mod tests {
         let provider2 = Address::generate(&env);
         let provider3 = Address::generate(&env);
 
+        fund(&env, &xlm_token, &provider1, 5000);
+
         RiskPoolContract::deposit_liquidity(env.clone(), provider1.clone(), 5000).unwrap();
+        fund(&env, &xlm_token, &provider2, 3000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider2.clone(), 3000).unwrap();
+        fund(&env, &xlm_token, &provider3, 2000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider3.clone(), 2000).unwrap();
 
         let stats = RiskPoolContract::get_pool_stats(env.clone()).unwrap();
@@ -917,6 +3023,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 1000);
 
         let result = RiskPoolContract::deposit_liquidity(
             env.clone(),
@@ -937,6 +3044,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         let result = RiskPoolContract::reserve_liquidity(
@@ -959,6 +3067,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 2000).unwrap();
@@ -978,6 +3087,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 5000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 5000).unwrap();
 
         let result = RiskPoolContract::reserve_liquidity(
@@ -996,6 +3106,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         // Reserve most of the liquidity
@@ -1019,6 +3130,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 5000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 5000).unwrap();
 
         let result = RiskPoolContract::reserve_liquidity(
@@ -1047,6 +3159,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         let untrusted_contract = Address::generate(&env);
@@ -1067,6 +3180,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
@@ -1087,6 +3201,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         let result = RiskPoolContract::reserve_liquidity(
@@ -1105,6 +3220,7 @@ mod tests {
         initialize_pool(&env, &admin, &xlm_token, &claims_contract);
 
         let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         RiskPoolContract::pause(env.clone(), admin.clone()).unwrap();
@@ -1130,6 +3246,7 @@ mod tests {
 
         let provider = Address::generate(&env);
         let recipient = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
@@ -1151,6 +3268,700 @@ mod tests {
         assert_eq!(reserved_total, 0);
     }
 
+    #[test]
+    fn test_payout_reserved_claim_split_across_recipients() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+
+        let provider = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
+        RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
+        RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
+
+        RiskPoolContract::payout_reserved_claim_multi_asset(
+            env.clone(),
+            claims_contract.clone(),
+            1,
+            first,
+            shared::types::Asset::Native,
+            Some(1000),
+            None,
+        )
+        .unwrap();
+
+        let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap();
+        assert_eq!(reserved_total, 2000);
+
+        // A part-payment cannot exceed what remains reserved
+        let result = RiskPoolContract::payout_reserved_claim_multi_asset(
+            env.clone(),
+            claims_contract.clone(),
+            1,
+            second.clone(),
+            shared::types::Asset::Native,
+            Some(2001),
+            None,
+        );
+        assert_eq!(result, Err(ContractError::InsufficientFunds));
+
+        // None pays out the remainder and clears the reservation
+        RiskPoolContract::payout_reserved_claim_multi_asset(
+            env.clone(),
+            claims_contract.clone(),
+            1,
+            second.clone(),
+            shared::types::Asset::Native,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let stats = RiskPoolContract::get_pool_stats(env.clone()).unwrap();
+        assert_eq!(stats.0, 7000);
+        assert_eq!(stats.1, 3000);
+        assert!(!env.storage().persistent().has(&(CLAIM_RESERVATION, 1u64)));
+
+        let token_client = soroban_sdk::token::Client::new(&env, &xlm_token);
+        assert_eq!(token_client.balance(&second), 2000);
+    }
+
+    #[test]
+    fn test_settlement_in_alternative_asset_draws_on_its_own_holdings() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+
+        let provider = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
+        RiskPoolContract::deposit_liquidity(env.clone(), provider, 10000).unwrap();
+        RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
+
+        let usdc_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let usdc = shared::types::Asset::Contract(usdc_token.clone());
+        RiskPoolContract::set_asset_token(env.clone(), admin.clone(), usdc.clone(), usdc_token.clone())
+            .unwrap();
+        assert_eq!(RiskPoolContract::get_payout_liquidity(env.clone(), usdc.clone(), 1), Ok(0));
+
+        // Base-asset capital cannot be parked as a payout reserve
+        assert_eq!(
+            RiskPoolContract::fund_payout_asset(
+                env.clone(),
+                admin.clone(),
+                shared::types::Asset::Native,
+                100
+            ),
+            Err(ContractError::InvalidInput)
+        );
+
+        soroban_sdk::token::StellarAssetClient::new(&env, &usdc_token).mint(&admin, &5000);
+        RiskPoolContract::fund_payout_asset(env.clone(), admin.clone(), usdc.clone(), 5000).unwrap();
+        assert_eq!(RiskPoolContract::get_payout_liquidity(env.clone(), usdc.clone(), 1), Ok(5000));
+
+        // The 3000 claim is paid as 4500 of the alternative asset
+        RiskPoolContract::payout_reserved_claim_multi_asset(
+            env.clone(),
+            claims_contract.clone(),
+            1,
+            recipient.clone(),
+            usdc.clone(),
+            None,
+            Some(4500),
+        )
+        .unwrap();
+
+        // Provider capital is untouched; only the reservation is released
+        let stats = RiskPoolContract::get_pool_stats(env.clone()).unwrap();
+        assert_eq!(stats.0, 10000);
+        assert_eq!(stats.1, 3000);
+        let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap();
+        assert_eq!(reserved_total, 0);
+
+        assert_eq!(RiskPoolContract::get_recorded_balance(env.clone(), usdc.clone()), 500);
+        assert_eq!(
+            RiskPoolContract::get_recorded_balance(env.clone(), shared::types::Asset::Native),
+            10000
+        );
+        assert_eq!(soroban_sdk::token::Client::new(&env, &usdc_token).balance(&recipient), 4500);
+    }
+
+    #[test]
+    fn test_adjust_reservation_up_and_down() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+
+        let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
+        RiskPoolContract::deposit_liquidity(env.clone(), provider, 10000).unwrap();
+        RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
+        RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 2, 5000).unwrap();
+
+        RiskPoolContract::adjust_reservation(env.clone(), claims_contract.clone(), 1, 4000).unwrap();
+        let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap();
+        assert_eq!(reserved_total, 9000);
+
+        // Growing past free liquidity is refused
+        let result = RiskPoolContract::adjust_reservation(env.clone(), claims_contract.clone(), 1, 5001);
+        assert_eq!(result, Err(ContractError::InsufficientFunds));
+
+        // Shrinking releases the difference
+        RiskPoolContract::adjust_reservation(env.clone(), claims_contract.clone(), 2, 1000).unwrap();
+        let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap();
+        assert_eq!(reserved_total, 5000);
+
+        let result = RiskPoolContract::adjust_reservation(env.clone(), claims_contract, 3, 100);
+        assert_eq!(result, Err(ContractError::NotFound));
+    }
+
+    #[test]
+    fn test_withdrawal_cooldown_queue_and_solvency_buffer() {
+        use soroban_sdk::testutils::Ledger;
+
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+        env.ledger().with_mut(|li| li.timestamp = 1000);
+        RiskPoolContract::set_withdrawal_config(
+            env.clone(),
+            admin.clone(),
+            WithdrawalConfig {
+                cooldown_secs: 86400,
+                solvency_buffer: 1000,
+                max_utilization_bps: 7000,
+            },
+        ).unwrap();
+
+        let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
+        RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
+        RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
+
+        // Below the utilization cap the cooldown starts at once
+        let first = RiskPoolContract::request_withdrawal(env.clone(), provider.clone(), 2500).unwrap();
+        let request = RiskPoolContract::get_withdrawal(env.clone(), first).unwrap();
+        assert_eq!(request.status, WithdrawalStatus::Pending);
+        assert_eq!(request.available_at, Some(1000 + 86400));
+        let result = RiskPoolContract::complete_withdrawal(env.clone(), provider.clone(), first);
+        assert_eq!(result, Err(ContractError::CooldownActive));
+
+        // At 70% utilization new requests queue
+        RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 2, 4000).unwrap();
+        let second = RiskPoolContract::request_withdrawal(env.clone(), provider.clone(), 1000).unwrap();
+        assert_eq!(
+            RiskPoolContract::get_withdrawal(env.clone(), second).unwrap().status,
+            WithdrawalStatus::Queued
+        );
+        assert_eq!(RiskPoolContract::get_withdrawal_queue(env.clone()).len(), 1);
+        assert_eq!(RiskPoolContract::get_locked_shares(env.clone(), provider.clone(), DEFAULT_TRANCHE), 3500);
+        assert_eq!(RiskPoolContract::get_pending_withdrawal(env.clone(), provider.clone()), 3500);
+        let result = RiskPoolContract::request_withdrawal(env.clone(), provider.clone(), 7000);
+        assert_eq!(result, Err(ContractError::InsufficientFunds));
+
+        // After the cooldown, the withdrawal still may not eat into reservations plus the buffer
+        env.ledger().with_mut(|li| li.timestamp = 1000 + 86400);
+        assert_eq!(RiskPoolContract::process_withdrawal_queue(env.clone(), 10).unwrap(), 0);
+        let result = RiskPoolContract::complete_withdrawal(env.clone(), provider.clone(), first);
+        assert_eq!(result, Err(ContractError::InsufficientFunds));
+
+        RiskPoolContract::adjust_reservation(env.clone(), claims_contract.clone(), 2, 1000).unwrap();
+        RiskPoolContract::complete_withdrawal(env.clone(), provider.clone(), first).unwrap();
+        assert_eq!(RiskPoolContract::get_pool_stats(env.clone()).unwrap().0, 7500);
+
+        // Utilization has fallen, so the queued request starts its cooldown
+        assert_eq!(RiskPoolContract::process_withdrawal_queue(env.clone(), 10).unwrap(), 1);
+        assert!(RiskPoolContract::get_withdrawal_queue(env.clone()).is_empty());
+        let result = RiskPoolContract::complete_withdrawal(env.clone(), provider.clone(), second);
+        assert_eq!(result, Err(ContractError::CooldownActive));
+
+        env.ledger().with_mut(|li| li.timestamp = 1000 + 2 * 86400);
+        RiskPoolContract::complete_withdrawal(env.clone(), provider.clone(), second).unwrap();
+        assert_eq!(RiskPoolContract::get_pool_stats(env.clone()).unwrap().0, 6500);
+        assert_eq!(RiskPoolContract::get_provider_info(env.clone(), provider.clone()).unwrap().0, 6500);
+        assert_eq!(RiskPoolContract::get_locked_shares(env.clone(), provider.clone(), DEFAULT_TRANCHE), 0);
+        assert_eq!(RiskPoolContract::get_pending_withdrawal(env.clone(), provider.clone()), 0);
+        assert_eq!(RiskPoolContract::share_balance(env.clone(), provider, DEFAULT_TRANCHE), 6500);
+    }
+
+    #[test]
+    fn test_shares_track_nav_and_transfer() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+        let policy_contract = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let buyer = Address::generate(&env);
+        let lender = Address::generate(&env);
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+            RiskPoolContract::register_policy_contract(env.clone(), admin.clone(), policy_contract.clone()).unwrap();
+
+            // The first deposit mints shares at par
+            fund(&env, &xlm_token, &first, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), first.clone(), 10000).unwrap();
+            assert_eq!(RiskPoolContract::share_balance(env.clone(), first.clone(), DEFAULT_TRANCHE), 10000);
+
+            // Premium income raises the NAV
+            fund(&env, &xlm_token, &pool_id, 2000);
+            RiskPoolContract::credit_premium(
+                env.clone(),
+                policy_contract.clone(),
+                shared::types::Asset::Native,
+                2000,
+            ).unwrap();
+            let nav = RiskPoolContract::get_pool_nav(env.clone(), DEFAULT_TRANCHE).unwrap();
+            assert_eq!(nav.net_asset_value, 12000);
+            assert_eq!(nav.share_price, 12_000_000);
+
+            // Later deposits buy in at the higher price
+            fund(&env, &xlm_token, &second, 6000);
+            RiskPoolContract::deposit_liquidity(env.clone(), second.clone(), 6000).unwrap();
+            assert_eq!(RiskPoolContract::share_balance(env.clone(), second.clone(), DEFAULT_TRANCHE), 5000);
+
+            // Claim payouts lower it for every holder in proportion
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3600).unwrap();
+            RiskPoolContract::payout_reserved_claim(
+                env.clone(),
+                claims_contract.clone(),
+                1,
+                Address::generate(&env),
+            ).unwrap();
+            assert_eq!(RiskPoolContract::share_value(env.clone(), first.clone(), DEFAULT_TRANCHE), Ok(9600));
+            assert_eq!(RiskPoolContract::share_value(env.clone(), second.clone(), DEFAULT_TRANCHE), Ok(4800));
+
+            // Positions can be sold or pledged
+            RiskPoolContract::transfer_shares(env.clone(), first.clone(), buyer.clone(), DEFAULT_TRANCHE, 1000).unwrap();
+            assert_eq!(RiskPoolContract::share_value(env.clone(), buyer.clone(), DEFAULT_TRANCHE), Ok(960));
+            // The principal behind the sold shares moves with them
+            assert_eq!(RiskPoolContract::get_provider_info(env.clone(), first.clone()).unwrap().0, 9000);
+            assert_eq!(RiskPoolContract::get_provider_info(env.clone(), buyer.clone()).unwrap().0, 1000);
+
+            let expiry = env.ledger().sequence() + 100;
+            RiskPoolContract::approve_shares(env.clone(), second.clone(), lender.clone(), DEFAULT_TRANCHE, 2000, expiry).unwrap();
+            RiskPoolContract::transfer_shares_from(
+                env.clone(),
+                lender.clone(),
+                second.clone(),
+                lender.clone(),
+                DEFAULT_TRANCHE,
+                2000,
+            ).unwrap();
+            assert_eq!(RiskPoolContract::share_allowance(env.clone(), second.clone(), lender.clone(), DEFAULT_TRANCHE), 0);
+            assert_eq!(RiskPoolContract::get_provider_info(env.clone(), second.clone()).unwrap().0, 3600);
+            assert_eq!(RiskPoolContract::get_provider_info(env.clone(), lender.clone()).unwrap().0, 2400);
+            let result = RiskPoolContract::transfer_shares_from(
+                env.clone(),
+                lender.clone(),
+                second.clone(),
+                lender.clone(),
+                DEFAULT_TRANCHE,
+                1,
+            );
+            assert_eq!(result, Err(ContractError::Unauthorized));
+
+            // Shares locked in a withdrawal cannot be moved
+            RiskPoolContract::request_withdrawal(env.clone(), first.clone(), 8640).unwrap();
+            assert_eq!(RiskPoolContract::get_locked_shares(env.clone(), first.clone(), DEFAULT_TRANCHE), 9000);
+            let result = RiskPoolContract::transfer_shares(env.clone(), first.clone(), buyer.clone(), DEFAULT_TRANCHE, 1);
+            assert_eq!(result, Err(ContractError::InsufficientFunds));
+        });
+    }
+
+    #[test]
+    fn test_tranche_premium_split_and_loss_waterfall() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+        let policy_contract = Address::generate(&env);
+        let senior = Address::generate(&env);
+        let junior = Address::generate(&env);
+        const JUNIOR_TRANCHE: u32 = 1;
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+            RiskPoolContract::register_policy_contract(env.clone(), admin.clone(), policy_contract.clone()).unwrap();
+
+            let mut tranches = Vec::new(&env);
+            tranches.push_back(TrancheConfig {
+                id: DEFAULT_TRANCHE,
+                premium_share_bps: 4000,
+                waterfall_rank: 1,
+            });
+            tranches.push_back(TrancheConfig {
+                id: JUNIOR_TRANCHE,
+                premium_share_bps: 5000,
+                waterfall_rank: 0,
+            });
+            let result = RiskPoolContract::define_tranches(env.clone(), admin.clone(), tranches.clone());
+            assert_eq!(result, Err(ContractError::InvalidInput));
+
+            tranches.set(1, TrancheConfig {
+                id: JUNIOR_TRANCHE,
+                premium_share_bps: 6000,
+                waterfall_rank: 0,
+            });
+            RiskPoolContract::define_tranches(env.clone(), admin.clone(), tranches).unwrap();
+            assert_eq!(RiskPoolContract::get_tranches(env.clone()).get(0).unwrap().id, JUNIOR_TRANCHE);
+
+            fund(&env, &xlm_token, &senior, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), senior.clone(), 10000).unwrap();
+            fund(&env, &xlm_token, &junior, 2000);
+            RiskPoolContract::deposit_to_tranche(
+                env.clone(),
+                junior.clone(),
+                JUNIOR_TRANCHE,
+                2000,
+            ).unwrap();
+
+            // The junior tranche earns the larger premium share
+            fund(&env, &xlm_token, &pool_id, 1000);
+            RiskPoolContract::credit_premium(
+                env.clone(),
+                policy_contract.clone(),
+                shared::types::Asset::Native,
+                1000,
+            ).unwrap();
+            assert_eq!(RiskPoolContract::get_pool_nav(env.clone(), JUNIOR_TRANCHE).unwrap().net_asset_value, 2600);
+            assert_eq!(RiskPoolContract::get_pool_nav(env.clone(), DEFAULT_TRANCHE).unwrap().net_asset_value, 10400);
+
+            // Reserved claims fall on the junior tranche until it is used up
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
+            let junior_exposure = RiskPoolContract::get_tranche_exposure(env.clone(), JUNIOR_TRANCHE).unwrap();
+            assert_eq!(junior_exposure.reserved_exposure, 2600);
+            let senior_exposure = RiskPoolContract::get_tranche_exposure(env.clone(), DEFAULT_TRANCHE).unwrap();
+            assert_eq!(senior_exposure.reserved_exposure, 400);
+
+            RiskPoolContract::payout_reserved_claim(
+                env.clone(),
+                claims_contract.clone(),
+                1,
+                Address::generate(&env),
+            ).unwrap();
+            let junior_state = RiskPoolContract::get_tranche_exposure(env.clone(), JUNIOR_TRANCHE).unwrap().state;
+            assert_eq!(junior_state.capital, 0);
+            assert_eq!(junior_state.losses_absorbed, 2600);
+            assert_eq!(RiskPoolContract::share_value(env.clone(), junior.clone(), JUNIOR_TRANCHE), Ok(0));
+            assert_eq!(RiskPoolContract::share_value(env.clone(), senior.clone(), DEFAULT_TRANCHE), Ok(10000));
+
+            // Withdrawals are made per tranche
+            let request_id = RiskPoolContract::request_tranche_withdrawal(
+                env.clone(),
+                senior.clone(),
+                DEFAULT_TRANCHE,
+                1000,
+            ).unwrap();
+            assert_eq!(RiskPoolContract::get_withdrawal(env.clone(), request_id).unwrap().tranche_id, DEFAULT_TRANCHE);
+            let result = RiskPoolContract::request_tranche_withdrawal(env.clone(), senior.clone(), JUNIOR_TRANCHE, 1);
+            assert_eq!(result, Err(ContractError::InvalidState));
+
+            // A tranche with shares outstanding cannot be dropped
+            let mut single = Vec::new(&env);
+            single.push_back(TrancheConfig {
+                id: DEFAULT_TRANCHE,
+                premium_share_bps: 10000,
+                waterfall_rank: 0,
+            });
+            let result = RiskPoolContract::define_tranches(env.clone(), admin.clone(), single);
+            assert_eq!(result, Err(ContractError::InvalidState));
+        });
+    }
+
+    #[test]
+    fn test_tranche_withdrawal_keeps_reserved_exposure_covered() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+        let senior = Address::generate(&env);
+        let junior = Address::generate(&env);
+        const JUNIOR_TRANCHE: u32 = 1;
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+            RiskPoolContract::set_withdrawal_config(
+                env.clone(),
+                admin.clone(),
+                WithdrawalConfig {
+                    cooldown_secs: 0,
+                    solvency_buffer: 0,
+                    max_utilization_bps: 10000,
+                },
+            ).unwrap();
+
+            let mut tranches = Vec::new(&env);
+            tranches.push_back(TrancheConfig {
+                id: DEFAULT_TRANCHE,
+                premium_share_bps: 4000,
+                waterfall_rank: 1,
+            });
+            tranches.push_back(TrancheConfig {
+                id: JUNIOR_TRANCHE,
+                premium_share_bps: 6000,
+                waterfall_rank: 0,
+            });
+            RiskPoolContract::define_tranches(env.clone(), admin.clone(), tranches).unwrap();
+
+            fund(&env, &xlm_token, &senior, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), senior.clone(), 10000).unwrap();
+            fund(&env, &xlm_token, &junior, 2000);
+            RiskPoolContract::deposit_to_tranche(env.clone(), junior.clone(), JUNIOR_TRANCHE, 2000).unwrap();
+
+            // The junior tranche carries the whole reservation
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 1500).unwrap();
+            let request_id = RiskPoolContract::request_tranche_withdrawal(
+                env.clone(),
+                junior.clone(),
+                JUNIOR_TRANCHE,
+                1000,
+            ).unwrap();
+
+            // The pool as a whole could pay, but the junior tranche would fall below its exposure
+            let result = RiskPoolContract::complete_withdrawal(env.clone(), junior.clone(), request_id);
+            assert_eq!(result, Err(ContractError::InsufficientFunds));
+
+            RiskPoolContract::adjust_reservation(env.clone(), claims_contract.clone(), 1, 1000).unwrap();
+            RiskPoolContract::complete_withdrawal(env.clone(), junior.clone(), request_id).unwrap();
+            let exposure = RiskPoolContract::get_tranche_exposure(env.clone(), JUNIOR_TRANCHE).unwrap();
+            assert_eq!(exposure.state.capital, 1000);
+            assert_eq!(exposure.reserved_exposure, 1000);
+        });
+    }
+
+    #[test]
+    fn test_excess_of_loss_treaty_cedes_claims_and_premium() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+        let reinsurer_id = env.register_contract(None, RiskPoolContract);
+        let policy_contract = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let holder = Address::generate(&env);
+
+        env.as_contract(&reinsurer_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &Address::generate(&env));
+            RiskPoolContract::register_cedant(env.clone(), admin.clone(), pool_id.clone()).unwrap();
+            let provider = Address::generate(&env);
+            fund(&env, &xlm_token, &provider, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), provider, 10000).unwrap();
+        });
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+            RiskPoolContract::register_policy_contract(env.clone(), admin.clone(), policy_contract.clone()).unwrap();
+            let provider = Address::generate(&env);
+            fund(&env, &xlm_token, &provider, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), provider, 10000).unwrap();
+
+            RiskPoolContract::set_reinsurance_treaty(
+                env.clone(),
+                admin.clone(),
+                ReinsuranceTreaty {
+                    id: 1,
+                    kind: TreatyKind::ExcessOfLoss,
+                    reinsurer: reinsurer_id.clone(),
+                    ceded_share_bps: 0,
+                    retention: 2000,
+                    aggregate_retention: None,
+                    limit: 5000,
+                    ceded_premium_bps: 1000,
+                },
+            ).unwrap();
+
+            // 10% of each premium goes to the reinsurer
+            fund(&env, &xlm_token, &pool_id, 1000);
+            RiskPoolContract::credit_premium(
+                env.clone(),
+                policy_contract.clone(),
+                shared::types::Asset::Native,
+                1000,
+            ).unwrap();
+            assert_eq!(RiskPoolContract::get_pool_stats(env.clone()).unwrap().0, 10900);
+
+            // Refunds take back the ceded share from the reinsurer
+            RiskPoolContract::refund_premium(
+                env.clone(),
+                policy_contract.clone(),
+                holder.clone(),
+                500,
+                shared::types::Asset::Native,
+            ).unwrap();
+            assert_eq!(RiskPoolContract::get_pool_stats(env.clone()).unwrap().0, 10450);
+
+            // Claims within the retention stay with the pool
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 1500).unwrap();
+            assert_eq!(RiskPoolContract::get_claim_cessions(env.clone(), 1).get(0).unwrap().reserved, 0);
+
+            // The part of a claim above the retention is reserved with the reinsurer
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 2, 4000).unwrap();
+            let cession = RiskPoolContract::get_claim_cessions(env.clone(), 2).get(0).unwrap();
+            assert_eq!(cession.reserved, 2000);
+            let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap();
+            assert_eq!(reserved_total, 3500);
+
+            // Adjusting a claim re-applies the treaty: above the retention the reinsurer
+            // takes its layer, and lets go again once the claim is back inside it
+            RiskPoolContract::adjust_reservation(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
+            assert_eq!(RiskPoolContract::get_claim_cessions(env.clone(), 1).get(0).unwrap().reserved, 1000);
+            RiskPoolContract::adjust_reservation(env.clone(), claims_contract.clone(), 1, 1800).unwrap();
+            assert_eq!(RiskPoolContract::get_claim_cessions(env.clone(), 1).get(0).unwrap().reserved, 0);
+            let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap();
+            assert_eq!(reserved_total, 3800);
+
+            // Payments inside the retention are met by the pool before the reinsurer pays
+            RiskPoolContract::payout_reserved_claim_multi_asset(
+                env.clone(),
+                claims_contract.clone(),
+                2,
+                recipient.clone(),
+                shared::types::Asset::Native,
+                Some(3000),
+                None,
+            ).unwrap();
+            let cession = RiskPoolContract::get_claim_cessions(env.clone(), 2).get(0).unwrap();
+            assert_eq!(cession.reserved, 1000);
+            assert_eq!(cession.paid, 1000);
+
+            RiskPoolContract::payout_reserved_claim(env.clone(), claims_contract.clone(), 2, recipient.clone()).unwrap();
+            assert_eq!(RiskPoolContract::get_pool_stats(env.clone()).unwrap().0, 8450);
+        });
+
+        env.as_contract(&reinsurer_id, || {
+            assert_eq!(RiskPoolContract::get_assumed_reservation(env.clone(), pool_id.clone(), 1), 0);
+            assert_eq!(RiskPoolContract::get_assumed_reservation(env.clone(), pool_id.clone(), 2), 0);
+            // Deposit, ceded premium less its refund, less the ceded share of the claim
+            assert_eq!(RiskPoolContract::get_pool_stats(env.clone()).unwrap().0, 8050);
+        });
+
+        let token_client = soroban_sdk::token::Client::new(&env, &xlm_token);
+        assert_eq!(token_client.balance(&recipient), 4000);
+        assert_eq!(token_client.balance(&holder), 500);
+        assert_eq!(token_client.balance(&reinsurer_id), 8050);
+    }
+
+    #[test]
+    fn test_quota_share_treaty_cedes_from_first_unit_and_rejects_cycles() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+        let reinsurer_id = env.register_contract(None, RiskPoolContract);
+
+        env.as_contract(&reinsurer_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &Address::generate(&env));
+            RiskPoolContract::register_cedant(env.clone(), admin.clone(), pool_id.clone()).unwrap();
+            let provider = Address::generate(&env);
+            fund(&env, &xlm_token, &provider, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), provider, 10000).unwrap();
+        });
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+            let provider = Address::generate(&env);
+            fund(&env, &xlm_token, &provider, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), provider, 10000).unwrap();
+
+            let mut treaty = ReinsuranceTreaty {
+                id: 1,
+                kind: TreatyKind::QuotaShare,
+                reinsurer: reinsurer_id.clone(),
+                ceded_share_bps: 3000,
+                retention: 500,
+                aggregate_retention: None,
+                limit: 5000,
+                ceded_premium_bps: 2000,
+            };
+            // A quota share is proportional from the first unit and takes no retention
+            let result = RiskPoolContract::set_reinsurance_treaty(env.clone(), admin.clone(), treaty.clone());
+            assert_eq!(result, Err(ContractError::InvalidInput));
+            treaty.retention = 0;
+            RiskPoolContract::set_reinsurance_treaty(env.clone(), admin.clone(), treaty).unwrap();
+
+            // The loss share is the treaty's own, not its premium share
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 1000).unwrap();
+            assert_eq!(RiskPoolContract::get_claim_cessions(env.clone(), 1).get(0).unwrap().reserved, 300);
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 2, 100).unwrap();
+            assert_eq!(RiskPoolContract::get_claim_cessions(env.clone(), 2).get(0).unwrap().reserved, 30);
+            let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap();
+            assert_eq!(reserved_total, 770);
+        });
+
+        env.as_contract(&reinsurer_id, || {
+            assert_eq!(RiskPoolContract::get_assumed_reservation(env.clone(), pool_id.clone(), 1), 300);
+
+            // The reinsurer may not cede back to the pool that cedes to it
+            let result = RiskPoolContract::set_reinsurance_treaty(
+                env.clone(),
+                admin.clone(),
+                ReinsuranceTreaty {
+                    id: 1,
+                    kind: TreatyKind::QuotaShare,
+                    reinsurer: pool_id.clone(),
+                    ceded_share_bps: 1000,
+                    retention: 0,
+                    aggregate_retention: None,
+                    limit: 5000,
+                    ceded_premium_bps: 1000,
+                },
+            );
+            assert_eq!(result, Err(ContractError::InvalidInput));
+        });
+    }
+
+    #[test]
+    fn test_excess_of_loss_aggregate_retention() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+        let reinsurer_id = env.register_contract(None, RiskPoolContract);
+
+        env.as_contract(&reinsurer_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &Address::generate(&env));
+            RiskPoolContract::register_cedant(env.clone(), admin.clone(), pool_id.clone()).unwrap();
+            let provider = Address::generate(&env);
+            fund(&env, &xlm_token, &provider, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), provider, 10000).unwrap();
+        });
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+            let provider = Address::generate(&env);
+            fund(&env, &xlm_token, &provider, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), provider, 10000).unwrap();
+
+            RiskPoolContract::set_reinsurance_treaty(
+                env.clone(),
+                admin.clone(),
+                ReinsuranceTreaty {
+                    id: 1,
+                    kind: TreatyKind::ExcessOfLoss,
+                    reinsurer: reinsurer_id.clone(),
+                    ceded_share_bps: 0,
+                    retention: 1000,
+                    aggregate_retention: Some(1500),
+                    limit: 5000,
+                    ceded_premium_bps: 0,
+                },
+            ).unwrap();
+
+            // The first claim uses the full per-claim retention
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 1200).unwrap();
+            let cession = RiskPoolContract::get_claim_cessions(env.clone(), 1).get(0).unwrap();
+            assert_eq!((cession.retention, cession.reserved), (1000, 200));
+
+            // Only what is left of the aggregate retention applies to the next
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 2, 1200).unwrap();
+            let cession = RiskPoolContract::get_claim_cessions(env.clone(), 2).get(0).unwrap();
+            assert_eq!((cession.retention, cession.reserved), (500, 700));
+
+            // Once it is used up the reinsurer takes claims from the first unit
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 3, 800).unwrap();
+            let cession = RiskPoolContract::get_claim_cessions(env.clone(), 3).get(0).unwrap();
+            assert_eq!((cession.retention, cession.reserved), (0, 800));
+
+            let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap();
+            assert_eq!(reserved_total, 1500);
+        });
+
+        env.as_contract(&reinsurer_id, || {
+            assert_eq!(RiskPoolContract::get_pool_stats(env.clone()).unwrap().0, 10000);
+            let reserved_total: i128 = env.storage().persistent().get(&RESERVED_TOTAL).unwrap();
+            assert_eq!(reserved_total, 1700);
+        });
+    }
+
     #[test]
     fn test_payout_reserved_claim_not_found() {
         let (env, admin, xlm_token, claims_contract) = setup_test_env();
@@ -1177,6 +3988,8 @@ mod tests {
         let recipient = Address::generate(&env);
         let untrusted_contract = Address::generate(&env);
 
+        fund(&env, &xlm_token, &provider, 10000);
+
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
         RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
 
@@ -1198,6 +4011,8 @@ mod tests {
         let provider = Address::generate(&env);
         let recipient = Address::generate(&env);
 
+        fund(&env, &xlm_token, &provider, 10000);
+
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
         RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
 
@@ -1213,6 +4028,192 @@ mod tests {
         assert_eq!(result, Err(ContractError::Paused));
     }
 
+    // ============================================================
+    // PREMIUM REFUND TESTS
+    // ============================================================
+
+    #[test]
+    fn test_refund_premium_success() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+        let policy_contract = Address::generate(&env);
+        let holder = Address::generate(&env);
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+            RiskPoolContract::register_policy_contract(env.clone(), admin.clone(), policy_contract.clone()).unwrap();
+
+            let provider = Address::generate(&env);
+            fund(&env, &xlm_token, &provider, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
+
+            let result = RiskPoolContract::refund_premium(
+                env.clone(),
+                policy_contract.clone(),
+                holder.clone(),
+                2500,
+                shared::types::Asset::Native,
+            );
+            assert!(result.is_ok());
+
+            let stats = RiskPoolContract::get_pool_stats(env.clone()).unwrap();
+            assert_eq!(stats.0, 7500);
+            assert_eq!(stats.1, 0);  // refunds are not claim payouts
+        });
+
+        let token_client = soroban_sdk::token::Client::new(&env, &xlm_token);
+        assert_eq!(token_client.balance(&holder), 2500);
+        assert_eq!(token_client.balance(&pool_id), 7500);
+    }
+
+    #[test]
+    fn test_get_asset_liquidity() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+        let token_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        soroban_sdk::token::StellarAssetClient::new(&env, &token_address).mint(&pool_id, &4000);
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+            let usdc = shared::types::Asset::Stellar((Symbol::new(&env, "USDC"), admin.clone()));
+            assert_eq!(
+                RiskPoolContract::get_asset_liquidity(env.clone(), usdc.clone()),
+                Err(ContractError::UnsupportedAsset)
+            );
+            RiskPoolContract::set_asset_token(
+                env.clone(),
+                admin.clone(),
+                usdc.clone(),
+                token_address.clone(),
+            ).unwrap();
+            assert_eq!(RiskPoolContract::get_asset_liquidity(env.clone(), usdc.clone()), Ok(4000));
+            // Tokens sent straight to the pool are not payout reserves
+            assert_eq!(
+                RiskPoolContract::get_payout_liquidity(env.clone(), usdc.clone(), 1),
+                Ok(0)
+            );
+
+            // Premium in another asset cannot be mixed into the base-asset NAV
+            assert_eq!(
+                RiskPoolContract::credit_premium(env.clone(), claims_contract.clone(), usdc, 1000),
+                Err(ContractError::UnsupportedAsset)
+            );
+        });
+    }
+
+    #[test]
+    fn test_payout_liquidity_excludes_other_claims_reservations() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+
+            let provider = Address::generate(&env);
+            fund(&env, &xlm_token, &provider, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 2, 2000).unwrap();
+
+            let native = shared::types::Asset::Native;
+            assert_eq!(RiskPoolContract::get_asset_liquidity(env.clone(), native.clone()), Ok(10000));
+            assert_eq!(
+                RiskPoolContract::get_payout_liquidity(env.clone(), native.clone(), 1),
+                Ok(8000)
+            );
+            assert_eq!(
+                RiskPoolContract::get_payout_liquidity(env.clone(), native.clone(), 2),
+                Ok(7000)
+            );
+            // A claim with no reservation cannot draw on anyone else's
+            assert_eq!(RiskPoolContract::get_payout_liquidity(env.clone(), native, 3), Ok(5000));
+        });
+    }
+
+    #[test]
+    fn test_reconcile_balances_reports_custody_drift() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        let pool_id = env.register_contract(None, RiskPoolContract);
+
+        env.as_contract(&pool_id, || {
+            initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+
+            let provider = Address::generate(&env);
+            fund(&env, &xlm_token, &provider, 10000);
+            RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
+            RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
+            RiskPoolContract::payout_reserved_claim(
+                env.clone(),
+                claims_contract.clone(),
+                1,
+                Address::generate(&env),
+            ).unwrap();
+
+            // Transfers made through the pool keep the books in line with the token balance
+            let report = RiskPoolContract::reconcile_balances(env.clone()).unwrap();
+            assert_eq!(report.len(), 1);
+            let native = report.get(0).unwrap();
+            assert_eq!(native.asset, shared::types::Asset::Native);
+            assert_eq!(native.on_chain_balance, 7000);
+            assert_eq!(native.recorded_balance, 7000);
+            assert_eq!(native.drift, 0);
+        });
+
+        // Tokens sent straight to the pool bypass its accounting
+        fund(&env, &xlm_token, &pool_id, 500);
+
+        env.as_contract(&pool_id, || {
+            let native = RiskPoolContract::reconcile_balances(env.clone()).unwrap().get(0).unwrap();
+            assert_eq!(native.on_chain_balance, 7500);
+            assert_eq!(native.recorded_balance, 7000);
+            assert_eq!(native.drift, 500);
+        });
+    }
+
+    #[test]
+    fn test_refund_premium_respects_reserved_liquidity() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+
+        let policy_contract = Address::generate(&env);
+        RiskPoolContract::register_policy_contract(env.clone(), admin.clone(), policy_contract.clone()).unwrap();
+
+        let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
+        RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
+        RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 8000).unwrap();
+
+        let result = RiskPoolContract::refund_premium(
+            env.clone(),
+            policy_contract.clone(),
+            Address::generate(&env),
+            3000,
+            shared::types::Asset::Native,
+        );
+
+        assert_eq!(result, Err(ContractError::InsufficientFunds));
+    }
+
+    #[test]
+    fn test_refund_premium_unauthorized_contract() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+
+        let provider = Address::generate(&env);
+        fund(&env, &xlm_token, &provider, 10000);
+        RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
+
+        let result = RiskPoolContract::refund_premium(
+            env.clone(),
+            Address::generate(&env),
+            Address::generate(&env),
+            1000,
+            shared::types::Asset::Native,
+        );
+
+        assert_eq!(result, Err(ContractError::NotTrustedContract));
+    }
+
     // ============================================================
     // PAYOUT CLAIM TESTS (Non-Reserved)
     // ============================================================
@@ -1227,6 +4228,7 @@ mod tests {
         let recipient = Address::generate(&env);
 
         RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         let result = RiskPoolContract::payout_claim(
@@ -1253,6 +4255,7 @@ mod tests {
         let recipient = Address::generate(&env);
 
         RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        fund(&env, &xlm_token, &provider, 5000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 5000).unwrap();
 
         let result = RiskPoolContract::payout_claim(
@@ -1275,6 +4278,7 @@ mod tests {
         let recipient = Address::generate(&env);
 
         RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         // Reserve 7000
@@ -1300,6 +4304,8 @@ mod tests {
         let unauthorized = Address::generate(&env);
         let recipient = Address::generate(&env);
 
+        fund(&env, &xlm_token, &provider, 10000);
+
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         let result = RiskPoolContract::payout_claim(
@@ -1322,6 +4328,7 @@ mod tests {
         let recipient = Address::generate(&env);
 
         RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         let result = RiskPoolContract::payout_claim(
@@ -1347,6 +4354,7 @@ mod tests {
         let recipient = Address::generate(&env);
 
         // Deposit
+        fund(&env, &xlm_token, &provider, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
         assert!(check_liquidity_invariant(&env).is_ok());
 
@@ -1479,7 +4487,9 @@ mod tests {
         let recipient = Address::generate(&env);
 
         // Multiple providers deposit
+        fund(&env, &xlm_token, &provider1, 10000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider1.clone(), 10000).unwrap();
+        fund(&env, &xlm_token, &provider2, 5000);
         RiskPoolContract::deposit_liquidity(env.clone(), provider2.clone(), 5000).unwrap();
 
         let stats = RiskPoolContract::get_pool_stats(env.clone()).unwrap();
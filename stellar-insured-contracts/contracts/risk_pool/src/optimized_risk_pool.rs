@@ -37,6 +37,8 @@ pub struct CompactProviderInfo {
     pub registered_days: u32,
     /// Flags for various states (bit-packed)
     pub flags: u8,
+    /// Requested but not yet completed withdrawals (compact)
+    pub pending_withdrawal: i64,
 }
 
 /// Storage optimization keys
@@ -150,11 +152,16 @@ impl OptimizedRiskPool {
             .get(&(crate::PROVIDER, provider.clone()))
             .ok_or(crate::ContractError::NotFound)?;
 
+        let pending_withdrawal: i128 = env.storage().persistent()
+            .get(&(crate::PENDING_WITHDRAWAL, provider.clone()))
+            .unwrap_or(0i128);
+
         let compact_info = CompactProviderInfo {
             balance: Self::to_compact_amount(provider_info.0)?,
             total_deposited: Self::to_compact_amount(provider_info.1)?,
             registered_days: OptimizedStructures::timestamp_to_days(provider_info.2),
             flags: 0u8, // Reserved for future use
+            pending_withdrawal: Self::to_compact_amount(pending_withdrawal)?,
         };
 
         // Cache for fast access
@@ -196,6 +203,34 @@ impl OptimizedRiskPool {
         Ok(())
     }
 
+    /// Track a change in a provider's requested-but-not-completed withdrawals
+    pub fn adjust_pending_withdrawal(
+        env: &Env,
+        provider: &Address,
+        change: i128,
+    ) -> Result<(), crate::ContractError> {
+        let mut provider_info = Self::get_provider_info_optimized(env, provider)?;
+
+        provider_info.pending_withdrawal = provider_info.pending_withdrawal
+            .checked_add(Self::to_compact_amount(change)?)
+            .ok_or(crate::ContractError::Overflow)?;
+        if provider_info.pending_withdrawal < 0 || provider_info.pending_withdrawal > provider_info.balance {
+            return Err(crate::ContractError::InvalidState);
+        }
+
+        // Update cache
+        let cache_key = Symbol::new(env, &format!("PROV_{}", provider.to_string().get(0..8).unwrap_or("default")));
+        GasOptimizer::cache_set(env, cache_key, &provider_info)?;
+
+        // Update persistent storage
+        env.storage().persistent().set(
+            &(crate::PENDING_WITHDRAWAL, provider.clone()),
+            &Self::from_compact_amount(provider_info.pending_withdrawal),
+        );
+
+        Ok(())
+    }
+
     /// Efficient batch deposit processing
     pub fn batch_deposit_liquidity_optimized(
         env: &Env,
@@ -419,6 +454,13 @@ mod tests {
         let updated_info = OptimizedRiskPool::get_provider_info_optimized(&env, &provider).unwrap();
         assert_eq!(updated_info.balance, 6000i64);
         assert_eq!(updated_info.total_deposited, 6000i64);
+        assert_eq!(updated_info.pending_withdrawal, 0i64);
+
+        // Pending withdrawals are tracked alongside the balance they are drawn from
+        OptimizedRiskPool::adjust_pending_withdrawal(&env, &provider, 2500).unwrap();
+        let pending_info = OptimizedRiskPool::get_provider_info_optimized(&env, &provider).unwrap();
+        assert_eq!(pending_info.pending_withdrawal, 2500i64);
+        assert!(OptimizedRiskPool::adjust_pending_withdrawal(&env, &provider, 4000).is_err());
     }
 
     #[test]
@@ -115,6 +115,85 @@ pub struct ClaimPayoutPreference {
     pub alternatives: Vec<Asset>,
 }
 
+// ===== Coverage Terms =====
+
+/// Maximum payable for losses caused by a single named peril
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PerilSubLimit {
+    /// Peril identifier (e.g., "flood", "theft")
+    pub peril: Symbol,
+    /// Cap applied to the gross loss for this peril
+    pub limit: i128,
+}
+
+/// Loss-sharing terms applied when a claim is approved
+///
+/// The net payable is computed as: gross loss capped by the peril sub-limit,
+/// less the deductible, less the holder's co-insurance share of the remainder.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoverageTerms {
+    /// Amount of each loss retained by the holder
+    pub deductible: i128,
+    /// Holder's share of the loss above the deductible in basis points
+    pub coinsurance_bps: u32,
+    /// Per-peril caps on the gross loss
+    pub sub_limits: Vec<PerilSubLimit>,
+}
+
+// ===== Beneficiaries =====
+
+/// A named recipient of claim payouts and their share
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Beneficiary {
+    /// Address receiving this share of each payout
+    pub recipient: Address,
+    /// Share of the payout in basis points
+    pub share_bps: u32,
+}
+
+/// Beneficiaries designated by the policyholder
+///
+/// Primary shares sum to 10000 basis points. The contingent beneficiary
+/// receives the share of any primary beneficiary unable to take payment.
+/// An empty designation pays the claimant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryDesignation {
+    pub beneficiaries: Vec<Beneficiary>,
+    pub contingent: Option<Address>,
+}
+
+// ===== Group Policies =====
+
+/// Lifecycle of a member certificate under a group master policy
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CertificateStatus {
+    Active,
+    Removed,
+}
+
+/// A member's cover under a group master policy
+///
+/// Claims against the certificate are capped by its coverage and draw
+/// from the master policy's aggregate limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberCertificate {
+    pub id: u64,
+    pub master_policy_id: u64,
+    pub member: Address,
+    pub coverage_amount: i128,
+    /// Pro-rata premium charged to the group sponsor at enrollment
+    pub premium_amount: i128,
+    pub enrolled_at: u64,
+    pub removed_at: Option<u64>,
+    pub status: CertificateStatus,
+}
+
 // ===== Status Enums =====
 
 /// Represents the lifecycle status of a policy
@@ -10,6 +10,8 @@ use insurance_contracts::authorization::{
 };
 // Import invariant checks
 use insurance_invariants::{InvariantError, ProtocolInvariants};
+// Import token custody helpers
+use insurance_contracts::token::{self, TokenError};
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, Address, Env, Map, Symbol, Vec,
 };
@@ -53,6 +55,8 @@ pub enum ContractError {
     ProposalNotActive = 16,
     QuorumNotMet = 17,
     ThresholdNotMet = 18,
+    UnsupportedAsset = 19,
+    TransferFailed = 20,
     // Invariant violation errors (100-199)
     InvalidAmount = 103,
     BalanceViolation = 100,
@@ -88,6 +92,15 @@ fn invoker(env: &Env) -> Address {
     env.current_contract_address()
 }
 
+impl From<TokenError> for ContractError {
+    fn from(err: TokenError) -> Self {
+        match err {
+            TokenError::UnsupportedAsset => ContractError::UnsupportedAsset,
+            TokenError::TransferFailed => ContractError::TransferFailed,
+        }
+    }
+}
+
 impl From<InvariantError> for ContractError {
     fn from(err: InvariantError) -> Self {
         match err {
@@ -307,15 +320,16 @@ impl TreasuryContract {
 
     /// Deposit premium fees from policy contract
     pub fn deposit_premium_fee(env: Env, from: Address, amount: i128) -> Result<(), ContractError> {
+        from.require_auth();
+
         if is_paused(&env) {
             return Err(ContractError::Paused);
         }
 
         validate_amount(amount)?;
 
-        // Verify caller is a trusted contract
-        let caller = invoker(&env);
-        if !is_trusted_contract(&env, &caller) {
+        // The depositing policy contract must be trusted
+        if !is_trusted_contract(&env, &from) {
             return Err(ContractError::NotTrustedContract);
         }
 
@@ -692,11 +706,89 @@ impl TreasuryContract {
         Ok(())
     }
 
+    /// Pay a keeper bounty in the native asset out of the treasury balance.
+    /// Only trusted contracts (e.g. the policy contract's keeper sweeps) may call this.
+    pub fn pay_keeper_bounty(
+        env: Env,
+        caller_contract: Address,
+        keeper: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(amount)?;
+
+        if !is_trusted_contract(&env, &caller_contract) {
+            return Err(ContractError::NotTrustedContract);
+        }
+
+        let balance = get_balance(&env);
+        if amount > balance {
+            return Err(ContractError::InsufficientFunds);
+        }
+
+        let new_balance = balance.checked_sub(amount).ok_or(ContractError::BalanceViolation)?;
+        set_balance(&env, new_balance)?;
+
+        let total_withdrawn: i128 =
+            env.storage().persistent().get(&TOTAL_WITHDRAWN).unwrap_or(0i128);
+        let new_total_withdrawn =
+            total_withdrawn.checked_add(amount).ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&TOTAL_WITHDRAWN, &new_total_withdrawn);
+
+        token::transfer(
+            &env,
+            &shared::types::Asset::Native,
+            &env.current_contract_address(),
+            &keeper,
+            amount,
+        )?;
+
+        env.events().publish(
+            (Symbol::new(&env, "keeper_bounty_paid"), keeper),
+            (caller_contract, amount, new_balance),
+        );
+
+        Ok(())
+    }
+
+    /// Register the token contract backing an asset (admin only)
+    pub fn set_asset_token(
+        env: Env,
+        admin: Address,
+        asset: shared::types::Asset,
+        token_address: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        validate_address(&env, &token_address)?;
+
+        token::set_asset_token(&env, &asset, &token_address);
+
+        env.events().publish(
+            (Symbol::new(&env, "asset_token_set"), token_address.clone()),
+            (asset, token_address),
+        );
+
+        Ok(())
+    }
+
     /// Get current treasury balance
     pub fn get_balance(env: Env) -> i128 {
         get_balance(&env)
     }
 
+    /// Get the fee percentage (basis points) applied to premiums
+    pub fn get_fee_percentage(env: Env) -> Result<u32, ContractError> {
+        let config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        Ok(config.fee_percentage)
+    }
+
     /// Get treasury statistics
     pub fn get_stats(env: Env) -> Result<TreasuryStats, ContractError> {
         let total_fees: i128 =
@@ -845,9 +937,8 @@ mod tests {
         let (env, admin, governance, untrusted) = create_test_env();
 
         TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        env.mock_all_auths();
+        let result = TreasuryContract::deposit_premium_fee(env.clone(), untrusted.clone(), 1000);
 
         assert_eq!(result, Err(ContractError::NotTrustedContract));
     }
@@ -861,9 +952,7 @@ mod tests {
 
         env.ledger().set_sequence_number(1);
         env.mock_all_auths();
-
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        let result = TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 1000);
 
         assert!(result.is_ok());
 
@@ -875,19 +964,111 @@ mod tests {
     }
 
     #[test]
-    fn test_deposit_premium_fee_invalid_amount() {
+    fn test_pay_keeper_bounty_without_trust() {
+        let (env, admin, governance, untrusted) = create_test_env();
+
+        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        env.mock_all_auths();
+
+        let keeper = Address::random(&env);
+        let result =
+            TreasuryContract::pay_keeper_bounty(env.clone(), untrusted.clone(), keeper, 100);
+
+        assert_eq!(result, Err(ContractError::NotTrustedContract));
+    }
+
+    #[test]
+    fn test_pay_keeper_bounty_insufficient_funds() {
         let (env, admin, governance, trusted) = create_test_env();
 
         TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
         TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        env.mock_all_auths();
+
+        let keeper = Address::random(&env);
+        let result = TreasuryContract::pay_keeper_bounty(env.clone(), trusted.clone(), keeper, 100);
 
+        assert_eq!(result, Err(ContractError::InsufficientFunds));
+        assert_eq!(TreasuryContract::get_balance(env.clone()), 0);
+    }
+
+    #[test]
+    fn test_pay_keeper_bounty_transfers_tokens() {
+        let (env, admin, governance, trusted) = create_test_env();
         env.mock_all_auths();
+        let treasury_id = env.register_contract(None, TreasuryContract);
+        let xlm_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        soroban_sdk::token::StellarAssetClient::new(&env, &xlm_token).mint(&treasury_id, &1000);
+        let keeper = Address::random(&env);
+
+        env.as_contract(&treasury_id, || {
+            TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500)
+                .unwrap();
+            TreasuryContract::set_asset_token(
+                env.clone(),
+                admin.clone(),
+                shared::types::Asset::Native,
+                xlm_token.clone(),
+            )
+            .unwrap();
+            env.storage().persistent().set(&(TRUSTED_CONTRACTS, &trusted), &true);
+            set_balance(&env, 1000).unwrap();
+
+            TreasuryContract::pay_keeper_bounty(env.clone(), trusted.clone(), keeper.clone(), 100)
+                .unwrap();
+
+            assert_eq!(TreasuryContract::get_balance(env.clone()), 900);
+            assert_eq!(TreasuryContract::get_stats(env.clone()).unwrap().total_withdrawn, 100);
+        });
+
+        let xlm = soroban_sdk::token::Client::new(&env, &xlm_token);
+        assert_eq!(xlm.balance(&keeper), 100);
+        assert_eq!(xlm.balance(&treasury_id), 900);
+    }
 
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 0);
+    #[test]
+    fn test_deposit_premium_fee_from_registered_policy_contract() {
+        let (env, admin, governance, policy_contract) = create_test_env();
+        env.mock_all_auths();
+        let treasury_id = env.register_contract(None, TreasuryContract);
+        let client = TreasuryContractClient::new(&env, &treasury_id);
+
+        env.as_contract(&treasury_id, || {
+            TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500)
+                .unwrap();
+        });
+
+        // A depositor the treasury has not registered is refused
+        assert_eq!(
+            client.try_deposit_premium_fee(&policy_contract, &50_000),
+            Err(Ok(ContractError::NotTrustedContract))
+        );
+
+        env.as_contract(&treasury_id, || {
+            env.storage().persistent().set(&(TRUSTED_CONTRACTS, &policy_contract), &true);
+        });
+        client.deposit_premium_fee(&policy_contract, &50_000);
+
+        // The depositor, not the treasury, authorized the deposit
+        let auths = env.auths();
+        assert_eq!(auths.len(), 1);
+        assert_eq!(auths[0].0, policy_contract);
+        assert_eq!(client.get_balance(), 50_000);
+        assert_eq!(client.get_stats().total_fees_collected, 50_000);
+    }
+
+    #[test]
+    fn test_deposit_premium_fee_invalid_amount() {
+        let (env, admin, governance, trusted) = create_test_env();
+
+        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+
+        env.mock_all_auths();
+        let result = TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 0);
         assert_eq!(result, Err(ContractError::InvalidAmount));
 
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), -100);
+        let result = TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), -100);
         assert_eq!(result, Err(ContractError::InvalidAmount));
     }
 
@@ -938,7 +1119,7 @@ mod tests {
 
         let depositor = Address::random(&env);
 
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000).unwrap();
+        TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 1000).unwrap();
         TreasuryContract::deposit_claim_penalty(env.clone(), depositor.clone(), 500).unwrap();
         TreasuryContract::deposit_slashing_fee(env.clone(), depositor.clone(), 250).unwrap();
 
@@ -977,9 +1158,7 @@ mod tests {
         env.mock_all_auths();
 
         TreasuryContract::set_pause(env.clone(), true).unwrap();
-
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        let result = TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 1000);
 
         assert_eq!(result, Err(ContractError::Paused));
     }
@@ -994,8 +1173,7 @@ mod tests {
         env.mock_all_auths();
 
         // Deposit funds first
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 10000).unwrap();
+        TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 10000).unwrap();
 
         // Create withdrawal proposal
         let recipient = Address::random(&env);
@@ -1030,8 +1208,7 @@ mod tests {
         env.mock_all_auths();
 
         // Deposit only 1000 funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000).unwrap();
+        TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 1000).unwrap();
 
         // Try to propose withdrawal of 5000
         let recipient = Address::random(&env);
@@ -1058,8 +1235,7 @@ mod tests {
         env.mock_all_auths();
 
         // Deposit funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 10000).unwrap();
+        TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 10000).unwrap();
 
         // Create withdrawal proposal
         let recipient = Address::random(&env);
@@ -1112,8 +1288,7 @@ mod tests {
         env.mock_all_auths();
 
         // Deposit only 2000 funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 2000).unwrap();
+        TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 2000).unwrap();
 
         // Create withdrawal proposal for 5000
         let recipient = Address::random(&env);
@@ -1151,8 +1326,7 @@ mod tests {
         env.mock_all_auths();
 
         // Deposit funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 10000).unwrap();
+        TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 10000).unwrap();
 
         // Create withdrawal proposal
         let recipient = Address::random(&env);
@@ -1194,8 +1368,7 @@ mod tests {
         assert!(result.is_ok());
 
         // Verify deposits fail
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        let result = TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 1000);
         assert_eq!(result, Err(ContractError::Paused));
 
         // Unpause contract
@@ -1203,7 +1376,7 @@ mod tests {
         assert!(result.is_ok());
 
         // Verify deposits work again
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        let result = TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 1000);
         assert!(result.is_ok());
     }
 
@@ -1238,8 +1411,7 @@ mod tests {
         env.mock_all_auths();
 
         // Deposit funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 20000).unwrap();
+        TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 20000).unwrap();
 
         // Create multiple withdrawal proposals
         let recipient1 = Address::random(&env);
@@ -1294,15 +1466,13 @@ mod tests {
 
         env.mock_all_auths();
 
-        let depositor = Address::random(&env);
-
         // Test overflow prevention
         let result =
-            TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), i128::MAX);
+            TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), i128::MAX);
         assert!(result.is_ok());
 
         // Trying to add more should fail
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1);
+        let result = TreasuryContract::deposit_premium_fee(env.clone(), trusted.clone(), 1);
         assert_eq!(result, Err(ContractError::Overflow));
     }
 